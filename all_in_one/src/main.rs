@@ -0,0 +1,131 @@
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use clap::Parser;
+use lazy_static::lazy_static;
+use snowflake::SnowflakeGenerator;
+use tonic::transport::{NamedService, Server};
+
+use common::config::settings::Settings;
+use common::customer_pb::customer_services_server::CustomerServicesServer;
+use common::order_item_pb::order_services_server::OrderServicesServer;
+use common::product_pb::product_services_server::ProductServicesServer;
+use common::util::cli::{run_command, Cli, Command};
+use common::util::connections::{create_cache, create_database_connection, create_id_generator};
+use common::util::health::health_service;
+use common::util::metrics::{serve_metrics, GrpcMetricsLayer};
+use common::util::shutdown::Shutdown;
+use common::util::telemetry::grpc_request_span;
+use common::util::tools::tracing_initialize;
+
+use crate::customer::services::grpc_service::GrpcCustomerServicesImpl;
+use crate::order::services::grpc_service::GrpcOrderServiceImpl;
+use crate::product::services::grpc_service::ProductServicesImpl;
+
+// the modules of the services are compiled into this binary, they refer to each other
+// and to the statics below by `crate::`, so their names are unique across the services.
+// the gateway isn't compiled in, its modules have the same names (e.g. `customer`), so it
+// runs as its own process with its client addresses pointed at this server.
+#[path = "../../customer_services/src/address/mod.rs"]
+mod address;
+#[path = "../../customer_services/src/customer/mod.rs"]
+mod customer;
+#[path = "../../customer_services/src/note/mod.rs"]
+mod note;
+
+#[path = "../../product_services/src/category/mod.rs"]
+mod category;
+#[path = "../../product_services/src/image/mod.rs"]
+mod image;
+#[path = "../../product_services/src/product/mod.rs"]
+mod product;
+#[path = "../../product_services/src/supplier/mod.rs"]
+mod supplier;
+#[path = "../../product_services/src/tag/mod.rs"]
+mod tag;
+
+#[path = "../../order_services/src/item_note/mod.rs"]
+mod item_note;
+#[path = "../../order_services/src/order/mod.rs"]
+mod order;
+
+lazy_static! {
+    static ref CLI: Cli = Cli::parse();
+    #[cfg(not(test))]
+    static ref SETTINGS: Settings = Settings::load_or_exit(&CLI.source());
+    #[cfg(test)]
+    static ref SETTINGS: Settings = Settings::default();
+    /// one generator is shared by all of the services, so the ids never collide.
+    static ref ID_GENERATOR: Arc<Mutex<SnowflakeGenerator>> = {
+        let generator = create_id_generator(SETTINGS.id_generator.clone());
+        Arc::new(Mutex::new(generator))
+    };
+    /// the region of the phone numbers which don't have a country code.
+    static ref DEFAULT_PHONE_REGION: String = SETTINGS.default_phone_region.clone();
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let settings = &*SETTINGS;
+
+    match CLI.command() {
+        Command::Serve => serve(settings).await,
+        command => run_command(command, settings).await,
+    }
+}
+
+/// host the customer, product and order services on one server,
+/// they share the database connection pool and the cache.
+async fn serve(settings: &Settings) -> Result<()> {
+    let _tracing_guard = tracing_initialize(settings, &CLI.log_dir, "all_in_one");
+
+    let database_connection = create_database_connection(settings.postgres.clone())
+        .await
+        .expect("Can't connect to database.");
+
+    let cache = create_cache(settings.cache.clone()).await;
+
+    let customer_service =
+        GrpcCustomerServicesImpl::new(database_connection.clone(), cache.clone());
+    let product_service = ProductServicesImpl::new(database_connection.clone(), cache.clone());
+    let order_item_service = GrpcOrderServiceImpl::new(database_connection.clone(), cache);
+
+    let shutdown = Shutdown::listen();
+
+    let health_service = health_service(
+        database_connection.clone(),
+        vec![
+            CustomerServicesServer::<GrpcCustomerServicesImpl>::NAME,
+            ProductServicesServer::<ProductServicesImpl>::NAME,
+            OrderServicesServer::<GrpcOrderServiceImpl>::NAME,
+        ],
+        &shutdown,
+    );
+
+    let addr = CLI.listen_or(settings.addresses.all_in_one_host);
+
+    tracing::info!(message = "starting all-in-one server", %addr);
+
+    serve_metrics(
+        settings.addresses.all_in_one_metrics,
+        database_connection.clone(),
+        &shutdown,
+    );
+
+    let server = Server::builder()
+        .trace_fn(grpc_request_span)
+        .layer(GrpcMetricsLayer)
+        .add_service(health_service)
+        .add_service(CustomerServicesServer::new(customer_service))
+        .add_service(ProductServicesServer::new(product_service))
+        .add_service(OrderServicesServer::new(order_item_service))
+        .serve_with_shutdown(addr, shutdown.triggered());
+
+    let drained = shutdown.drain(server, settings.shutdown_timeout).await;
+    if let Some(result) = drained {
+        database_connection.close().await;
+        result?;
+    }
+
+    Ok(())
+}
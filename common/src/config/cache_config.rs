@@ -0,0 +1,21 @@
+use crate::config::redis_config::RedisConfig;
+
+#[derive(Debug, Default, Clone)]
+pub struct CacheConfig {
+    /// the values are read from the database every time if caching is disabled.
+    pub enabled: bool,
+    /// the seconds before a cached value expires.
+    pub ttl: u64,
+    /// redis is optional, the values aren't cached without it.
+    pub redis: Option<RedisConfig>,
+}
+
+impl CacheConfig {
+    pub fn new(enabled: Option<bool>, ttl: Option<u64>, redis: Option<RedisConfig>) -> Self {
+        Self {
+            enabled: enabled.unwrap_or(redis.is_some()),
+            ttl: ttl.unwrap_or(300),
+            redis,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// the format of the log lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// the human readable lines.
+    Text,
+    /// a json object per line, it has the fields of the spans as well, e.g. `request_id`.
+    Json,
+}
+
+impl Default for LogFormat {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err("expected text or json".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text => write!(f, "text"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    /// the levels of the modules which override the default level,
+    /// e.g. `sqlx=warn,common::util::health=debug`.
+    pub filter: Option<String>,
+}
+
+impl LogConfig {
+    pub fn new(format: Option<LogFormat>, filter: Option<String>) -> Self {
+        Self {
+            format: format.unwrap_or_default(),
+            filter,
+        }
+    }
+}
@@ -1,4 +1,7 @@
 pub mod id_generator_config;
 pub mod base_config;
+pub mod cache_config;
+pub mod log_config;
 pub mod postgres_config;
-pub mod redis_config;
\ No newline at end of file
+pub mod redis_config;
+pub mod settings;
\ No newline at end of file
@@ -1,4 +1,4 @@
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct PostgresConfig {
     pub host: String,
     pub database: String,
@@ -0,0 +1,84 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::customer_pb;
+use crate::util::tools::timestamp2datetime;
+
+/// a shipping address of a customer.
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Address {
+    pub id: i64,
+    pub customer_id: i64,
+    pub recipient: String,
+    pub phone: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: String,
+    pub is_default: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<Address> for customer_pb::Address {
+    fn from(a: Address) -> Self {
+        Self {
+            id: a.id as u64,
+            customer_id: a.customer_id as u64,
+            recipient: a.recipient,
+            phone: a.phone,
+            line1: a.line1,
+            line2: a.line2,
+            city: a.city,
+            region: a.region,
+            postal_code: a.postal_code,
+            country: a.country,
+            is_default: a.is_default,
+            created_at: a.created_at.timestamp() as u64,
+            updated_at: a.updated_at.map(|d| d.timestamp() as u64),
+        }
+    }
+}
+
+impl From<customer_pb::Address> for Address {
+    fn from(a: customer_pb::Address) -> Self {
+        Self {
+            id: a.id as i64,
+            customer_id: a.customer_id as i64,
+            recipient: a.recipient,
+            phone: a.phone,
+            line1: a.line1,
+            line2: a.line2,
+            city: a.city,
+            region: a.region,
+            postal_code: a.postal_code,
+            country: a.country,
+            is_default: a.is_default,
+            created_at: timestamp2datetime(a.created_at),
+            updated_at: a.updated_at.map(timestamp2datetime),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum Addresses {
+    Table,
+    Id,
+    CustomerId,
+    Recipient,
+    Phone,
+    Line1,
+    Line2,
+    City,
+    Region,
+    PostalCode,
+    Country,
+    IsDefault,
+    CreatedAt,
+    UpdatedAt,
+    DeletedAt,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::product_pb;
+use crate::util::tools::timestamp2datetime;
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Category {
+    pub id: i64,
+    pub parent_id: Option<i64>,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<Category> for product_pb::Category {
+    fn from(c: Category) -> Self {
+        Self {
+            id: c.id as u64,
+            parent_id: c.parent_id.map(|e| e as u64),
+            name: c.name,
+            created_at: c.created_at.timestamp() as u64,
+            updated_at: c.updated_at.map(|d| d.timestamp() as u64),
+        }
+    }
+}
+
+impl From<product_pb::Category> for Category {
+    fn from(c: product_pb::Category) -> Self {
+        Self {
+            id: c.id as i64,
+            parent_id: c.parent_id.map(|e| e as i64),
+            name: c.name,
+            created_at: timestamp2datetime(c.created_at),
+            updated_at: c.updated_at.map(timestamp2datetime),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum Categories {
+    Table,
+    Id,
+    ParentId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::customer_pb;
+use crate::util::tools::timestamp2datetime;
+
+/// the way to contact a customer except email and phone, e.g. a LINE id or an Instagram account.
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct ContactChannel {
+    pub id: i64,
+    pub customer_id: i64,
+    pub channel: String,
+    pub handle: String,
+    pub preferred: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ContactChannel {
+    /// the channel with the customer id, it is not a part of the pb message.
+    pub fn from_pb(customer_id: i64, c: customer_pb::ContactChannel) -> Self {
+        Self {
+            id: c.id as i64,
+            customer_id,
+            channel: c.channel,
+            handle: c.handle,
+            preferred: c.preferred,
+            created_at: timestamp2datetime(c.created_at),
+        }
+    }
+}
+
+impl From<ContactChannel> for customer_pb::ContactChannel {
+    fn from(c: ContactChannel) -> Self {
+        Self {
+            id: c.id as u64,
+            channel: c.channel,
+            handle: c.handle,
+            preferred: c.preferred,
+            created_at: c.created_at.timestamp() as u64,
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum ContactChannels {
+    Table,
+    Id,
+    CustomerId,
+    Channel,
+    Handle,
+    Preferred,
+    CreatedAt,
+}
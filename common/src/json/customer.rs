@@ -1,12 +1,15 @@
 use chrono::{DateTime, Utc};
 use sea_query::Iden;
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
 
+use crate::json::contact_channel::ContactChannel;
+use crate::json::note::Note;
 use crate::util::tools::timestamp2datetime;
 use crate::{customer_pb, order_item_pb};
 
-#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Customer {
     pub id: i64,
     pub name: String,
@@ -14,6 +17,26 @@ pub struct Customer {
     pub phone: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
+    pub contacts: Vec<ContactChannel>,
+    /// the notes are only attached to the detail of a customer.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<Note>,
+}
+
+impl<'r> ::sqlx::FromRow<'r, PgRow> for Customer {
+    fn from_row(row: &'r PgRow) -> Result<Self, Error> {
+        // contact channels and notes are stored in other tables, the services attach them.
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            email: row.try_get("email")?,
+            phone: row.try_get("phone")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            contacts: vec![],
+            notes: vec![],
+        })
+    }
 }
 
 impl From<Customer> for customer_pb::Customer {
@@ -25,19 +48,29 @@ impl From<Customer> for customer_pb::Customer {
             phone: c.phone,
             created_at: c.created_at.timestamp() as u64,
             updated_at: c.updated_at.map(|d| d.timestamp() as u64),
+            contacts: c.contacts.into_iter().map(|e| e.into()).collect(),
+            notes: c.notes.into_iter().map(|e| e.into()).collect(),
         }
     }
 }
 
 impl From<customer_pb::Customer> for Customer {
     fn from(c: customer_pb::Customer) -> Self {
+        let id = c.id as i64;
+
         Self {
-            id: c.id as i64,
+            id,
             name: c.name,
             email: c.email,
             phone: c.phone,
             created_at: timestamp2datetime(c.created_at),
             updated_at: c.updated_at.map(timestamp2datetime),
+            contacts: c
+                .contacts
+                .into_iter()
+                .map(|e| ContactChannel::from_pb(id, e))
+                .collect(),
+            notes: c.notes.into_iter().map(|e| e.into()).collect(),
         }
     }
 }
@@ -61,6 +94,37 @@ impl From<order_item_pb::order_item::Customer> for Customer {
             phone: None,
             created_at: timestamp2datetime(c.created_at),
             updated_at: None,
+            contacts: vec![],
+            notes: vec![],
+        }
+    }
+}
+
+/// two customers which look like the same person.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CustomerDuplicate {
+    pub customer: Customer,
+    pub duplicate: Customer,
+    /// why they look like the same customer, e.g. "phone", "email" and "name".
+    pub reasons: Vec<String>,
+}
+
+impl From<CustomerDuplicate> for customer_pb::CustomerDuplicate {
+    fn from(d: CustomerDuplicate) -> Self {
+        Self {
+            customer: Some(d.customer.into()),
+            duplicate: Some(d.duplicate.into()),
+            reasons: d.reasons,
+        }
+    }
+}
+
+impl From<customer_pb::CustomerDuplicate> for CustomerDuplicate {
+    fn from(d: customer_pb::CustomerDuplicate) -> Self {
+        Self {
+            customer: d.customer.map(|c| c.into()).unwrap(),
+            duplicate: d.duplicate.map(|c| c.into()).unwrap(),
+            reasons: d.reasons,
         }
     }
 }
@@ -74,4 +138,6 @@ pub enum Customers {
     Phone,
     CreatedAt,
     UpdatedAt,
+    DeletedAt,
+    MergedInto,
 }
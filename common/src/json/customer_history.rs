@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::json::order_item::OrderItem;
+use crate::order_item_pb::customer_history_response::{
+    CurrencyTotal as CurrencyTotalPb, StatusCount as StatusCountPb,
+};
+use crate::order_item_pb::CustomerHistoryResponse;
+use crate::util::tools::timestamp2datetime;
+
+/// the order items of a customer with the aggregates of them.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CustomerHistory {
+    pub items: Vec<OrderItem>,
+    pub status_counts: Vec<StatusCount>,
+    pub totals: Vec<CurrencyTotal>,
+    pub first_ordered_at: Option<DateTime<Utc>>,
+    pub last_ordered_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct StatusCount {
+    pub status: u32,
+    pub count: u32,
+}
+
+/// the sum of price * quantity of the order items in a currency, except the out of stock ones.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct CurrencyTotal {
+    pub currency: i16,
+    pub total: Decimal,
+}
+
+impl From<CustomerHistory> for CustomerHistoryResponse {
+    fn from(h: CustomerHistory) -> Self {
+        Self {
+            items: h.items.into_iter().map(|e| e.into()).collect(),
+            status_counts: h.status_counts.into_iter().map(|e| e.into()).collect(),
+            totals: h.totals.into_iter().map(|e| e.into()).collect(),
+            first_ordered_at: h.first_ordered_at.map(|d| d.timestamp() as u64),
+            last_ordered_at: h.last_ordered_at.map(|d| d.timestamp() as u64),
+        }
+    }
+}
+
+impl From<CustomerHistoryResponse> for CustomerHistory {
+    fn from(h: CustomerHistoryResponse) -> Self {
+        Self {
+            items: h.items.into_iter().map(|e| e.into()).collect(),
+            status_counts: h.status_counts.into_iter().map(|e| e.into()).collect(),
+            totals: h.totals.into_iter().map(|e| e.into()).collect(),
+            first_ordered_at: h.first_ordered_at.map(timestamp2datetime),
+            last_ordered_at: h.last_ordered_at.map(timestamp2datetime),
+        }
+    }
+}
+
+impl From<StatusCount> for StatusCountPb {
+    fn from(s: StatusCount) -> Self {
+        Self {
+            status: s.status,
+            count: s.count,
+        }
+    }
+}
+
+impl From<StatusCountPb> for StatusCount {
+    fn from(s: StatusCountPb) -> Self {
+        Self {
+            status: s.status,
+            count: s.count,
+        }
+    }
+}
+
+impl From<CurrencyTotal> for CurrencyTotalPb {
+    fn from(t: CurrencyTotal) -> Self {
+        Self {
+            currency: t.currency as u32,
+            total: t.total.to_f64().unwrap(),
+        }
+    }
+}
+
+impl From<CurrencyTotalPb> for CurrencyTotal {
+    fn from(t: CurrencyTotalPb) -> Self {
+        Self {
+            currency: t.currency as i16,
+            total: Decimal::from_f64(t.total).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportRowStatus {
+    Created,
+    Skipped,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ImportRowResult {
+    pub row: u32,
+    pub status: ImportRowStatus,
+    pub id: Option<i64>,
+    pub reason: Option<String>,
+}
+
+/// the result of each imported row.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct ImportReport {
+    pub rows: Vec<ImportRowResult>,
+    pub created: u32,
+    pub skipped: u32,
+    pub failed: u32,
+}
+
+impl ImportReport {
+    pub fn add_created(&mut self, row: u32, id: i64) {
+        self.created += 1;
+        self.push(row, ImportRowStatus::Created, Some(id), None);
+    }
+
+    pub fn add_skipped(&mut self, row: u32, reason: String) {
+        self.skipped += 1;
+        self.push(row, ImportRowStatus::Skipped, None, Some(reason));
+    }
+
+    pub fn add_failed(&mut self, row: u32, reason: String) {
+        self.failed += 1;
+        self.push(row, ImportRowStatus::Failed, None, Some(reason));
+    }
+
+    fn push(&mut self, row: u32, status: ImportRowStatus, id: Option<i64>, reason: Option<String>) {
+        self.rows.push(ImportRowResult {
+            row,
+            status,
+            id,
+            reason,
+        });
+    }
+}
+
+impl From<ImportRowStatus> for types::ImportRowStatus {
+    fn from(s: ImportRowStatus) -> Self {
+        match s {
+            ImportRowStatus::Created => Self::Created,
+            ImportRowStatus::Skipped => Self::Skipped,
+            ImportRowStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<types::ImportRowStatus> for ImportRowStatus {
+    fn from(s: types::ImportRowStatus) -> Self {
+        match s {
+            types::ImportRowStatus::Created => Self::Created,
+            types::ImportRowStatus::Skipped => Self::Skipped,
+            types::ImportRowStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<ImportReport> for types::ImportReport {
+    fn from(r: ImportReport) -> Self {
+        Self {
+            rows: r
+                .rows
+                .into_iter()
+                .map(|e| types::ImportRowResult {
+                    row: e.row,
+                    status: types::ImportRowStatus::from(e.status) as i32,
+                    id: e.id.map(|id| id as u64),
+                    reason: e.reason,
+                })
+                .collect(),
+            created: r.created,
+            skipped: r.skipped,
+            failed: r.failed,
+        }
+    }
+}
+
+impl From<types::ImportReport> for ImportReport {
+    fn from(r: types::ImportReport) -> Self {
+        Self {
+            rows: r
+                .rows
+                .into_iter()
+                .map(|e| ImportRowResult {
+                    row: e.row,
+                    status: types::ImportRowStatus::from_i32(e.status)
+                        .unwrap_or(types::ImportRowStatus::Failed)
+                        .into(),
+                    id: e.id.map(|id| id as i64),
+                    reason: e.reason,
+                })
+                .collect(),
+            created: r.created,
+            skipped: r.skipped,
+            failed: r.failed,
+        }
+    }
+}
@@ -1,3 +1,13 @@
+pub mod address;
+pub mod category;
+pub mod contact_channel;
 pub mod customer;
+pub mod customer_history;
+pub mod import;
+pub mod note;
 pub mod order_item;
 pub mod product;
+pub mod product_image;
+pub mod shopping_list;
+pub mod supplier;
+pub mod tag;
@@ -0,0 +1,58 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::types;
+use crate::util::tools::timestamp2datetime;
+
+/// an internal comment of the staff on a customer or an order item.
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Note {
+    pub id: i64,
+    pub author: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Note> for types::Note {
+    fn from(n: Note) -> Self {
+        Self {
+            id: n.id as u64,
+            author: n.author,
+            body: n.body,
+            created_at: n.created_at.timestamp() as u64,
+        }
+    }
+}
+
+impl From<types::Note> for Note {
+    fn from(n: types::Note) -> Self {
+        Self {
+            id: n.id as i64,
+            author: n.author,
+            body: n.body,
+            created_at: timestamp2datetime(n.created_at),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum CustomerNotes {
+    Table,
+    Id,
+    CustomerId,
+    Author,
+    Body,
+    CreatedAt,
+}
+
+#[derive(Iden, Clone)]
+pub enum OrderItemNotes {
+    Table,
+    Id,
+    OrderItemId,
+    Author,
+    Body,
+    CreatedAt,
+}
@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use sea_query::Iden;
 use serde::{Deserialize, Serialize};
+use serde_repr::Deserialize_repr;
 use sqlx::postgres::PgRow;
 use sqlx::{Error, Row};
 
@@ -20,6 +21,20 @@ pub struct OrderItem {
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// the shipping address of the order item.
+    pub address_id: Option<i64>,
+}
+
+/// the status of an order item, it is stored and sent as its number.
+#[derive(Debug, Deserialize_repr, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum OrderItemStatus {
+    Picked = 0,
+    Available = 1,
+    /// waiting to be bought.
+    Ordering = 2,
+    /// it can't be bought, the customer doesn't pay for it.
+    OutOfStock = 3,
 }
 
 impl<'r> ::sqlx::FromRow<'r, PgRow> for OrderItem {
@@ -43,6 +58,8 @@ impl<'r> ::sqlx::FromRow<'r, PgRow> for OrderItem {
         let product_price: Decimal = row.try_get(12)?;
         let product_created_at: DateTime<Utc> = row.try_get(13)?;
 
+        let address_id: Option<i64> = row.try_get(14)?;
+
         let product = Product {
             id: product_id,
             name: product_name,
@@ -51,6 +68,10 @@ impl<'r> ::sqlx::FromRow<'r, PgRow> for OrderItem {
             created_at: product_created_at,
             updated_at: None,
             deleted_at: None,
+            category_id: None,
+            tags: vec![],
+            images: vec![],
+            supplier: None,
         };
 
         let customer = Customer {
@@ -60,6 +81,8 @@ impl<'r> ::sqlx::FromRow<'r, PgRow> for OrderItem {
             phone: None,
             created_at: customer_created_at,
             updated_at: None,
+            contacts: vec![],
+            notes: vec![],
         };
 
         Ok(Self {
@@ -71,6 +94,7 @@ impl<'r> ::sqlx::FromRow<'r, PgRow> for OrderItem {
             created_at,
             updated_at,
             deleted_at,
+            address_id,
         })
     }
 }
@@ -86,6 +110,7 @@ impl From<OrderItem> for order_item_pb::OrderItem {
             updated_at: o.updated_at.map(|d| d.timestamp() as u64),
             deleted_at: o.deleted_at.map(|d| d.timestamp() as u64),
             status: o.status,
+            address_id: o.address_id.map(|e| e as u64),
         }
     }
 }
@@ -101,6 +126,57 @@ impl From<order_item_pb::OrderItem> for OrderItem {
             created_at: timestamp2datetime(o.created_at),
             updated_at: o.updated_at.map(timestamp2datetime),
             deleted_at: o.deleted_at.map(timestamp2datetime),
+            address_id: o.address_id.map(|e| e as i64),
+        }
+    }
+}
+
+/// the reason why an item of a batch can't be created.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BatchItemError {
+    /// the 0-based position of the item in the batch.
+    pub index: u32,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BatchCreateOrderItemResult {
+    pub items: Vec<OrderItem>,
+    pub errors: Vec<BatchItemError>,
+}
+
+impl From<BatchItemError> for order_item_pb::batch_create_order_item_response::ItemError {
+    fn from(e: BatchItemError) -> Self {
+        Self {
+            index: e.index,
+            reason: e.reason,
+        }
+    }
+}
+
+impl From<order_item_pb::batch_create_order_item_response::ItemError> for BatchItemError {
+    fn from(e: order_item_pb::batch_create_order_item_response::ItemError) -> Self {
+        Self {
+            index: e.index,
+            reason: e.reason,
+        }
+    }
+}
+
+impl From<BatchCreateOrderItemResult> for order_item_pb::BatchCreateOrderItemResponse {
+    fn from(r: BatchCreateOrderItemResult) -> Self {
+        Self {
+            items: r.items.into_iter().map(|e| e.into()).collect(),
+            errors: r.errors.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+}
+
+impl From<order_item_pb::BatchCreateOrderItemResponse> for BatchCreateOrderItemResult {
+    fn from(r: order_item_pb::BatchCreateOrderItemResponse) -> Self {
+        Self {
+            items: r.items.into_iter().map(|e| e.into()).collect(),
+            errors: r.errors.into_iter().map(|e| e.into()).collect(),
         }
     }
 }
@@ -116,4 +192,5 @@ pub enum OrderItems {
     CreatedAt,
     UpdatedAt,
     DeletedAt,
+    AddressId,
 }
@@ -3,12 +3,15 @@ use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use sea_query::Iden;
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+use sqlx::postgres::PgRow;
+use sqlx::{Error, Row};
 
+use crate::json::product_image::ProductImage;
+use crate::json::supplier::ProductSupplier;
 use crate::util::tools::timestamp2datetime;
 use crate::{order_item_pb, product_pb};
 
-#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Product {
     pub id: i64,
     pub name: String,
@@ -17,6 +20,29 @@ pub struct Product {
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
+    pub category_id: Option<i64>,
+    pub tags: Vec<String>,
+    pub images: Vec<ProductImage>,
+    pub supplier: Option<ProductSupplier>,
+}
+
+impl<'r> ::sqlx::FromRow<'r, PgRow> for Product {
+    fn from_row(row: &'r PgRow) -> Result<Self, Error> {
+        // tags, images and supplier are stored in other tables, the product service attaches them.
+        Ok(Self {
+            id: row.try_get("id")?,
+            name: row.try_get("name")?,
+            currency: row.try_get("currency")?,
+            price: row.try_get("price")?,
+            created_at: row.try_get("created_at")?,
+            updated_at: row.try_get("updated_at")?,
+            deleted_at: row.try_get("deleted_at")?,
+            category_id: row.try_get("category_id")?,
+            tags: vec![],
+            images: vec![],
+            supplier: None,
+        })
+    }
 }
 
 impl From<Product> for product_pb::Product {
@@ -29,6 +55,10 @@ impl From<Product> for product_pb::Product {
             created_at: p.created_at.timestamp() as u64,
             updated_at: p.updated_at.map(|d| d.timestamp() as u64),
             deleted_at: p.deleted_at.map(|d| d.timestamp() as u64),
+            category_id: p.category_id.map(|e| e as u64),
+            tags: p.tags,
+            images: p.images.into_iter().map(|e| e.into()).collect(),
+            supplier: p.supplier.map(|e| e.into()),
         }
     }
 }
@@ -43,6 +73,10 @@ impl From<product_pb::Product> for Product {
             created_at: timestamp2datetime(p.created_at),
             updated_at: p.updated_at.map(timestamp2datetime),
             deleted_at: p.deleted_at.map(timestamp2datetime),
+            category_id: p.category_id.map(|e| e as i64),
+            tags: p.tags,
+            images: p.images.into_iter().map(|e| e.into()).collect(),
+            supplier: p.supplier.map(|e| e.into()),
         }
     }
 }
@@ -69,6 +103,10 @@ impl From<order_item_pb::order_item::Product> for Product {
             created_at: timestamp2datetime(p.created_at),
             updated_at: None,
             deleted_at: None,
+            category_id: None,
+            tags: vec![],
+            images: vec![],
+            supplier: None,
         }
     }
 }
@@ -83,4 +121,5 @@ pub enum Products {
     CreatedAt,
     UpdatedAt,
     DeletedAt,
+    CategoryId,
 }
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::product_pb;
+use crate::util::tools::timestamp2datetime;
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct ProductImage {
+    pub id: i64,
+    pub product_id: i64,
+    pub key: String,
+    pub url: String,
+    pub thumbnail_key: String,
+    pub thumbnail_url: String,
+    pub content_type: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ProductImage> for product_pb::ProductImage {
+    fn from(i: ProductImage) -> Self {
+        Self {
+            id: i.id as u64,
+            product_id: i.product_id as u64,
+            key: i.key,
+            url: i.url,
+            thumbnail_key: i.thumbnail_key,
+            thumbnail_url: i.thumbnail_url,
+            content_type: i.content_type,
+            created_at: i.created_at.timestamp() as u64,
+        }
+    }
+}
+
+impl From<product_pb::ProductImage> for ProductImage {
+    fn from(i: product_pb::ProductImage) -> Self {
+        Self {
+            id: i.id as i64,
+            product_id: i.product_id as i64,
+            key: i.key,
+            url: i.url,
+            thumbnail_key: i.thumbnail_key,
+            thumbnail_url: i.thumbnail_url,
+            content_type: i.content_type,
+            created_at: timestamp2datetime(i.created_at),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum ProductImages {
+    Table,
+    Id,
+    ProductId,
+    Key,
+    Url,
+    ThumbnailKey,
+    ThumbnailUrl,
+    ContentType,
+    CreatedAt,
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::order_item_pb::shopping_list_item::WaitingCustomer as WaitingCustomerPb;
+use crate::order_item_pb::ShoppingListItem as ShoppingListItemPb;
+
+/// an order item which is waiting to be bought, with its product supplier.
+#[derive(Debug, FromRow, Clone)]
+pub struct ShoppingListEntry {
+    pub order_item_id: i64,
+    pub quantity: i16,
+    pub customer_id: i64,
+    pub customer_name: String,
+    pub product_id: i64,
+    pub product_name: String,
+    pub supplier_id: Option<i64>,
+    pub supplier_name: Option<String>,
+    pub source_url: Option<String>,
+}
+
+/// the total quantity of a product to buy and the customers waiting for it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ShoppingListItem {
+    pub product_id: i64,
+    pub product_name: String,
+    pub supplier_id: Option<i64>,
+    pub supplier_name: Option<String>,
+    pub source_url: Option<String>,
+    pub quantity: u32,
+    pub customers: Vec<WaitingCustomer>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WaitingCustomer {
+    pub order_item_id: i64,
+    pub customer_id: i64,
+    pub customer_name: String,
+    pub quantity: u32,
+}
+
+impl From<ShoppingListItem> for ShoppingListItemPb {
+    fn from(i: ShoppingListItem) -> Self {
+        Self {
+            product_id: i.product_id as u64,
+            product_name: i.product_name,
+            supplier_id: i.supplier_id.map(|e| e as u64),
+            supplier_name: i.supplier_name,
+            source_url: i.source_url,
+            quantity: i.quantity,
+            customers: i.customers.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+}
+
+impl From<ShoppingListItemPb> for ShoppingListItem {
+    fn from(i: ShoppingListItemPb) -> Self {
+        Self {
+            product_id: i.product_id as i64,
+            product_name: i.product_name,
+            supplier_id: i.supplier_id.map(|e| e as i64),
+            supplier_name: i.supplier_name,
+            source_url: i.source_url,
+            quantity: i.quantity,
+            customers: i.customers.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+}
+
+impl From<WaitingCustomer> for WaitingCustomerPb {
+    fn from(c: WaitingCustomer) -> Self {
+        Self {
+            order_item_id: c.order_item_id as u64,
+            customer_id: c.customer_id as u64,
+            customer_name: c.customer_name,
+            quantity: c.quantity,
+        }
+    }
+}
+
+impl From<WaitingCustomerPb> for WaitingCustomer {
+    fn from(c: WaitingCustomerPb) -> Self {
+        Self {
+            order_item_id: c.order_item_id as i64,
+            customer_id: c.customer_id as i64,
+            customer_name: c.customer_name,
+            quantity: c.quantity,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::product_pb;
+use crate::util::tools::timestamp2datetime;
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Supplier {
+    pub id: i64,
+    pub name: String,
+    pub website: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<Supplier> for product_pb::Supplier {
+    fn from(s: Supplier) -> Self {
+        Self {
+            id: s.id as u64,
+            name: s.name,
+            website: s.website,
+            created_at: s.created_at.timestamp() as u64,
+            updated_at: s.updated_at.map(|d| d.timestamp() as u64),
+        }
+    }
+}
+
+impl From<product_pb::Supplier> for Supplier {
+    fn from(s: product_pb::Supplier) -> Self {
+        Self {
+            id: s.id as i64,
+            name: s.name,
+            website: s.website,
+            created_at: timestamp2datetime(s.created_at),
+            updated_at: s.updated_at.map(timestamp2datetime),
+        }
+    }
+}
+
+/// where a product is bought from and how much it costs.
+///
+/// the exchange rate converts the cost price to the sale currency of the product.
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct ProductSupplier {
+    pub supplier_id: i64,
+    pub supplier_name: String,
+    pub source_url: Option<String>,
+    pub cost_price: Decimal,
+    pub cost_currency: i16,
+    pub exchange_rate: Decimal,
+    /// None if the margin overflows a `Decimal`.
+    #[sqlx(default)]
+    pub margin: Option<Decimal>,
+    #[sqlx(default)]
+    pub margin_rate: Option<Decimal>,
+}
+
+impl ProductSupplier {
+    /// compute the margin of the product by its sale price.
+    ///
+    /// params:
+    /// - price: the sale price of the product.
+    ///
+    /// return:
+    /// - ProductSupplier: margin is the sale price minus the converted cost,
+    ///   margin_rate is the margin divided by the sale price.
+    ///   they are None if the margin overflows a `Decimal`.
+    pub fn with_margin(self, price: Decimal) -> Self {
+        let margin = match self
+            .cost_price
+            .checked_mul(self.exchange_rate)
+            .and_then(|cost| price.checked_sub(cost))
+        {
+            Some(margin) => margin,
+            None => {
+                tracing::warn!(
+                    message = "the margin of the supplier overflows",
+                    supplier_id = self.supplier_id
+                );
+                return Self {
+                    margin: None,
+                    margin_rate: None,
+                    ..self
+                };
+            }
+        };
+
+        // the rate of a free product is zero.
+        let margin_rate = margin
+            .checked_div(price)
+            .map(|e| e.round_dp(4))
+            .unwrap_or(Decimal::ZERO);
+
+        Self {
+            margin: Some(margin),
+            margin_rate: Some(margin_rate),
+            ..self
+        }
+    }
+}
+
+impl From<ProductSupplier> for product_pb::ProductSupplier {
+    fn from(s: ProductSupplier) -> Self {
+        Self {
+            supplier_id: s.supplier_id as u64,
+            supplier_name: s.supplier_name,
+            source_url: s.source_url,
+            cost_price: s.cost_price.to_f64().unwrap(),
+            cost_currency: s.cost_currency as i32,
+            exchange_rate: s.exchange_rate.to_f64().unwrap(),
+            margin: s.margin.and_then(|e| e.to_f64()),
+            margin_rate: s.margin_rate.and_then(|e| e.to_f64()),
+        }
+    }
+}
+
+impl From<product_pb::ProductSupplier> for ProductSupplier {
+    fn from(s: product_pb::ProductSupplier) -> Self {
+        Self {
+            supplier_id: s.supplier_id as i64,
+            supplier_name: s.supplier_name,
+            source_url: s.source_url,
+            cost_price: Decimal::from_f64(s.cost_price).unwrap(),
+            cost_currency: s.cost_currency as i16,
+            exchange_rate: Decimal::from_f64(s.exchange_rate).unwrap(),
+            margin: s.margin.and_then(Decimal::from_f64),
+            margin_rate: s.margin_rate.and_then(Decimal::from_f64),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum Suppliers {
+    Table,
+    Id,
+    Name,
+    Website,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden, Clone)]
+pub enum ProductSuppliers {
+    Table,
+    ProductId,
+    SupplierId,
+    SourceUrl,
+    CostPrice,
+    CostCurrency,
+    ExchangeRate,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn margin_converts_cost_to_sale_currency() {
+        let supplier = ProductSupplier {
+            supplier_id: 1,
+            supplier_name: "Don Quijote".to_string(),
+            source_url: None,
+            cost_price: Decimal::new(1000, 0),
+            cost_currency: 1,
+            exchange_rate: Decimal::new(5, 2),
+            margin: None,
+            margin_rate: None,
+        }
+        .with_margin(Decimal::new(80, 0));
+
+        assert_eq!(supplier.margin, Some(Decimal::new(30, 0)));
+        assert_eq!(supplier.margin_rate, Some(Decimal::new(3750, 4)));
+
+        let overflowed = ProductSupplier {
+            cost_price: Decimal::MAX,
+            exchange_rate: Decimal::new(2, 0),
+            ..supplier
+        }
+        .with_margin(Decimal::new(80, 0));
+
+        assert_eq!(overflowed.margin, None);
+        assert_eq!(overflowed.margin_rate, None);
+    }
+}
@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use sea_query::Iden;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::product_pb;
+use crate::util::tools::timestamp2datetime;
+
+#[derive(Debug, FromRow, Serialize, Deserialize, Clone)]
+pub struct Tag {
+    pub id: i64,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Tag> for product_pb::Tag {
+    fn from(t: Tag) -> Self {
+        Self {
+            id: t.id as u64,
+            name: t.name,
+            created_at: t.created_at.timestamp() as u64,
+        }
+    }
+}
+
+impl From<product_pb::Tag> for Tag {
+    fn from(t: product_pb::Tag) -> Self {
+        Self {
+            id: t.id as i64,
+            name: t.name,
+            created_at: timestamp2datetime(t.created_at),
+        }
+    }
+}
+
+#[derive(Iden, Clone)]
+pub enum Tags {
+    Table,
+    Id,
+    Name,
+    CreatedAt,
+}
+
+#[derive(Iden, Clone)]
+pub enum ProductTags {
+    Table,
+    ProductId,
+    TagId,
+}
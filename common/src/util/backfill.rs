@@ -0,0 +1,137 @@
+use anyhow::Result;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::{Pool, Postgres, Row};
+
+use crate::json::customer::Customers;
+use crate::util::sql::bind_query;
+use crate::util::tools::EXPORT_BATCH_SIZE;
+use crate::util::validation::{normalize_email, normalize_phone};
+
+/// the result of normalizing the stored contacts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ContactsBackfill {
+    /// the customers whose phone or email is rewritten.
+    pub updated: u64,
+    /// the phones and the emails which can't be normalized, they are kept as they are.
+    pub invalid: u64,
+}
+
+/// Normalize the phones and the emails of the customers which were stored before they were
+/// normalized on write, so the duplicate checks compare the values in the same format.
+///
+/// it can run again, the normalized values don't change.
+///
+/// params:
+/// - pool: the database.
+/// - default_region: the region of the national phone numbers.
+pub async fn normalize_customer_contacts(
+    pool: &Pool<Postgres>,
+    default_region: &str,
+) -> Result<ContactsBackfill> {
+    let mut backfill = ContactsBackfill::default();
+    let mut after_id = 0_i64;
+
+    loop {
+        let (sql, values) = Query::select()
+            .columns(vec![Customers::Id, Customers::Email, Customers::Phone])
+            .from(Customers::Table)
+            .and_where(Expr::col(Customers::Id).gt(after_id))
+            .order_by(Customers::Id, Order::Asc)
+            .limit(EXPORT_BATCH_SIZE)
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(pool)
+            .await?;
+
+        for row in &rows {
+            let id: i64 = row.try_get("id")?;
+            let email = normalized(row.try_get("email")?, normalize_email, &mut backfill);
+            let phone = normalized(
+                row.try_get("phone")?,
+                |e| normalize_phone(e, default_region),
+                &mut backfill,
+            );
+
+            let mut update_values = vec![];
+
+            if let Some(email) = email {
+                update_values.push((Customers::Email, email.into()));
+            }
+
+            if let Some(phone) = phone {
+                update_values.push((Customers::Phone, phone.into()));
+            }
+
+            if update_values.is_empty() {
+                continue;
+            }
+
+            let (sql, values) = Query::update()
+                .table(Customers::Table)
+                .values(update_values)
+                .and_where(Expr::col(Customers::Id).eq(id))
+                .build(PostgresQueryBuilder);
+
+            bind_query(sqlx::query(&sql), &values).execute(pool).await?;
+
+            backfill.updated += 1;
+        }
+
+        match rows.last() {
+            Some(last) if rows.len() as u64 >= EXPORT_BATCH_SIZE => {
+                after_id = last.try_get("id")?;
+            }
+            _ => return Ok(backfill),
+        }
+    }
+}
+
+/// the normalized value if it is different from the stored one,
+/// a value which can't be normalized is counted as invalid.
+fn normalized<F>(
+    value: Option<String>,
+    normalize: F,
+    backfill: &mut ContactsBackfill,
+) -> Option<String>
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    let value = value.filter(|e| !e.trim().is_empty())?;
+
+    match normalize(&value) {
+        Ok(normalized) if normalized != value => Some(normalized),
+        Ok(_) => None,
+        Err(_) => {
+            backfill.invalid += 1;
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_rewrite_the_changed_values() {
+        let mut backfill = ContactsBackfill::default();
+        let phone = |e: &str| normalize_phone(e, "TW");
+
+        assert_eq!(
+            normalized(Some("0912-345-678".to_string()), phone, &mut backfill),
+            Some("+886912345678".to_string())
+        );
+        assert_eq!(
+            normalized(Some("+886912345678".to_string()), phone, &mut backfill),
+            None
+        );
+        assert_eq!(
+            normalized(Some("call me".to_string()), phone, &mut backfill),
+            None
+        );
+        assert_eq!(normalized(None, phone, &mut backfill), None);
+
+        assert_eq!(backfill.invalid, 1);
+    }
+}
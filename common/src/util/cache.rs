@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::sync::Mutex;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use r2d2_redis::redis::Commands;
+use r2d2_redis::{r2d2, RedisConnectionManager};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::util::alias::AppResult;
+
+/// A cache keeps the serialized values by key, e.g. the detail of a product.
+#[async_trait]
+pub trait Cache: Debug + Send + Sync {
+    async fn get(&self, key: &str) -> Result<Option<String>>;
+
+    async fn set(&self, key: &str, value: String) -> Result<()>;
+
+    /// delete the values by keys. deleting a missing key is not an error.
+    async fn delete(&self, keys: Vec<String>) -> Result<()>;
+}
+
+/// Cache the values in redis, they expire after `ttl` seconds.
+#[derive(Clone)]
+pub struct RedisCache {
+    pool: r2d2::Pool<RedisConnectionManager>,
+    ttl: u64,
+}
+
+impl RedisCache {
+    pub fn new(pool: r2d2::Pool<RedisConnectionManager>, ttl: u64) -> Self {
+        Self { pool, ttl }
+    }
+}
+
+impl Debug for RedisCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RedisCache")
+            .field("ttl", &self.ttl)
+            .finish()
+    }
+}
+
+// the redis client is blocking, so the commands run on the blocking threads.
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let pool = self.pool.clone();
+        let key = key.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            Ok(conn.get(key)?)
+        })
+        .await?
+    }
+
+    async fn set(&self, key: &str, value: String) -> Result<()> {
+        let pool = self.pool.clone();
+        let key = key.to_string();
+        let ttl = self.ttl as usize;
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            Ok(conn.set_ex(key, value, ttl)?)
+        })
+        .await?
+    }
+
+    async fn delete(&self, keys: Vec<String>) -> Result<()> {
+        let pool = self.pool.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            Ok(conn.del(keys)?)
+        })
+        .await?
+    }
+}
+
+/// Keep the values in the memory of the process, they never expire.
+/// it is used as a fake of redis in tests.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    values: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        Ok(self.values.lock().unwrap().get(key).cloned())
+    }
+
+    async fn set(&self, key: &str, value: String) -> Result<()> {
+        self.values.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn delete(&self, keys: Vec<String>) -> Result<()> {
+        let mut values = self.values.lock().unwrap();
+        for key in keys {
+            values.remove(&key);
+        }
+        Ok(())
+    }
+}
+
+/// Nothing is cached, it is used when caching is disabled.
+#[derive(Debug, Default, Clone)]
+pub struct NoCache;
+
+#[async_trait]
+impl Cache for NoCache {
+    async fn get(&self, _key: &str) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    async fn set(&self, _key: &str, _value: String) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete(&self, _keys: Vec<String>) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// the key of the detail of a product.
+pub fn product_key(id: u64) -> String {
+    format!("product:{}", id)
+}
+
+/// the key which marks a product is exist.
+pub fn product_exists_key(id: u64) -> String {
+    format!("product:{}:exists", id)
+}
+
+/// all of the cached keys of a product, they are deleted when the product is changed.
+pub fn product_keys(id: u64) -> Vec<String> {
+    vec![product_key(id), product_exists_key(id)]
+}
+
+/// the key of the detail of a customer.
+pub fn customer_key(id: u64) -> String {
+    format!("customer:{}", id)
+}
+
+/// the key which marks a customer is exist.
+pub fn customer_exists_key(id: u64) -> String {
+    format!("customer:{}:exists", id)
+}
+
+/// all of the cached keys of a customer, they are deleted when the customer is changed.
+pub fn customer_keys(id: u64) -> Vec<String> {
+    vec![customer_key(id), customer_exists_key(id)]
+}
+
+/// read the value from the cache, or load it and cache it when the cache misses.
+///
+/// only the found values are cached, so a value which is created later can be read.
+/// the cache errors don't fail the request, the value is loaded as if caching is disabled.
+pub async fn read_through<T, F, Fut>(cache: &dyn Cache, key: &str, load: F) -> AppResult<Option<T>>
+where
+    T: Serialize + DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = AppResult<Option<T>>>,
+{
+    match cache.get(key).await {
+        Ok(Some(value)) => match serde_json::from_str(&value) {
+            Ok(value) => return Ok(Some(value)),
+            Err(e) => tracing::warn!(message = "can't parse the cached value", key, %e),
+        },
+        Ok(None) => {}
+        Err(e) => tracing::warn!(message = "can't read the cache", key, %e),
+    }
+
+    let value = load().await?;
+
+    if let Some(v) = &value {
+        let cached = match serde_json::to_string(v) {
+            Ok(v) => cache.set(key, v).await,
+            Err(e) => Err(e.into()),
+        };
+
+        if let Err(e) = cached {
+            tracing::warn!(message = "can't write the cache", key, %e);
+        }
+    }
+
+    Ok(value)
+}
+
+/// delete the cached values, the error is only logged because the values expire anyway.
+pub async fn invalidate(cache: &dyn Cache, keys: Vec<String>) {
+    if let Err(e) = cache.delete(keys).await {
+        tracing::warn!(message = "can't invalidate the cache", %e);
+    }
+}
+
+/// apply a change which is kept in the cached products, e.g. renaming their tag,
+/// the cached products are deleted once the change succeeds.
+///
+/// params:
+/// - cache
+/// - product_ids: the products which the change affects, they are listed before the change.
+/// - change
+///
+/// return:
+/// - the result of the change.
+pub async fn change_products<T, Fut>(
+    cache: &dyn Cache,
+    product_ids: Vec<i64>,
+    change: Fut,
+) -> AppResult<T>
+where
+    Fut: Future<Output = AppResult<T>>,
+{
+    let result = change.await?;
+
+    for id in product_ids {
+        invalidate(cache, product_keys(id as u64)).await;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn read_through_the_cache() {
+        let cache = MemoryCache::new();
+        let counter = AtomicU32::new(0);
+        let loads = &counter;
+
+        let load = move || async move {
+            loads.fetch_add(1, Ordering::SeqCst);
+            Ok(Some("pokki".to_string()))
+        };
+
+        let value = read_through(&cache, &product_key(1), load).await.unwrap();
+        assert_eq!(value, Some("pokki".to_string()));
+
+        let value = read_through(&cache, &product_key(1), load).await.unwrap();
+        assert_eq!(value, Some("pokki".to_string()));
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+
+        invalidate(&cache, product_keys(1)).await;
+
+        let _ = read_through(&cache, &product_key(1), load).await.unwrap();
+        assert_eq!(loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn missing_value_is_not_cached() {
+        let cache = MemoryCache::new();
+
+        let value = read_through(&cache, &customer_exists_key(1), || async {
+            Ok::<Option<bool>, _>(None)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, None);
+        assert_eq!(cache.get(&customer_exists_key(1)).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::{Parser, Subcommand};
+
+use crate::config::settings::{Settings, SettingsSource};
+use crate::util::backfill::normalize_customer_contacts;
+use crate::util::connections::create_database_connection;
+
+/// The command line interface which is shared by all of the binaries.
+#[derive(Debug, Clone, Parser)]
+#[clap(version)]
+pub struct Cli {
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+
+    /// the TOML file of the settings.
+    #[clap(long, global = true)]
+    pub config: Option<PathBuf>,
+
+    /// the env file of the settings, it overrides the TOML file. [default: env/.env if it exists]
+    #[clap(long, global = true)]
+    pub env_file: Option<PathBuf>,
+
+    /// the address to listen on, it overrides the host address in the settings.
+    #[clap(long, global = true)]
+    pub listen: Option<SocketAddr>,
+
+    /// the dir of the daily log files.
+    #[clap(long, global = true, default_value = "logs")]
+    pub log_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Subcommand)]
+pub enum Command {
+    /// run the server, it is the default command.
+    Serve,
+    /// apply the database migrations and normalize the stored contacts of the customers.
+    Migrate,
+    /// validate the settings and report all of the problems.
+    CheckConfig,
+    /// print the settings as an env file, the secrets are redacted.
+    PrintConfig,
+}
+
+impl Cli {
+    pub fn command(&self) -> Command {
+        self.command.unwrap_or(Command::Serve)
+    }
+
+    pub fn source(&self) -> SettingsSource {
+        SettingsSource {
+            config_file: self.config.clone(),
+            env_file: self.env_file.clone(),
+        }
+    }
+
+    /// the address to listen on, `default` is the host address in the settings.
+    pub fn listen_or(&self, default: SocketAddr) -> SocketAddr {
+        self.listen.unwrap_or(default)
+    }
+}
+
+/// run the commands which are the same in all of the binaries, `serve` is run by each binary.
+///
+/// the settings are loaded before the command runs, so `check-config` only reports
+/// they are valid, the invalid settings have been reported when they are loaded.
+pub async fn run_command(command: Command, settings: &Settings) -> Result<()> {
+    match command {
+        Command::Serve => anyhow::bail!("serve is run by the binary."),
+        Command::Migrate => {
+            let pool = create_database_connection(settings.postgres.clone()).await?;
+            sqlx::migrate!("./migrations").run(&pool).await?;
+            println!("the migrations are applied.");
+
+            let backfill =
+                normalize_customer_contacts(&pool, &settings.default_phone_region).await?;
+            pool.close().await;
+            println!(
+                "the contacts of {} customers are normalized, {} invalid contacts are kept.",
+                backfill.updated, backfill.invalid
+            );
+        }
+        Command::CheckConfig => println!("the settings are valid."),
+        Command::PrintConfig => {
+            for (key, value) in settings.to_values() {
+                println!("{}={}", key, value);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_the_arguments() {
+        let cli = Cli::try_parse_from(["customer_services"]).unwrap();
+        assert_eq!(cli.command(), Command::Serve);
+        assert_eq!(cli.log_dir, PathBuf::from("logs"));
+
+        let cli = Cli::try_parse_from([
+            "customer_services",
+            "print-config",
+            "--env-file",
+            "env/prod.env",
+            "--listen=0.0.0.0:10001",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.command(), Command::PrintConfig);
+        assert_eq!(cli.source().env_file, Some(PathBuf::from("env/prod.env")));
+        assert_eq!(cli.listen_or("127.0.0.1:1".parse().unwrap()).port(), 10001);
+
+        assert!(Cli::try_parse_from(["customer_services", "--listen", "localhost"]).is_err());
+    }
+}
@@ -1,12 +1,24 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use anyhow::Result;
-use r2d2_redis::{r2d2, RedisConnectionManager};
+use r2d2_redis::{r2d2, redis, RedisConnectionManager};
 use snowflake::SnowflakeGenerator;
 use sqlx::{Pool, Postgres};
 use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
 
+use crate::config::cache_config::CacheConfig;
 use crate::config::id_generator_config::IdGeneratorConfig;
 use crate::config::postgres_config::PostgresConfig;
 use crate::config::redis_config::RedisConfig;
+use crate::util::cache::{Cache, NoCache, RedisCache};
+
+/// how long a request waits for a redis connection, the value is loaded from the database
+/// after it, so an outage of redis doesn't stall the requests.
+const REDIS_CONNECTION_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// how long a redis command waits for reading or writing.
+const REDIS_IO_TIMEOUT: Duration = Duration::from_millis(500);
 
 /// Create a database connection.
 ///
@@ -27,21 +39,74 @@ pub async fn create_database_connection(config: PostgresConfig) -> Result<Pool<P
     Ok(pool)
 }
 
-/// Create a redis connection
+/// Create a redis connection, the server is checked by a PING.
+///
+/// r2d2 blocks while it connects, so the pool is built on the blocking threads.
 ///
 /// return: redis connection pool.
 pub async fn create_redis_connection(
     config: RedisConfig,
 ) -> Result<r2d2::Pool<RedisConnectionManager>> {
-    let redis_uri = format!(
-        "redis://{}:{}@{}:{}",
-        config.username, config.password, config.host, config.port
-    );
+    let manager = RedisConnectionManager::new(config.uri())?;
 
-    let manager = RedisConnectionManager::new(redis_uri)?;
-    let pool = r2d2::Pool::builder().build(manager)?;
+    tokio::task::spawn_blocking(move || {
+        let pool = r2d2::Pool::builder()
+            .connection_timeout(REDIS_CONNECTION_TIMEOUT)
+            .min_idle(Some(1))
+            .connection_customizer(Box::new(RedisTimeouts))
+            .build(manager)?;
 
-    Ok(pool)
+        let mut conn = pool.get()?;
+        redis::cmd("PING").query::<String>(&mut *conn)?;
+
+        Ok(pool)
+    })
+    .await?
+}
+
+/// Set the read and write timeouts of the redis connections,
+/// a command on a dead connection fails instead of blocking forever.
+#[derive(Debug)]
+struct RedisTimeouts;
+
+impl<E: 'static> r2d2::CustomizeConnection<redis::Connection, E> for RedisTimeouts {
+    fn on_acquire(&self, conn: &mut redis::Connection) -> Result<(), E> {
+        let timeouts = conn
+            .set_read_timeout(Some(REDIS_IO_TIMEOUT))
+            .and_then(|_| conn.set_write_timeout(Some(REDIS_IO_TIMEOUT)));
+
+        if let Err(e) = timeouts {
+            tracing::warn!(message = "can't set the timeouts of a redis connection", %e);
+        }
+
+        Ok(())
+    }
+}
+
+/// Create the cache of the services.
+///
+/// redis is optional, the services keep running without caching if it isn't configured or reachable.
+///
+/// return: the redis cache, or a cache which keeps nothing if caching is disabled.
+pub async fn create_cache(config: CacheConfig) -> Arc<dyn Cache> {
+    let redis = match config.redis {
+        Some(redis) if config.enabled => redis,
+        Some(_) => return Arc::new(NoCache),
+        None => {
+            if config.enabled {
+                tracing::warn!(message = "caching is enabled but redis isn't configured");
+            }
+            return Arc::new(NoCache);
+        }
+    };
+
+    match create_redis_connection(redis).await {
+        Ok(pool) => Arc::new(RedisCache::new(pool, config.ttl)),
+        Err(e) => {
+            tracing::error!(message = "can't connect to redis, caching is disabled", %e);
+            Arc::new(NoCache)
+        }
+    }
 }
 
 /// Create a id generator
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::util::validation::FieldError;
+
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum AppError {
     #[error("database error: {0}")]
@@ -8,4 +10,14 @@ pub enum AppError {
     ConnectionError(String),
     #[error("{0}")]
     BadRequest(String),
+    #[error("invalid fields: {}", field_names(.0))]
+    ValidationError(Vec<FieldError>),
+}
+
+fn field_names(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| e.field.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
@@ -0,0 +1,90 @@
+use std::future::Future;
+use std::time::Duration;
+
+use sqlx::{Pool, Postgres};
+use tonic_health::proto::health_server::{Health, HealthServer};
+use tonic_health::server::HealthReporter;
+use tonic_health::ServingStatus;
+
+use crate::util::shutdown::Shutdown;
+
+/// how often the database is checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// how long a check waits for the database.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Serve the standard grpc health checking protocol, the services are NOT_SERVING
+/// while the database is unreachable, and after the shutdown is triggered,
+/// so the load balancer stops sending requests to a draining process.
+///
+/// params:
+/// - pool: the database which the services depend on.
+/// - names: the names of the grpc services, e.g. `customer.CustomerServices`,
+///   the status of the whole server is reported by the empty name as well.
+/// - shutdown: the shutdown of the process.
+pub fn health_service(
+    pool: Pool<Postgres>,
+    names: Vec<&'static str>,
+    shutdown: &Shutdown,
+) -> HealthServer<impl Health> {
+    let (reporter, service) = tonic_health::server::health_reporter();
+
+    tokio::spawn(report_health(reporter, pool, names, shutdown.triggered()));
+
+    service
+}
+
+async fn report_health(
+    mut reporter: HealthReporter,
+    pool: Pool<Postgres>,
+    names: Vec<&'static str>,
+    shutdown: impl Future<Output = ()>,
+) {
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    let mut reachable = None;
+
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = &mut shutdown => {
+                tracing::info!(message = "shutting down, the services are not serving");
+                set_status(&mut reporter, &names, ServingStatus::NotServing).await;
+                return;
+            }
+        }
+
+        let is_reachable = is_database_reachable(&pool).await;
+
+        if reachable == Some(is_reachable) {
+            continue;
+        }
+
+        let status = if is_reachable {
+            tracing::info!(message = "the database is reachable, the services are serving");
+            ServingStatus::Serving
+        } else {
+            tracing::error!(message = "the database is unreachable, the services are not serving");
+            ServingStatus::NotServing
+        };
+
+        set_status(&mut reporter, &names, status).await;
+
+        reachable = Some(is_reachable);
+    }
+}
+
+async fn set_status(reporter: &mut HealthReporter, names: &[&'static str], status: ServingStatus) {
+    for name in std::iter::once("").chain(names.iter().copied()) {
+        reporter.set_service_status(name, status).await;
+    }
+}
+
+/// check the database by a query, a closed pool is unreachable.
+pub async fn is_database_reachable(pool: &Pool<Postgres>) -> bool {
+    let check = sqlx::query("SELECT 1").execute(pool);
+
+    matches!(tokio::time::timeout(CHECK_TIMEOUT, check).await, Ok(Ok(_)))
+}
@@ -0,0 +1,262 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use futures::future::BoxFuture;
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, Encoder,
+    HistogramVec, IntCounterVec, IntGaugeVec, TextEncoder,
+};
+use sqlx::{Pool, Postgres};
+use tonic::codegen::http;
+use tower::{Layer, Service};
+use warp::filters::path::FullPath;
+use warp::filters::BoxedFilter;
+use warp::reply::Response;
+use warp::{Filter, Rejection, Reply};
+
+use crate::util::shutdown::Shutdown;
+
+lazy_static! {
+    static ref GRPC_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "grpc_requests_total",
+        "The number of the grpc requests by method and status code.",
+        &["method", "code"]
+    )
+    .unwrap();
+    static ref GRPC_DURATION: HistogramVec = register_histogram_vec!(
+        "grpc_request_duration_seconds",
+        "The latency of the grpc requests by method.",
+        &["method"]
+    )
+    .unwrap();
+    static ref HTTP_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "http_requests_total",
+        "The number of the http requests by method, route and status.",
+        &["method", "route", "status"]
+    )
+    .unwrap();
+    static ref HTTP_DURATION: HistogramVec = register_histogram_vec!(
+        "http_request_duration_seconds",
+        "The latency of the http requests by method and route.",
+        &["method", "route"]
+    )
+    .unwrap();
+    static ref DB_POOL_CONNECTIONS: IntGaugeVec = register_int_gauge_vec!(
+        "db_pool_connections",
+        "The number of the database connections by state, idle or in_use.",
+        &["state"]
+    )
+    .unwrap();
+}
+
+/// A tonic layer which records the count and the latency of each rpc.
+///
+/// the code is read from the `grpc-status` header, which is sent by the failed unary calls.
+/// a call without it is counted as `Ok`, so an error in the trailers of a stream isn't counted.
+#[derive(Debug, Clone, Default)]
+pub struct GrpcMetricsLayer;
+
+impl<S> Layer<S> for GrpcMetricsLayer {
+    type Service = GrpcMetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcMetricsService { inner }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GrpcMetricsService<S> {
+    inner: S,
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for GrpcMetricsService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<ReqBody>) -> Self::Future {
+        let method = request.uri().path().to_string();
+        let started = Instant::now();
+        let response = self.inner.call(request);
+
+        Box::pin(async move {
+            let response = response.await;
+
+            let code = match &response {
+                Ok(response) => response
+                    .headers()
+                    .get("grpc-status")
+                    .map(|e| tonic::Code::from_bytes(e.as_bytes()))
+                    .unwrap_or(tonic::Code::Ok),
+                Err(_) => tonic::Code::Unavailable,
+            };
+
+            record_grpc(&method, code, started.elapsed());
+
+            response
+        })
+    }
+}
+
+fn record_grpc(method: &str, code: tonic::Code, elapsed: Duration) {
+    let code = format!("{:?}", code);
+
+    GRPC_REQUESTS.with_label_values(&[method, &code]).inc();
+    GRPC_DURATION
+        .with_label_values(&[method])
+        .observe(elapsed.as_secs_f64());
+}
+
+/// the route of the requests which don't match a route or are rejected, e.g. the 404 probes,
+/// so they are counted by a single series.
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// the name of the route which replies to a request, it is the route label of the http metrics.
+#[derive(Debug, Clone)]
+struct RouteName(String);
+
+/// Name the replies of the routes by their paths with the ids replaced by `:id`,
+/// the paths of the routes only have literal or numeric segments, so the names are bounded.
+pub fn matched<F, R>(routes: F) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone,
+    R: Reply,
+{
+    warp::path::full()
+        .and(routes)
+        .map(|path: FullPath, reply: R| with_route_name(route(path.as_str()), reply))
+}
+
+/// Name the replies of the routes whose paths have free segments, e.g. the file names of images.
+pub fn named<F, R>(
+    name: &'static str,
+    routes: F,
+) -> impl Filter<Extract = (Response,), Error = Rejection> + Clone
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone,
+    R: Reply,
+{
+    routes.map(move |reply: R| with_route_name(name.to_string(), reply))
+}
+
+fn with_route_name(name: String, reply: impl Reply) -> Response {
+    let mut response = reply.into_response();
+    response.extensions_mut().insert(RouteName(name));
+
+    response
+}
+
+/// Record the count and the latency of a http request, the route is the name given by
+/// `matched` or `named`, the replies of the rejections don't have one, they are `UNMATCHED_ROUTE`.
+pub async fn http_metrics<F, B, E>(
+    method: http::Method,
+    response: F,
+) -> Result<http::Response<B>, E>
+where
+    F: Future<Output = Result<http::Response<B>, E>>,
+{
+    let started = Instant::now();
+    let response = response.await;
+
+    if let Ok(response) = &response {
+        let route = response
+            .extensions()
+            .get::<RouteName>()
+            .map(|e| e.0.as_str())
+            .unwrap_or(UNMATCHED_ROUTE);
+        let status = response.status().as_u16().to_string();
+
+        HTTP_REQUESTS
+            .with_label_values(&[method.as_str(), route, &status])
+            .inc();
+        HTTP_DURATION
+            .with_label_values(&[method.as_str(), route])
+            .observe(started.elapsed().as_secs_f64());
+    }
+
+    response
+}
+
+/// the route of a matched path, the segments which contain a digit are the ids,
+/// they are replaced by `:id` to keep the number of the routes small.
+fn route(path: &str) -> String {
+    path.split('/')
+        .map(|e| {
+            if e.bytes().any(|b| b.is_ascii_digit()) && !is_version(e) {
+                ":id"
+            } else {
+                e
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// the version of an api, e.g. `v1`.
+fn is_version(segment: &str) -> bool {
+    segment.starts_with('v') && segment[1..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// render all of the metrics of the process in the prometheus text format.
+pub fn render_metrics(pool: &Pool<Postgres>) -> String {
+    let idle = pool.num_idle() as i64;
+    let size = pool.size() as i64;
+
+    DB_POOL_CONNECTIONS.with_label_values(&["idle"]).set(idle);
+    DB_POOL_CONNECTIONS
+        .with_label_values(&["in_use"])
+        .set(size - idle);
+
+    let mut buffer = vec![];
+
+    if let Err(e) = TextEncoder::new().encode(&prometheus::gather(), &mut buffer) {
+        tracing::error!(message = "can't encode the metrics", %e);
+    }
+
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+/// the `/metrics` route.
+pub fn metrics_route(pool: Pool<Postgres>) -> BoxedFilter<(impl Reply,)> {
+    warp::path!("metrics")
+        .and(warp::get())
+        .map(move || render_metrics(&pool))
+        .boxed()
+}
+
+/// serve `/metrics` on its own address until the shutdown, it is used by the grpc services
+/// which don't serve http.
+pub fn serve_metrics(addr: SocketAddr, pool: Pool<Postgres>, shutdown: &Shutdown) {
+    let (addr, server) =
+        warp::serve(metrics_route(pool)).bind_with_graceful_shutdown(addr, shutdown.triggered());
+
+    tracing::info!(message = "serving metrics", %addr);
+
+    tokio::spawn(server);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_ids_in_route() {
+        assert_eq!(route("/api/v1/customers"), "/api/v1/customers");
+        assert_eq!(
+            route("/api/v1/customers/1234/notes/5678"),
+            "/api/v1/customers/:id/notes/:id"
+        );
+        assert_eq!(route("/images/3f2a9c.png"), "/images/:id");
+    }
+}
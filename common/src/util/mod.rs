@@ -1,4 +1,16 @@
 pub mod alias;
+pub mod backfill;
+pub mod cache;
+pub mod cli;
 pub mod connections;
 pub mod errors;
+pub mod health;
+pub mod metrics;
+pub mod request_id;
+pub mod search;
+pub mod shutdown;
+pub mod sql;
+pub mod storage;
+pub mod telemetry;
 pub mod tools;
+pub mod validation;
@@ -0,0 +1,70 @@
+use std::future::Future;
+
+use tonic::codegen::http::HeaderMap;
+use uuid::Uuid;
+
+/// the header of the request id, it is the metadata key of the grpc requests as well.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// the longest request id which is accepted from a client.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// the valid request id of the headers, e.g. the one which is forwarded by the gateway.
+pub fn request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|e| e.to_str().ok())
+        .filter(|e| is_valid(e))
+}
+
+/// the request id of the client, a new one is generated if the client doesn't send a valid one.
+pub fn request_id_or_generate(headers: &HeaderMap) -> String {
+    request_id(headers)
+        .map(|e| e.to_string())
+        .unwrap_or_else(|| Uuid::new_v4().to_string())
+}
+
+/// an id is kept short and printable, so it can't break a log line or a header.
+fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'))
+}
+
+/// run the future of a request with its id, the id is read by `current_request_id`.
+pub async fn scope<F: Future>(id: String, future: F) -> F::Output {
+    REQUEST_ID.scope(id, future).await
+}
+
+/// the id of the request which is being handled, None outside of `scope`.
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|e| e.clone()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::codegen::http::HeaderValue;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn keep_the_valid_request_id() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("req-42.a:b"));
+        assert_eq!(request_id_or_generate(&headers), "req-42.a:b");
+
+        headers.insert(REQUEST_ID_HEADER, HeaderValue::from_static("a b"));
+        let generated = request_id_or_generate(&headers);
+        assert!(Uuid::parse_str(&generated).is_ok());
+
+        assert_eq!(current_request_id(), None);
+        let id = scope("req-42".to_string(), async { current_request_id() }).await;
+        assert_eq!(id, Some("req-42".to_string()));
+    }
+}
@@ -0,0 +1,321 @@
+use sea_query::{Expr, Iden, SimpleExpr};
+
+/// the romaji syllables in the Hepburn and the Kunrei-shiki romanization, longest first.
+const SYLLABLES: &[(&str, &str)] = &[
+    ("kya", "きゃ"),
+    ("kyu", "きゅ"),
+    ("kyo", "きょ"),
+    ("gya", "ぎゃ"),
+    ("gyu", "ぎゅ"),
+    ("gyo", "ぎょ"),
+    ("sha", "しゃ"),
+    ("shi", "し"),
+    ("shu", "しゅ"),
+    ("she", "しぇ"),
+    ("sho", "しょ"),
+    ("sya", "しゃ"),
+    ("syu", "しゅ"),
+    ("syo", "しょ"),
+    ("jya", "じゃ"),
+    ("jyu", "じゅ"),
+    ("jyo", "じょ"),
+    ("zya", "じゃ"),
+    ("zyu", "じゅ"),
+    ("zyo", "じょ"),
+    ("cha", "ちゃ"),
+    ("chi", "ち"),
+    ("chu", "ちゅ"),
+    ("che", "ちぇ"),
+    ("cho", "ちょ"),
+    ("tya", "ちゃ"),
+    ("tyu", "ちゅ"),
+    ("tyo", "ちょ"),
+    ("tsu", "つ"),
+    ("nya", "にゃ"),
+    ("nyu", "にゅ"),
+    ("nyo", "にょ"),
+    ("hya", "ひゃ"),
+    ("hyu", "ひゅ"),
+    ("hyo", "ひょ"),
+    ("bya", "びゃ"),
+    ("byu", "びゅ"),
+    ("byo", "びょ"),
+    ("pya", "ぴゃ"),
+    ("pyu", "ぴゅ"),
+    ("pyo", "ぴょ"),
+    ("mya", "みゃ"),
+    ("myu", "みゅ"),
+    ("myo", "みょ"),
+    ("rya", "りゃ"),
+    ("ryu", "りゅ"),
+    ("ryo", "りょ"),
+    ("ka", "か"),
+    ("ki", "き"),
+    ("ku", "く"),
+    ("ke", "け"),
+    ("ko", "こ"),
+    ("ga", "が"),
+    ("gi", "ぎ"),
+    ("gu", "ぐ"),
+    ("ge", "げ"),
+    ("go", "ご"),
+    ("sa", "さ"),
+    ("si", "し"),
+    ("su", "す"),
+    ("se", "せ"),
+    ("so", "そ"),
+    ("za", "ざ"),
+    ("ji", "じ"),
+    ("zi", "じ"),
+    ("zu", "ず"),
+    ("ze", "ぜ"),
+    ("zo", "ぞ"),
+    ("ja", "じゃ"),
+    ("ju", "じゅ"),
+    ("je", "じぇ"),
+    ("jo", "じょ"),
+    ("ta", "た"),
+    ("ti", "ち"),
+    ("tu", "つ"),
+    ("te", "て"),
+    ("to", "と"),
+    ("da", "だ"),
+    ("di", "ぢ"),
+    ("du", "づ"),
+    ("de", "で"),
+    ("do", "ど"),
+    ("na", "な"),
+    ("ni", "に"),
+    ("nu", "ぬ"),
+    ("ne", "ね"),
+    ("no", "の"),
+    ("ha", "は"),
+    ("hi", "ひ"),
+    ("fu", "ふ"),
+    ("hu", "ふ"),
+    ("he", "へ"),
+    ("ho", "ほ"),
+    ("fa", "ふぁ"),
+    ("fi", "ふぃ"),
+    ("fe", "ふぇ"),
+    ("fo", "ふぉ"),
+    ("ba", "ば"),
+    ("bi", "び"),
+    ("bu", "ぶ"),
+    ("be", "べ"),
+    ("bo", "ぼ"),
+    ("pa", "ぱ"),
+    ("pi", "ぴ"),
+    ("pu", "ぷ"),
+    ("pe", "ぺ"),
+    ("po", "ぽ"),
+    ("ma", "ま"),
+    ("mi", "み"),
+    ("mu", "む"),
+    ("me", "め"),
+    ("mo", "も"),
+    ("ya", "や"),
+    ("yu", "ゆ"),
+    ("yo", "よ"),
+    ("ra", "ら"),
+    ("ri", "り"),
+    ("ru", "る"),
+    ("re", "れ"),
+    ("ro", "ろ"),
+    ("wa", "わ"),
+    ("wo", "を"),
+    ("a", "あ"),
+    ("i", "い"),
+    ("u", "う"),
+    ("e", "え"),
+    ("o", "お"),
+];
+
+const DEFAULT_SEARCH_LIMIT: u64 = 10;
+const MAX_SEARCH_LIMIT: u64 = 50;
+
+/// the offset between a hiragana and the katakana of it, e.g. "あ" (U+3042) => "ア" (U+30A2).
+const KATAKANA_OFFSET: u32 = 0x60;
+
+/// the terms to search for a query, a romaji query also matches the names in hiragana and katakana,
+/// e.g. "pokki" => ["pokki", "ぽっき", "ポッキ"].
+pub fn search_terms(query: &str) -> Vec<String> {
+    let query = query.trim().to_string();
+
+    if query.is_empty() {
+        return vec![];
+    }
+
+    match to_hiragana(&query) {
+        Some(hiragana) => {
+            let katakana = to_katakana(&hiragana);
+            vec![query, hiragana, katakana]
+        }
+        None => vec![query],
+    }
+}
+
+/// convert romaji to hiragana, e.g. "matcha" => "まっちゃ".
+///
+/// return:
+/// - None if the text isn't romaji, e.g. it has other letters or an unknown syllable.
+pub fn to_hiragana(romaji: &str) -> Option<String> {
+    let romaji = romaji.to_lowercase();
+
+    if !romaji.chars().any(|c| c.is_ascii_alphabetic())
+        || !romaji
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || matches!(c, ' ' | '-' | '\''))
+    {
+        return None;
+    }
+
+    let chars = romaji.chars().collect::<Vec<_>>();
+    let mut kana = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        match c {
+            ' ' => {
+                kana.push(' ');
+                i += 1;
+                continue;
+            }
+            '-' => {
+                kana.push('ー');
+                i += 1;
+                continue;
+            }
+            '\'' => {
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+
+        // "n" is a syllable when it isn't followed by a vowel or "y", e.g. "hon", "kanji" and "kon'ya".
+        if c == 'n' && !matches!(next, Some('a' | 'i' | 'u' | 'e' | 'o' | 'y')) {
+            kana.push('ん');
+            i += if next == Some('n') { 2 } else { 1 };
+            continue;
+        }
+
+        // a doubled consonant is a small "tsu", e.g. "kitte" and "matcha".
+        let is_doubled = next == Some(c) || (c == 't' && next == Some('c'));
+        if is_doubled && !"aiueon".contains(c) {
+            kana.push('っ');
+            i += 1;
+            continue;
+        }
+
+        let rest = chars[i..].iter().collect::<String>();
+        let (syllable, hiragana) = SYLLABLES.iter().find(|(s, _)| rest.starts_with(s))?;
+
+        kana.push_str(hiragana);
+        i += syllable.len();
+    }
+
+    Some(kana)
+}
+
+/// convert the hiragana in the text to katakana, the other characters are kept.
+pub fn to_katakana(hiragana: &str) -> String {
+    hiragana
+        .chars()
+        .map(|c| match c {
+            'ぁ'..='ゖ' => char::from_u32(c as u32 + KATAKANA_OFFSET).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// the number of the results of a search, it is 10 by default and 50 at most.
+pub fn search_limit(limit: u64) -> u64 {
+    match limit {
+        0 => DEFAULT_SEARCH_LIMIT,
+        _ => limit.min(MAX_SEARCH_LIMIT),
+    }
+}
+
+/// the quoted name of a column with its table, e.g. `"products"."name"`.
+pub fn column_sql(table: impl Iden, column: impl Iden) -> String {
+    format!("\"{}\".\"{}\"", table.to_string(), column.to_string())
+}
+
+/// the column contains or is similar to any of the terms, it is accelerated by the trigram index of the column.
+///
+/// params:
+/// - column: the SQL of the column, see `column_sql`.
+pub fn match_expr(column: &str, terms: &[String]) -> SimpleExpr {
+    let sql = terms
+        .iter()
+        .map(|_| format!("{column} ILIKE ? OR {column} % ?", column = column))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    let values = terms
+        .iter()
+        .flat_map(|term| [like_pattern(term), term.to_string()])
+        .collect::<Vec<_>>();
+
+    Expr::cust_with_values(&format!("({})", sql), values)
+}
+
+/// the best trigram similarity between the columns and the terms, from 0 to 1.
+pub fn rank_expr(columns: &[String], terms: &[String]) -> SimpleExpr {
+    let mut similarities = vec![];
+    let mut values = vec![];
+
+    for column in columns {
+        for term in terms {
+            similarities.push(format!("similarity({}, ?)", column));
+            values.push(term.to_string());
+        }
+    }
+
+    // the similarity of a null column is null, it is ranked as 0.
+    Expr::cust_with_values(
+        &format!("COALESCE(GREATEST({}), 0)", similarities.join(", ")),
+        values,
+    )
+}
+
+/// a LIKE pattern which contains the term, the wildcards in the term are escaped.
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_romaji_to_kana() {
+        assert_eq!(to_hiragana("pokki"), Some("ぽっき".to_string()));
+        assert_eq!(to_hiragana("Matcha"), Some("まっちゃ".to_string()));
+        assert_eq!(to_hiragana("shinkansen"), Some("しんかんせん".to_string()));
+        assert_eq!(to_hiragana("kon'nichiwa"), Some("こんにちわ".to_string()));
+        assert_eq!(to_hiragana("ra-men"), Some("らーめん".to_string()));
+        assert_eq!(to_katakana("らーめん"), "ラーメン");
+
+        assert_eq!(to_hiragana("xyz"), None);
+        assert_eq!(to_hiragana("0912345678"), None);
+        assert_eq!(to_hiragana("ポッキー"), None);
+    }
+
+    #[test]
+    fn search_romaji_in_kana() {
+        assert_eq!(search_terms(" pokki "), vec!["pokki", "ぽっき", "ポッキ"]);
+        assert_eq!(search_terms("kitkat"), vec!["kitkat"]);
+        assert_eq!(search_terms("boris@example.com"), vec!["boris@example.com"]);
+        assert!(search_terms(" ").is_empty());
+    }
+}
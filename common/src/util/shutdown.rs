@@ -0,0 +1,108 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// The shutdown of a process, it is triggered by SIGINT or SIGTERM.
+///
+/// the servers stop accepting requests when it is triggered, and the in-flight requests
+/// are given the drain timeout to finish.
+#[derive(Debug, Clone)]
+pub struct Shutdown {
+    receiver: watch::Receiver<bool>,
+}
+
+impl Shutdown {
+    /// listen to the signals of the process.
+    pub fn listen() -> Self {
+        let (sender, receiver) = watch::channel(false);
+
+        tokio::spawn(async move {
+            wait_for_signal().await;
+            tracing::info!(message = "shutting down");
+            let _ = sender.send(true);
+        });
+
+        Self { receiver }
+    }
+
+    /// resolve when the shutdown is triggered, it is given to `serve_with_shutdown`.
+    pub fn triggered(&self) -> impl Future<Output = ()> + Send + 'static {
+        let mut receiver = self.receiver.clone();
+
+        async move {
+            while !*receiver.borrow() {
+                if receiver.changed().await.is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// run the server until it stops after the shutdown, the server is dropped if the in-flight
+    /// requests don't finish in `drain_timeout` after the shutdown is triggered.
+    ///
+    /// return:
+    /// - None if the requests are cut off, they may still hold the database connections,
+    ///   so the pool shouldn't be closed, the connections are dropped with the runtime.
+    pub async fn drain<F, T>(&self, server: F, drain_timeout: Duration) -> Option<T>
+    where
+        F: Future<Output = T>,
+    {
+        tokio::pin!(server);
+
+        tokio::select! {
+            output = &mut server => return Some(output),
+            _ = self.triggered() => {}
+        }
+
+        match tokio::time::timeout(drain_timeout, server).await {
+            Ok(output) => Some(output),
+            Err(_) => {
+                tracing::warn!(
+                    message = "the in-flight requests didn't finish in time",
+                    ?drain_timeout
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate()).expect("Can't listen to SIGTERM.");
+
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = terminate.recv() => {}
+    }
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn drop_the_server_after_the_drain_timeout() {
+        let (sender, receiver) = watch::channel(true);
+        let shutdown = Shutdown { receiver };
+
+        let stopped = shutdown.drain(async { 1 }, Duration::from_millis(10)).await;
+        assert_eq!(stopped, Some(1));
+
+        let stuck = shutdown
+            .drain(std::future::pending::<()>(), Duration::from_millis(10))
+            .await;
+        assert_eq!(stuck, None);
+
+        drop(sender);
+    }
+}
@@ -0,0 +1,5 @@
+// bind the values of the statements built by `sea_query::QueryStatementBuilder::build` to the sqlx queries,
+// e.g. `bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)`.
+sea_query::sea_query_driver_postgres!();
+
+pub use sea_query_driver_postgres::{bind_query, bind_query_as};
@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+
+/// An object store keeps the uploaded files (e.g. product images) by key.
+#[async_trait]
+pub trait ObjectStore: Debug + Send + Sync {
+    /// store the bytes under the key.
+    ///
+    /// return:
+    /// - url: the public url of the stored object.
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<String>;
+
+    /// delete the object by key. deleting a missing object is not an error.
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// Store the objects on local disk, they are served under the `base_url`.
+#[derive(Debug, Clone)]
+pub struct FileSystemStore {
+    root: PathBuf,
+    base_url: String,
+}
+
+impl FileSystemStore {
+    pub fn new(root: impl Into<PathBuf>, base_url: &str) -> Self {
+        Self {
+            root: root.into(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// resolve the key to a path inside the root, the key can't leave the root.
+    fn path(&self, key: &str) -> Result<PathBuf> {
+        let key = Path::new(key);
+
+        let is_valid = key.components().all(|c| matches!(c, Component::Normal(_)));
+        if !is_valid || key.as_os_str().is_empty() {
+            return Err(anyhow!("invalid object key: {}", key.display()));
+        }
+
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for FileSystemStore {
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<String> {
+        let path = self.path(key)?;
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(&path, bytes).await?;
+
+        Ok(format!("{}/{}", self.base_url, key))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let path = self.path(key)?;
+
+        match tokio::fs::remove_file(path).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_and_delete_object() {
+        let root = std::env::temp_dir().join(format!("object-store-{}", uuid::Uuid::new_v4()));
+        let store = FileSystemStore::new(&root, "/images/");
+
+        let url = store.put("products/1/a.png", vec![1, 2, 3]).await.unwrap();
+        assert_eq!(url, "/images/products/1/a.png");
+        assert_eq!(
+            tokio::fs::read(root.join("products/1/a.png"))
+                .await
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+
+        store.delete("products/1/a.png").await.unwrap();
+        assert!(!root.join("products/1/a.png").exists());
+        assert!(store.delete("products/1/a.png").await.is_ok());
+
+        let _ = tokio::fs::remove_dir_all(root).await;
+    }
+
+    #[tokio::test]
+    async fn reject_keys_outside_root() {
+        let store = FileSystemStore::new(std::env::temp_dir(), "/images");
+
+        assert!(store.put("../a.png", vec![]).await.is_err());
+        assert!(store.put("/etc/a.png", vec![]).await.is_err());
+        assert!(store.delete("").await.is_err());
+    }
+}
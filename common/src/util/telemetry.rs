@@ -0,0 +1,156 @@
+use opentelemetry::propagation::{Extractor, Injector, TextMapPropagator};
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::sdk::trace::{self, Tracer};
+use opentelemetry::sdk::Resource;
+use opentelemetry::trace::TraceError;
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use tonic::codegen::http;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tonic::service::interceptor::InterceptedService;
+use tonic::service::Interceptor;
+use tonic::transport::Channel;
+use tonic::{Request, Status};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::util::request_id::{current_request_id, request_id, REQUEST_ID_HEADER};
+
+/// the channel of the grpc clients which send the trace context and the request id.
+pub type TracedChannel = InterceptedService<Channel, TraceContextInterceptor>;
+
+/// Create the tracer which exports the spans to an OTLP collector by grpc,
+/// the trace context is propagated in the W3C `traceparent` header.
+///
+/// params:
+/// - endpoint: the collector, e.g. `http://localhost:4317`.
+/// - service_name: the name of the process which is shown in the traces.
+pub fn otlp_tracer(endpoint: &str, service_name: &str) -> Result<Tracer, TraceError> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let resource = Resource::new(vec![KeyValue::new(
+        "service.name",
+        service_name.to_string(),
+    )]);
+
+    opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint),
+        )
+        .with_trace_config(trace::config().with_resource(resource))
+        .install_batch(opentelemetry::runtime::Tokio)
+}
+
+/// the traced channel of a channel.
+pub fn traced(channel: Channel) -> TracedChannel {
+    InterceptedService::new(channel, TraceContextInterceptor)
+}
+
+/// Add the trace context of the current span and the id of the current request
+/// to the metadata of the outgoing requests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TraceContextInterceptor;
+
+impl Interceptor for TraceContextInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let context = tracing::Span::current().context();
+
+        global::get_text_map_propagator(|propagator| {
+            propagator.inject_context(&context, &mut MetadataInjector(request.metadata_mut()))
+        });
+
+        if let Some(id) = current_request_id() {
+            if let Ok(value) = MetadataValue::try_from(id.as_str()) {
+                request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+/// the span of an incoming grpc request, its parent is the trace context in the headers,
+/// so the spans of a request are in the same trace across the services.
+/// the request id of the gateway is recorded in the span, so it is in all of the logs of the request.
+///
+/// it is given to `Server::trace_fn`.
+pub fn grpc_request_span(request: &http::Request<()>) -> tracing::Span {
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+
+    let span = tracing::info_span!(
+        "grpc request",
+        path = %request.uri().path(),
+        request_id = tracing::field::Empty
+    );
+    span.set_parent(parent);
+
+    if let Some(id) = request_id(request.headers()) {
+        span.record("request_id", &id);
+    }
+
+    span
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        let key = MetadataKey::from_bytes(key.as_bytes());
+        let value = MetadataValue::try_from(value.as_str());
+
+        if let (Ok(key), Ok(value)) = (key, value) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|e| e.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|e| e.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use opentelemetry::trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId};
+    use opentelemetry::Context;
+
+    use super::*;
+
+    #[test]
+    fn propagate_trace_context_from_metadata_to_headers() {
+        let propagator = TraceContextPropagator::new();
+
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            Default::default(),
+        );
+        let context = Context::new().with_remote_span_context(span_context.clone());
+
+        let mut metadata = MetadataMap::new();
+        propagator.inject_context(&context, &mut MetadataInjector(&mut metadata));
+
+        assert_eq!(
+            metadata.get("traceparent").unwrap(),
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+
+        let headers = metadata.into_headers();
+        let extracted = propagator.extract(&HeaderExtractor(&headers));
+
+        assert_eq!(extracted.span().span_context(), &span_context);
+    }
+}
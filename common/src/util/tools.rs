@@ -1,37 +1,142 @@
+use std::future::Future;
 use std::ops::DerefMut;
+use std::path::Path;
 use std::sync::Arc;
 
-use crate::config::base_config::Config;
-use crate::config::postgres_config::PostgresConfig;
 use anyhow::Result;
+use async_trait::async_trait;
 use chrono::{DateTime, NaiveDateTime, Utc};
 use futures::lock::Mutex;
+use futures::stream::{self, BoxStream};
+use futures::{StreamExt, TryStreamExt};
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
-use tonic::Status;
-use tracing_subscriber::fmt::writer::MakeWriterExt;
+use tonic::codegen::Bytes;
+use tonic::{Code, Status};
+use tracing::Subscriber;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use tracing_subscriber::fmt::writer::{MakeWriter, MakeWriterExt};
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
 
+use crate::config::log_config::{LogConfig, LogFormat};
+use crate::config::settings::Settings;
+use crate::util::alias::AppResult;
 use crate::util::errors::AppError;
+use crate::util::telemetry::otlp_tracer;
+
+/// the number of rows which are read from the database at once by an export.
+pub const EXPORT_BATCH_SIZE: u64 = 500;
 
 /// Init tracing - show logs in console to create daily log files.
 ///
 /// params:
-/// - debug: If debug is true, the logs only show in console. Otherwise, it will create daily log file.
+/// - settings: If debug is true, the logs only show in console. Otherwise, it will create daily log file.
+///   the logs are written in the format of `log`, and the spans are exported to `otlp_endpoint`.
 /// - dir: If debug is false, daily log files will store in this dir.
-/// - prefix: log files prefix.
+/// - prefix: log files prefix, it is the service name of the exported spans as well.
 ///
-pub fn tracing_initialize(debug: bool, dir: &str, prefix: &str) {
-    if debug {
-        tracing_subscriber::fmt()
-            .with_max_level(tracing::Level::DEBUG)
+/// return:
+/// - the guard of tracing, the logs and the spans are flushed when it is dropped,
+///   so it should be kept until the process exits.
+pub fn tracing_initialize(
+    settings: &Settings,
+    dir: impl AsRef<Path>,
+    prefix: &str,
+) -> TracingGuard {
+    let tracer = settings
+        .otlp_endpoint
+        .as_deref()
+        .map(|endpoint| (endpoint, otlp_tracer(endpoint, prefix)));
+
+    let (otlp, otlp_error) = match tracer {
+        Some((_, Ok(tracer))) => (
+            Some(tracing_opentelemetry::layer().with_tracer(tracer)),
+            None,
+        ),
+        Some((endpoint, Err(e))) => (None, Some((endpoint, e))),
+        None => (None, None),
+    };
+
+    let exporting = otlp.is_some();
+    let debug = settings.base.debug;
+    let level = if debug {
+        LevelFilter::DEBUG
+    } else {
+        LevelFilter::INFO
+    };
+    let registry = tracing_subscriber::registry()
+        .with(otlp)
+        .with(log_filter(level, &settings.log));
+
+    let log = if debug {
+        registry
+            .with(log_layer(settings.log.format, std::io::stdout))
             .init();
+
+        None
     } else {
         let logfile = tracing_appender::rolling::daily(dir, prefix);
+        let (logfile, guard) = tracing_appender::non_blocking(logfile);
         let stdout = std::io::stdout.with_max_level(tracing::Level::INFO);
 
-        tracing_subscriber::fmt()
-            .with_writer(stdout.and(logfile))
+        registry
+            .with(log_layer(settings.log.format, stdout.and(logfile)))
             .init();
+
+        Some(guard)
+    };
+
+    // it is logged after the logs are set up, so it is written to the log files as well.
+    if let Some((endpoint, e)) = otlp_error {
+        tracing::warn!(message = "can't export the spans", endpoint, error = %e);
+    }
+
+    TracingGuard {
+        _log: log,
+        exporting,
+    }
+}
+
+/// the default level with the levels of the modules, the later directive of the same module wins.
+fn log_filter(level: LevelFilter, log: &LogConfig) -> EnvFilter {
+    let directives = match &log.filter {
+        Some(filter) => format!("{},{}", level, filter),
+        None => level.to_string(),
+    };
+
+    // the filter is validated when the settings are loaded.
+    EnvFilter::try_new(directives).unwrap_or_else(|_| EnvFilter::new(level.to_string()))
+}
+
+fn log_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + 'static,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer().with_writer(writer);
+
+    match format {
+        LogFormat::Text => layer.boxed(),
+        LogFormat::Json => layer.json().boxed(),
+    }
+}
+
+/// Flush the logs and the exported spans when it is dropped.
+#[derive(Debug)]
+pub struct TracingGuard {
+    /// the logs of the files are flushed when it is dropped.
+    _log: Option<WorkerGuard>,
+    exporting: bool,
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if self.exporting {
+            opentelemetry::global::shutdown_tracer_provider();
+        }
     }
 }
 
@@ -48,7 +153,8 @@ pub fn timestamp2datetime(timestamp: u64) -> DateTime<Utc> {
     DateTime::<Utc>::from_utc(native_datetime, Utc)
 }
 
-/// handle app error and parse it to grpc status
+/// handle app error and parse it to grpc status, the field errors of a validation error
+/// are sent as json in the details of an invalid argument status.
 ///
 /// params:
 /// - err: app error.
@@ -58,7 +164,14 @@ pub fn timestamp2datetime(timestamp: u64) -> DateTime<Utc> {
 pub fn grpc_error_handler(err: AppError) -> Status {
     let msg = err.to_string();
     tracing::error!(message = msg.as_str());
-    Status::failed_precondition(msg)
+
+    match err {
+        AppError::ValidationError(errors) => {
+            let details = serde_json::to_vec(&errors).unwrap_or_default();
+            Status::with_details(Code::InvalidArgument, msg, Bytes::from(details))
+        }
+        _ => Status::failed_precondition(msg),
+    }
 }
 
 /// handle database error.
@@ -107,6 +220,78 @@ pub async fn rollback_transaction(session: Arc<Mutex<PoolConnection<Postgres>>>)
     execute(session, "ROLLBACK;").await
 }
 
+/// The boundary of the transactions of a service, see `in_transaction`.
+#[async_trait]
+pub trait Transaction: Send + Sync {
+    async fn begin(&self) -> Result<bool>;
+
+    async fn commit(&self) -> Result<bool>;
+
+    async fn rollback(&self) -> Result<bool>;
+}
+
+/// the transactions of a database session.
+#[async_trait]
+impl Transaction for Arc<Mutex<PoolConnection<Postgres>>> {
+    async fn begin(&self) -> Result<bool> {
+        begin_transaction(self.clone()).await
+    }
+
+    async fn commit(&self) -> Result<bool> {
+        commit_transaction(self.clone()).await
+    }
+
+    async fn rollback(&self) -> Result<bool> {
+        rollback_transaction(self.clone()).await
+    }
+}
+
+/// A transaction which does nothing, it is the boundary of the services whose repos are fakes.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoTransaction;
+
+#[async_trait]
+impl Transaction for NoTransaction {
+    async fn begin(&self) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn commit(&self) -> Result<bool> {
+        Ok(true)
+    }
+
+    async fn rollback(&self) -> Result<bool> {
+        Ok(true)
+    }
+}
+
+/// run the statements in a transaction, it is committed if the statements succeed,
+/// otherwise it is rolled back. the statements are a future, so they don't run before
+/// the transaction begins.
+///
+/// params:
+/// - transaction: e.g. a database session, the statements must use the same session.
+/// - statements
+///
+/// return:
+/// - the result of the statements.
+pub async fn in_transaction<T>(
+    transaction: &dyn Transaction,
+    statements: impl Future<Output = Result<T>>,
+) -> AppResult<T> {
+    transaction.begin().await.map_err(database_error_handler)?;
+
+    let result = statements.await;
+
+    if result.is_ok() {
+        transaction.commit().await.map_err(database_error_handler)?;
+    } else {
+        let _ = transaction.rollback().await;
+    }
+
+    result.map_err(database_error_handler)
+}
+
 /// execute a sql in database.
 ///
 /// params:
@@ -124,63 +309,39 @@ async fn execute(session: Arc<Mutex<PoolConnection<Postgres>>>, sql: &str) -> Re
         .map(|row| row.rows_affected() > 0)?)
 }
 
-/// Read postgresql config from the env.
+/// read the rows batch by batch and flatten them into a stream,
+/// so a huge result set is never buffered in memory at once.
 ///
-/// host: read the POSTGRES_HOST value from the env.
-/// database: read the POSTGRES_DATABASE value from the env.
-/// username: read the POSTGRES_USERNAME value from the env.
-/// password: read the POSTGRES_PASSWORD value from the env.
-/// port: read the POSTGRES_PORT value from the env.
-/// max_connection: read POSTGRES_MAX_CONNECTION value from the env.
+/// params:
+/// - fetch: read at most `limit` rows whose id is greater than `after_id`, ordered by id.
+/// - id: get the id of a row.
 ///
 /// return:
-/// - PostgresConfig
-pub fn read_postgresql_config_from_env() -> PostgresConfig {
-    let host = dotenv::var("POSTGRES_HOST").expect("Can read the database host from env file.");
-
-    let database =
-        dotenv::var("POSTGRES_DATABASE").expect("Can read the database name from env file.");
+/// - the stream of the rows, it stops at the first error.
+pub fn batch_stream<T, F, Fut>(fetch: F, id: fn(&T) -> i64) -> BoxStream<'static, AppResult<T>>
+where
+    T: Send + 'static,
+    F: FnMut(i64, u64) -> Fut + Send + 'static,
+    Fut: Future<Output = AppResult<Vec<T>>> + Send + 'static,
+{
+    stream::try_unfold((fetch, Some(0)), move |(mut fetch, after_id)| async move {
+        let after_id = match after_id {
+            Some(after_id) => after_id,
+            None => return Ok(None),
+        };
 
-    let username =
-        dotenv::var("POSTGRES_USERNAME").expect("Can read the database username from env file.");
+        let rows = fetch(after_id, EXPORT_BATCH_SIZE).await?;
 
-    let password =
-        dotenv::var("POSTGRES_PASSWORD").expect("Can read the database password from env file.");
-
-    let port = dotenv::var("POSTGRES_PORT")
-        .expect("Can read the database port from env file.")
-        .parse::<u16>()
-        .expect("Can parse the database port from string to u16.");
-
-    let max_connection = dotenv::var("POSTGRES_MAX_CONNECTION")
-        .expect("Can read the database max connection from env file.")
-        .parse::<u8>()
-        .expect("Can parse the database max connection from string to u8.");
-
-    PostgresConfig::new(
-        host,
-        database,
-        username,
-        password,
-        Some(port),
-        Some(max_connection),
-    )
-}
-
-/// Read the default config from the env.
-///
-/// debug: read the DEBUG value from the env.
-/// secret_key: read the SECRET_KEY value from the env.
-///
-/// return:
-/// - Config
-pub fn read_config_from_env() -> Config {
-    let debug = dotenv::var("DEBUG")
-        .map(|e| e.parse::<bool>().ok())
-        .ok()
-        .flatten();
+        // a partial batch is the last one.
+        let next = match rows.last() {
+            Some(last) if rows.len() as u64 >= EXPORT_BATCH_SIZE => Some(id(last)),
+            _ => None,
+        };
 
-    let secret_key = dotenv::var("SECRET_KEY").expect("Can read the secret key from the env file.");
+        let batch = stream::iter(rows.into_iter().map(Ok::<T, AppError>));
 
-    Config::new(debug, secret_key)
+        Ok(Some((batch, (fetch, next))))
+    })
+    .try_flatten()
+    .boxed()
 }
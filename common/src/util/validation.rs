@@ -0,0 +1,279 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::CreateNoteRequest;
+use crate::util::alias::AppResult;
+use crate::util::errors::AppError;
+
+/// the region which is used when a phone number doesn't have a country code.
+pub const DEFAULT_PHONE_REGION: &str = "TW";
+
+/// the country calling codes of the supported regions (ISO 3166-1 alpha-2).
+const CALLING_CODES: &[(&str, &str)] = &[
+    ("AU", "61"),
+    ("CA", "1"),
+    ("CN", "86"),
+    ("DE", "49"),
+    ("FR", "33"),
+    ("GB", "44"),
+    ("HK", "852"),
+    ("JP", "81"),
+    ("KR", "82"),
+    ("MO", "853"),
+    ("MY", "60"),
+    ("SG", "65"),
+    ("TW", "886"),
+    ("US", "1"),
+];
+
+/// E.164 numbers have at most 15 digits including the country code.
+const MAX_PHONE_DIGITS: usize = 15;
+const MIN_PHONE_DIGITS: usize = 8;
+
+const MAX_EMAIL_LENGTH: usize = 254;
+const MAX_EMAIL_LOCAL_LENGTH: usize = 64;
+const MAX_DOMAIN_LABEL_LENGTH: usize = 63;
+
+const MAX_NOTE_LENGTH: usize = 2000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// collect the errors of every invalid field in a request, so they can be reported together.
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        });
+    }
+
+    /// keep the error of the field, the value is returned if it is valid.
+    pub fn check<T>(&mut self, field: &str, result: Result<T, String>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(message) => {
+                self.add(field, message);
+                None
+            }
+        }
+    }
+
+    pub fn finish(self) -> AppResult<()> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(self.errors))
+        }
+    }
+}
+
+/// get the country calling code of a region, e.g. "TW" => "886".
+pub fn calling_code(region: &str) -> Option<&'static str> {
+    CALLING_CODES
+        .iter()
+        .find(|(r, _)| r.eq_ignore_ascii_case(region))
+        .map(|(_, code)| *code)
+}
+
+/// normalize a phone number to the E.164 format, e.g. "0912-345-678" => "+886912345678".
+///
+/// params:
+/// - phone: a number starts with "+" or "00" has a country code, otherwise it is a national number.
+/// - default_region: the region of a national number.
+///
+/// return:
+/// - the E.164 number or the reason why the number is invalid.
+pub fn normalize_phone(phone: &str, default_region: &str) -> Result<String, String> {
+    let phone = phone
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')'))
+        .collect::<String>();
+
+    let (is_international, digits) = match phone.strip_prefix('+') {
+        Some(digits) => (true, digits),
+        None => match phone.strip_prefix("00") {
+            Some(digits) => (true, digits),
+            None => (false, phone.as_str()),
+        },
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err("must be a phone number.".to_string());
+    }
+
+    let digits = if is_international {
+        digits.to_string()
+    } else {
+        let code = calling_code(default_region)
+            .ok_or_else(|| format!("unknown phone region {}.", default_region))?;
+
+        // the trunk prefix isn't a part of the number in international format.
+        format!("{}{}", code, digits.trim_start_matches('0'))
+    };
+
+    if digits.starts_with('0') {
+        return Err("must start with a country code.".to_string());
+    }
+
+    if !(MIN_PHONE_DIGITS..=MAX_PHONE_DIGITS).contains(&digits.len()) {
+        return Err(format!(
+            "must have {} to {} digits including the country code.",
+            MIN_PHONE_DIGITS, MAX_PHONE_DIGITS
+        ));
+    }
+
+    Ok(format!("+{}", digits))
+}
+
+/// validate the syntax of an email address (the dot-atom form of RFC 5322),
+/// the address is trimmed and folded to lowercase.
+pub fn normalize_email(email: &str) -> Result<String, String> {
+    let email = email.trim().to_lowercase();
+
+    let (local, domain) = email
+        .rsplit_once('@')
+        .ok_or_else(|| "must be an email address.".to_string())?;
+
+    if email.len() > MAX_EMAIL_LENGTH || local.len() > MAX_EMAIL_LOCAL_LENGTH {
+        return Err("is too long.".to_string());
+    }
+
+    let is_atom_char = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c);
+    let is_valid_local = local
+        .split('.')
+        .all(|atom| !atom.is_empty() && atom.chars().all(is_atom_char));
+
+    if !is_valid_local {
+        return Err("has an invalid name before @.".to_string());
+    }
+
+    let labels = domain.split('.').collect::<Vec<_>>();
+    let is_valid_domain = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && label.len() <= MAX_DOMAIN_LABEL_LENGTH
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+
+    if !is_valid_domain {
+        return Err("has an invalid domain.".to_string());
+    }
+
+    Ok(email)
+}
+
+/// normalize an optional field, a blank value is treated as missing.
+pub fn normalize_optional<F>(value: Option<String>, normalize: F) -> Result<Option<String>, String>
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    match value {
+        Some(v) if !v.trim().is_empty() => normalize(&v).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// trim the author and the body of a note, both of them are required.
+pub fn validate_note(request: CreateNoteRequest) -> AppResult<CreateNoteRequest> {
+    let mut validator = Validator::default();
+
+    let author = request.author.trim().to_string();
+    if author.is_empty() {
+        validator.add("author", "can't be empty.");
+    }
+
+    let body = request.body.trim().to_string();
+    if body.is_empty() {
+        validator.add("body", "can't be empty.");
+    } else if body.chars().count() > MAX_NOTE_LENGTH {
+        validator.add(
+            "body",
+            format!("can't be longer than {} characters.", MAX_NOTE_LENGTH),
+        );
+    }
+
+    validator.finish()?;
+
+    Ok(CreateNoteRequest {
+        owner_id: request.owner_id,
+        author,
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_phone_numbers() {
+        assert_eq!(
+            normalize_phone("0912-345-678", "TW"),
+            Ok("+886912345678".to_string())
+        );
+        assert_eq!(
+            normalize_phone("+852 9123 4567", "TW"),
+            Ok("+85291234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone("00852 9123 4567", "TW"),
+            Ok("+85291234567".to_string())
+        );
+        assert_eq!(
+            normalize_phone("(415) 555-0100", "us"),
+            Ok("+14155550100".to_string())
+        );
+
+        assert!(normalize_phone("123", "TW").is_err());
+        assert!(normalize_phone("0912-345-abc", "TW").is_err());
+        assert!(normalize_phone("+1 234 567 890 123 456", "TW").is_err());
+        assert!(normalize_phone("0912345678", "XX").is_err());
+    }
+
+    #[test]
+    fn normalize_email_addresses() {
+        assert_eq!(
+            normalize_email(" Boris.Lok+shop@Example.COM "),
+            Ok("boris.lok+shop@example.com".to_string())
+        );
+
+        assert!(normalize_email("boris").is_err());
+        assert!(normalize_email("boris..lok@example.com").is_err());
+        assert!(normalize_email(".boris@example.com").is_err());
+        assert!(normalize_email("boris@example").is_err());
+        assert!(normalize_email("boris@-example.com").is_err());
+        assert!(normalize_email("bo ris@example.com").is_err());
+    }
+
+    #[test]
+    fn collect_field_errors() {
+        let mut validator = Validator::default();
+
+        let email = validator.check(
+            "email",
+            normalize_optional(Some(" ".to_string()), normalize_email),
+        );
+        let phone = validator.check("phone", normalize_phone("123", "TW"));
+
+        assert_eq!(email, Some(None));
+        assert_eq!(phone, None);
+
+        match validator.finish() {
+            Err(AppError::ValidationError(errors)) => {
+                assert_eq!(errors.len(), 1);
+                assert_eq!(errors[0].field, "phone");
+            }
+            _ => panic!("expect a validation error."),
+        }
+    }
+}
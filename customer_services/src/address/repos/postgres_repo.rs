@@ -0,0 +1,202 @@
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::customer_pb::{CreateAddressRequest, UpdateAddressRequest};
+use common::json::address::{Address, Addresses};
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::address::repos::repo::AddressRepo;
+use crate::ID_GENERATOR;
+
+const ADDRESS_COLUMNS: [Addresses; 13] = [
+    Addresses::Id,
+    Addresses::CustomerId,
+    Addresses::Recipient,
+    Addresses::Phone,
+    Addresses::Line1,
+    Addresses::Line2,
+    Addresses::City,
+    Addresses::Region,
+    Addresses::PostalCode,
+    Addresses::Country,
+    Addresses::IsDefault,
+    Addresses::CreatedAt,
+    Addresses::UpdatedAt,
+];
+
+pub struct AddressRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl AddressRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl AddressRepo for AddressRepoImpl {
+    async fn get(&self, id: i64) -> Result<Option<Address>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(ADDRESS_COLUMNS)
+            .from(Addresses::Table)
+            .and_where(Expr::col(Addresses::Id).eq(id))
+            .and_where(Expr::col(Addresses::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Address>(&sql), &values)
+            .fetch_optional(conn.deref_mut())
+            .await?)
+    }
+
+    async fn create(&self, request: CreateAddressRequest) -> Result<Address> {
+        let id = ID_GENERATOR.lock().unwrap().next_id();
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::insert()
+            .into_table(Addresses::Table)
+            .columns(ADDRESS_COLUMNS.into_iter().take(12).collect::<Vec<_>>())
+            .values_panic(vec![
+                id.into(),
+                request.customer_id.into(),
+                request.recipient.into(),
+                request.phone.into(),
+                request.line1.into(),
+                request.line2.into(),
+                request.city.into(),
+                request.region.into(),
+                request.postal_code.into(),
+                request.country.into(),
+                request.is_default.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(ADDRESS_COLUMNS).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Address>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn update(&self, request: UpdateAddressRequest) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let mut update_values = vec![];
+
+        if let Some(recipient) = request.recipient {
+            update_values.push((Addresses::Recipient, recipient.into()));
+        }
+
+        if let Some(line1) = request.line1 {
+            update_values.push((Addresses::Line1, line1.into()));
+        }
+
+        if let Some(city) = request.city {
+            update_values.push((Addresses::City, city.into()));
+        }
+
+        if let Some(country) = request.country {
+            update_values.push((Addresses::Country, country.into()));
+        }
+
+        if let Some(is_default) = request.is_default {
+            update_values.push((Addresses::IsDefault, is_default.into()));
+        }
+
+        // an empty value removes the optional field.
+        let optional_values = [
+            (Addresses::Phone, request.phone),
+            (Addresses::Line2, request.line2),
+            (Addresses::Region, request.region),
+            (Addresses::PostalCode, request.postal_code),
+        ];
+
+        for (col, value) in optional_values {
+            if let Some(value) = value {
+                let value = Some(value).filter(|e| !e.is_empty());
+                update_values.push((col, value.into()));
+            }
+        }
+
+        if update_values.is_empty() {
+            return Ok(false);
+        }
+
+        update_values.push((Addresses::UpdatedAt, chrono::Utc::now().into()));
+
+        let (sql, values) = Query::update()
+            .table(Addresses::Table)
+            .values(update_values)
+            .and_where(Expr::col(Addresses::Id).eq(request.id))
+            .and_where(Expr::col(Addresses::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn delete(&self, id: i64) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::update()
+            .table(Addresses::Table)
+            .values(vec![
+                (Addresses::IsDefault, false.into()),
+                (Addresses::DeletedAt, chrono::Utc::now().into()),
+            ])
+            .and_where(Expr::col(Addresses::Id).eq(id))
+            .and_where(Expr::col(Addresses::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, customer_id: i64) -> Result<Vec<Address>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(ADDRESS_COLUMNS)
+            .from(Addresses::Table)
+            .and_where(Expr::col(Addresses::CustomerId).eq(customer_id))
+            .and_where(Expr::col(Addresses::DeletedAt).is_null())
+            .order_by(Addresses::IsDefault, Order::Desc)
+            .order_by(Addresses::Id, Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Address>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn clear_default(&self, customer_id: i64) -> Result<()> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::update()
+            .table(Addresses::Table)
+            .values(vec![(Addresses::IsDefault, false.into())])
+            .and_where(Expr::col(Addresses::CustomerId).eq(customer_id))
+            .and_where(Expr::col(Addresses::IsDefault).eq(true))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::customer_pb::{CreateAddressRequest, UpdateAddressRequest};
+use common::json::address::Address;
+
+#[async_trait]
+pub trait AddressRepo {
+    async fn get(&self, id: i64) -> Result<Option<Address>>;
+
+    async fn create(&self, request: CreateAddressRequest) -> Result<Address>;
+
+    async fn update(&self, request: UpdateAddressRequest) -> Result<bool>;
+
+    /// mark the address as deleted, the order items which ship to it keep the reference.
+    async fn delete(&self, id: i64) -> Result<bool>;
+
+    /// list the addresses of the customer, the default address is the first one.
+    async fn list(&self, customer_id: i64) -> Result<Vec<Address>>;
+
+    /// the customer doesn't have a default address after it is cleared.
+    async fn clear_default(&self, customer_id: i64) -> Result<()>;
+}
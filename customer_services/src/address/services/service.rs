@@ -0,0 +1,273 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::customer_pb::{CreateAddressRequest, UpdateAddressRequest};
+use common::json::address::Address;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::{database_error_handler, in_transaction};
+use common::util::validation::{calling_code, normalize_optional, normalize_phone, Validator};
+
+use crate::address::repos::postgres_repo::AddressRepoImpl;
+use crate::address::repos::repo::AddressRepo;
+use crate::customer::repos::postgres_repo::CustomerRepoImpl;
+use crate::customer::repos::repo::CustomerRepo;
+use crate::DEFAULT_PHONE_REGION;
+
+#[async_trait]
+pub trait AddressService {
+    async fn create(&self, request: CreateAddressRequest) -> AppResult<Address>;
+
+    async fn update(&self, request: UpdateAddressRequest) -> AppResult<Address>;
+
+    /// delete the address, the oldest address becomes the default one if the default address is deleted.
+    async fn delete(&self, customer_id: i64, id: i64) -> AppResult<bool>;
+
+    async fn list(&self, customer_id: i64) -> AppResult<Vec<Address>>;
+}
+
+pub(crate) struct AddressServiceImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+    repo: Box<dyn AddressRepo + Send + Sync>,
+    customer_repo: Box<dyn CustomerRepo + Send + Sync>,
+}
+
+impl AddressServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(AddressRepoImpl::new(session.clone()));
+        let customer_repo = Box::new(CustomerRepoImpl::new(session.clone()));
+
+        Self {
+            session,
+            repo,
+            customer_repo,
+        }
+    }
+
+    async fn check_customer_is_exist(&self, customer_id: i64) -> AppResult<()> {
+        let customer = self
+            .customer_repo
+            .get(customer_id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if customer.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the customer by id {}",
+                customer_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// get the address of the customer, the address of another customer can't be changed.
+    async fn get_existing(&self, customer_id: i64, id: i64) -> AppResult<Address> {
+        self.repo
+            .get(id)
+            .await
+            .map_err(database_error_handler)?
+            .filter(|a| a.customer_id == customer_id)
+            .ok_or_else(|| AppError::BadRequest(format!("Can't find the address by id {}", id)))
+    }
+}
+
+#[async_trait]
+impl AddressService for AddressServiceImpl {
+    async fn create(&self, request: CreateAddressRequest) -> AppResult<Address> {
+        let customer_id = request.customer_id as i64;
+
+        self.check_customer_is_exist(customer_id).await?;
+
+        let mut request = validate_create(request)?;
+
+        in_transaction(&self.session, async {
+            let addresses = self.repo.list(customer_id).await?;
+
+            if addresses.is_empty() {
+                request.is_default = true;
+            } else if request.is_default {
+                self.repo.clear_default(customer_id).await?;
+            }
+
+            self.repo.create(request).await
+        })
+        .await
+    }
+
+    async fn update(&self, request: UpdateAddressRequest) -> AppResult<Address> {
+        let customer_id = request.customer_id as i64;
+        let id = request.id as i64;
+
+        let old_address = self.get_existing(customer_id, id).await?;
+
+        let request = validate_update(request, &old_address.country)?;
+
+        in_transaction(&self.session, async {
+            if request.is_default == Some(true) {
+                self.repo.clear_default(customer_id).await?;
+            }
+
+            self.repo.update(request).await
+        })
+        .await?;
+
+        self.get_existing(customer_id, id).await
+    }
+
+    async fn delete(&self, customer_id: i64, id: i64) -> AppResult<bool> {
+        let address = self.get_existing(customer_id, id).await?;
+
+        in_transaction(&self.session, async {
+            let is_deleted = self.repo.delete(id).await?;
+
+            if address.is_default {
+                let addresses = self.repo.list(customer_id).await?;
+
+                if let Some(oldest) = addresses.first() {
+                    let request = UpdateAddressRequest {
+                        id: oldest.id as u64,
+                        customer_id: customer_id as u64,
+                        is_default: Some(true),
+                        ..Default::default()
+                    };
+                    self.repo.update(request).await?;
+                }
+            }
+
+            Ok(is_deleted)
+        })
+        .await
+    }
+
+    async fn list(&self, customer_id: i64) -> AppResult<Vec<Address>> {
+        self.check_customer_is_exist(customer_id).await?;
+
+        self.repo
+            .list(customer_id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
+
+fn validate_create(request: CreateAddressRequest) -> AppResult<CreateAddressRequest> {
+    let mut validator = Validator::default();
+
+    let recipient = validate_required(&mut validator, "recipient", request.recipient);
+    let line1 = validate_required(&mut validator, "line1", request.line1);
+    let city = validate_required(&mut validator, "city", request.city);
+    let country = validator
+        .check("country", normalize_country(&request.country))
+        .unwrap_or_default();
+
+    // a national phone number is in the country of the address.
+    let phone = validator
+        .check(
+            "phone",
+            normalize_optional(request.phone, |e| {
+                normalize_phone(e, phone_region(&country))
+            }),
+        )
+        .flatten();
+
+    validator.finish()?;
+
+    Ok(CreateAddressRequest {
+        customer_id: request.customer_id,
+        recipient,
+        phone,
+        line1,
+        line2: trim_optional(request.line2),
+        city,
+        region: trim_optional(request.region),
+        postal_code: trim_optional(request.postal_code),
+        country,
+        is_default: request.is_default,
+    })
+}
+
+fn validate_update(
+    request: UpdateAddressRequest,
+    old_country: &str,
+) -> AppResult<UpdateAddressRequest> {
+    let mut validator = Validator::default();
+
+    let recipient = request
+        .recipient
+        .map(|e| validate_required(&mut validator, "recipient", e));
+    let line1 = request
+        .line1
+        .map(|e| validate_required(&mut validator, "line1", e));
+    let city = request
+        .city
+        .map(|e| validate_required(&mut validator, "city", e));
+    let country = request
+        .country
+        .and_then(|e| validator.check("country", normalize_country(&e)));
+
+    let region = phone_region(country.as_deref().unwrap_or(old_country)).to_string();
+
+    // an empty phone number removes the phone number.
+    let phone = request.phone.map(|phone| {
+        if phone.trim().is_empty() {
+            String::new()
+        } else {
+            validator
+                .check("phone", normalize_phone(&phone, &region))
+                .unwrap_or_default()
+        }
+    });
+
+    validator.finish()?;
+
+    Ok(UpdateAddressRequest {
+        recipient,
+        phone,
+        line1,
+        line2: request.line2.map(|e| e.trim().to_string()),
+        city,
+        region: request.region.map(|e| e.trim().to_string()),
+        postal_code: request.postal_code.map(|e| e.trim().to_string()),
+        country,
+        ..request
+    })
+}
+
+fn validate_required(validator: &mut Validator, field: &str, value: String) -> String {
+    let value = value.trim().to_string();
+
+    if value.is_empty() {
+        validator.add(field, "can't be empty.");
+    }
+
+    value
+}
+
+fn trim_optional(value: Option<String>) -> Option<String> {
+    value
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+}
+
+/// the country is an ISO 3166-1 alpha-2 code, e.g. "tw" => "TW".
+fn normalize_country(country: &str) -> Result<String, String> {
+    let country = country.trim().to_uppercase();
+
+    if country.len() != 2 || !country.chars().all(|c| c.is_ascii_alphabetic()) {
+        return Err("must be a 2-letter country code.".to_string());
+    }
+
+    Ok(country)
+}
+
+fn phone_region(country: &str) -> &str {
+    if calling_code(country).is_some() {
+        country
+    } else {
+        DEFAULT_PHONE_REGION.as_str()
+    }
+}
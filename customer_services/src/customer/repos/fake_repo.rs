@@ -1,7 +1,8 @@
 use crate::customer::repos::repo::CustomerRepo;
 use crate::ID_GENERATOR;
 use async_trait::async_trait;
-use common::customer_pb::{CreateCustomerRequest, UpdateCustomerRequest};
+use common::customer_pb::{ContactChannelRequest, CreateCustomerRequest, UpdateCustomerRequest};
+use common::json::contact_channel::ContactChannel;
 use common::json::customer::Customer;
 use common::types::ListRequest;
 use futures::lock::Mutex;
@@ -43,6 +44,8 @@ impl CustomerRepo for FakeCustomerRepo {
             phone: req.phone,
             created_at: chrono::Utc::now(),
             updated_at: None,
+            contacts: vec![],
+            notes: vec![],
         };
         session.insert(id, c.clone());
         return Ok(c);
@@ -54,21 +57,7 @@ impl CustomerRepo for FakeCustomerRepo {
         let end = offset + req.page_size as usize;
         let mut c = session
             .values()
-            .filter(|e| {
-                if let Some(q) = req.query.to_owned() {
-                    return e.name.to_lowercase().contains(&q.to_lowercase())
-                        || e.email
-                            .as_ref()
-                            .map(|e| e.to_lowercase().contains(&q.to_lowercase()))
-                            .unwrap_or(true)
-                        || e.phone
-                            .as_ref()
-                            .map(|e| e.to_lowercase().contains(&q.to_lowercase()))
-                            .unwrap_or(false);
-                } else {
-                    true
-                }
-            })
+            .filter(|e| is_matched(e, &req.query))
             .map(|c| c.to_owned())
             .collect::<Vec<_>>();
 
@@ -78,6 +67,41 @@ impl CustomerRepo for FakeCustomerRepo {
         Ok(c.drain(start..end).collect::<Vec<_>>())
     }
 
+    async fn search(&self, query: String, limit: u64) -> anyhow::Result<Vec<Customer>> {
+        let session = self.session.lock().await;
+        let query = Some(query);
+        let mut c = session
+            .values()
+            .filter(|e| is_matched(e, &query))
+            .map(|c| c.to_owned())
+            .collect::<Vec<_>>();
+
+        // the fake repo doesn't rank the customers by similarity.
+        c.sort_by_key(|e| e.id);
+        c.truncate(limit as usize);
+
+        Ok(c)
+    }
+
+    async fn list_after(
+        &self,
+        req: ListRequest,
+        after_id: i64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<Customer>> {
+        let session = self.session.lock().await;
+        let mut c = session
+            .values()
+            .filter(|e| e.id > after_id && is_matched(e, &req.query))
+            .map(|c| c.to_owned())
+            .collect::<Vec<_>>();
+
+        c.sort_by_key(|e| e.id);
+        c.truncate(limit as usize);
+
+        Ok(c)
+    }
+
     async fn update(&self, req: UpdateCustomerRequest) -> anyhow::Result<bool> {
         let id = req.id as i64;
         let mut session = self.session.lock().await;
@@ -115,6 +139,100 @@ impl CustomerRepo for FakeCustomerRepo {
             .count()
             >= 1)
     }
+
+    async fn list_duplicate_candidates(
+        &self,
+        _customer_id: Option<i64>,
+        _limit: u64,
+    ) -> anyhow::Result<Vec<Customer>> {
+        // the fake repo doesn't pre-filter, every customer is a candidate.
+        let session = self.session.lock().await;
+        Ok(session.values().map(|c| c.to_owned()).collect())
+    }
+
+    async fn move_dependents(&self, source_id: i64, target_id: i64) -> anyhow::Result<()> {
+        // the fake repo only stores the contact channels which belong to the customers.
+        let mut session = self.session.lock().await;
+
+        let contacts = session
+            .get_mut(&source_id)
+            .map(|c| std::mem::take(&mut c.contacts))
+            .unwrap_or_default();
+
+        if let Some(target) = session.get_mut(&target_id) {
+            target
+                .contacts
+                .extend(contacts.into_iter().map(|c| ContactChannel {
+                    customer_id: target_id,
+                    preferred: false,
+                    ..c
+                }));
+        }
+
+        Ok(())
+    }
+
+    async fn set_contacts(
+        &self,
+        customer_id: i64,
+        contacts: Vec<ContactChannelRequest>,
+    ) -> anyhow::Result<Vec<ContactChannel>> {
+        let mut session = self.session.lock().await;
+
+        let contacts = contacts
+            .into_iter()
+            .map(|c| ContactChannel {
+                id: ID_GENERATOR.lock().unwrap().next_id(),
+                customer_id,
+                channel: c.channel,
+                handle: c.handle,
+                preferred: c.preferred,
+                created_at: chrono::Utc::now(),
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(customer) = session.get_mut(&customer_id) {
+            customer.contacts = contacts.clone();
+        }
+
+        Ok(contacts)
+    }
+
+    async fn get_contacts(
+        &self,
+        customer_ids: Vec<i64>,
+    ) -> anyhow::Result<HashMap<i64, Vec<ContactChannel>>> {
+        let session = self.session.lock().await;
+
+        Ok(customer_ids
+            .into_iter()
+            .filter_map(|id| session.get(&id).map(|c| (id, c.contacts.clone())))
+            .collect())
+    }
+
+    async fn soft_delete(&self, id: i64, _merged_into: Option<i64>) -> anyhow::Result<bool> {
+        let mut session = self.session.lock().await;
+        Ok(session.remove(&id).is_some())
+    }
+}
+
+fn is_matched(c: &Customer, query: &Option<String>) -> bool {
+    if let Some(q) = query.to_owned() {
+        return c.name.to_lowercase().contains(&q.to_lowercase())
+            || c.email
+                .as_ref()
+                .map(|e| e.to_lowercase().contains(&q.to_lowercase()))
+                .unwrap_or(true)
+            || c.phone
+                .as_ref()
+                .map(|e| e.to_lowercase().contains(&q.to_lowercase()))
+                .unwrap_or(false)
+            || c.contacts
+                .iter()
+                .any(|e| e.handle.to_lowercase().contains(&q.to_lowercase()));
+    }
+
+    true
 }
 
 #[cfg(test)]
@@ -154,6 +272,7 @@ mod test {
             name: None,
             email: Some("boris.lok@gmail.com".to_string()),
             phone: Some("1234567890".to_string()),
+            contacts: None,
         };
         let res = repo.update(req).await;
         assert!(res.unwrap());
@@ -194,7 +313,12 @@ mod test {
         email: Option<String>,
         phone: Option<String>,
     ) -> anyhow::Result<Customer> {
-        let req = CreateCustomerRequest { name, email, phone };
+        let req = CreateCustomerRequest {
+            name,
+            email,
+            phone,
+            contacts: vec![],
+        };
         repo.create(req).await
     }
 }
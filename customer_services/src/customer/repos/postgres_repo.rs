@@ -1,4 +1,5 @@
 /// references: https://qiita.com/FuJino/items/08b4c3298918191eab65
+use std::collections::HashMap;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
@@ -6,18 +7,58 @@ use anyhow::Result;
 use async_trait::async_trait;
 use futures::lock::Mutex;
 use futures::FutureExt;
-use sea_query::{Cond, Query};
+use sea_query::{Cond, Order, Query};
 use sea_query::{Expr, PostgresQueryBuilder};
 use sqlx::pool::PoolConnection;
 use sqlx::{Postgres, Row};
 
-use common::customer_pb::{CreateCustomerRequest, UpdateCustomerRequest};
+use common::customer_pb::{ContactChannelRequest, CreateCustomerRequest, UpdateCustomerRequest};
+use common::json::address::Addresses;
+use common::json::contact_channel::{ContactChannel, ContactChannels};
 use common::json::customer::{Customer, Customers};
+use common::json::note::CustomerNotes;
+use common::json::order_item::OrderItems;
 use common::types::ListRequest;
+use common::util::search::{column_sql, match_expr, rank_expr, search_terms};
+use common::util::sql::{bind_query, bind_query_as};
 
 use crate::customer::repos::repo::CustomerRepo;
 use crate::ID_GENERATOR;
 
+/// the customers which have the same phone, the same email or a similar name (`%` of pg_trgm)
+/// as the customer, the customer itself is the first one.
+const CANDIDATES_OF_CUSTOMER_SQL: &str = r#"
+SELECT c.id, c.name, c.email, c.phone, c.created_at, c.updated_at
+FROM customers c
+JOIN customers t ON t.id = $1
+WHERE c.deleted_at IS NULL
+  AND (c.id = t.id
+    OR c.phone = t.phone
+    OR lower(trim(c.email)) = lower(trim(t.email))
+    OR c.name % t.name)
+ORDER BY c.id = t.id DESC, c.id
+LIMIT $2
+"#;
+
+/// the customers of the pairs which have the same phone, the same email or a similar name,
+/// it scans the whole table so the pairs are limited.
+const CANDIDATE_PAIRS_SQL: &str = r#"
+WITH pairs AS (
+    SELECT a.id AS a_id, b.id AS b_id
+    FROM customers a
+    JOIN customers b ON a.id < b.id
+        AND (a.phone = b.phone
+          OR lower(trim(a.email)) = lower(trim(b.email))
+          OR a.name % b.name)
+    WHERE a.deleted_at IS NULL
+      AND b.deleted_at IS NULL
+    LIMIT $1
+)
+SELECT id, name, email, phone, created_at, updated_at
+FROM customers
+WHERE id IN (SELECT a_id FROM pairs UNION SELECT b_id FROM pairs)
+"#;
+
 pub struct CustomerRepoImpl {
     session: Arc<Mutex<PoolConnection<Postgres>>>,
 }
@@ -26,13 +67,48 @@ impl CustomerRepoImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
         Self { session }
     }
+
+    /// the customers whose name, email, phone or the handle of a contact channel matches the query.
+    fn query_cond(query: Option<String>) -> Option<Cond> {
+        let terms = search_terms(&query?);
+
+        if terms.is_empty() {
+            return None;
+        }
+
+        let contacts = Query::select()
+            .column(ContactChannels::CustomerId)
+            .from(ContactChannels::Table)
+            .and_where(match_expr(
+                &column_sql(ContactChannels::Table, ContactChannels::Handle),
+                &terms,
+            ))
+            .take();
+
+        let cond = Self::search_columns()
+            .iter()
+            .fold(Cond::any(), |cond, column| {
+                cond.add(match_expr(column, &terms))
+            })
+            .add(Expr::col(Customers::Id).in_subquery(contacts));
+
+        Some(cond)
+    }
+
+    fn search_columns() -> Vec<String> {
+        vec![
+            column_sql(Customers::Table, Customers::Name),
+            column_sql(Customers::Table, Customers::Email),
+            column_sql(Customers::Table, Customers::Phone),
+        ]
+    }
 }
 
 #[async_trait]
 impl CustomerRepo for CustomerRepoImpl {
     async fn get(&self, id: i64) -> Result<Option<Customer>> {
         let mut conn = self.session.lock().await;
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns(vec![
                 Customers::Id,
                 Customers::Name,
@@ -43,9 +119,10 @@ impl CustomerRepo for CustomerRepoImpl {
             ])
             .from(Customers::Table)
             .and_where(Expr::col(Customers::Id).eq(id))
-            .to_string(PostgresQueryBuilder);
+            .and_where(Expr::col(Customers::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
 
-        return Ok(sqlx::query_as::<_, Customer>(&sql)
+        return Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await?);
     }
@@ -72,25 +149,24 @@ impl CustomerRepo for CustomerRepoImpl {
             Customers::UpdatedAt,
         ];
 
-        let sql = Query::insert()
+        let (sql, values) = Query::insert()
             .into_table(Customers::Table)
             .columns(cols.clone().into_iter().take(5).collect::<Vec<_>>())
             .values_panic(vec![id.into(), name, email, phone, created_at])
             .returning(Query::select().columns(cols).take())
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        return Ok(sqlx::query_as::<_, Customer>(&sql)
+        return Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
             .fetch_one(conn.deref_mut())
             .await?);
     }
 
     async fn list(&self, request: ListRequest) -> Result<Vec<Customer>> {
         let mut conn = self.session.lock().await;
-        let query = request.query.map(|q| format!("%{}%", q));
         let page_size = request.page_size as u64;
         let offset = request.page as u64 * page_size;
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns(vec![
                 Customers::Id,
                 Customers::Name,
@@ -100,21 +176,83 @@ impl CustomerRepo for CustomerRepoImpl {
                 Customers::UpdatedAt,
             ])
             .cond_where(
-                Cond::any()
-                    .add_option(query.clone().map(|e| Expr::col(Customers::Name).like(&e)))
-                    .add_option(query.clone().map(|e| Expr::col(Customers::Email).like(&e)))
-                    .add_option(query.map(|e| Expr::col(Customers::Phone).like(&e))),
+                Cond::all()
+                    .add_option(Self::query_cond(request.query))
+                    .add(Expr::col(Customers::DeletedAt).is_null()),
             )
             .from(Customers::Table)
             .offset(offset)
             .limit(page_size)
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        return Ok(sqlx::query_as::<_, Customer>(&sql)
+        return Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
             .fetch_all(&mut *conn)
             .await?);
     }
 
+    async fn search(&self, query: String, limit: u64) -> Result<Vec<Customer>> {
+        let mut conn = self.session.lock().await;
+
+        let terms = search_terms(&query);
+
+        let (sql, values) = Query::select()
+            .columns(vec![
+                Customers::Id,
+                Customers::Name,
+                Customers::Email,
+                Customers::Phone,
+                Customers::CreatedAt,
+                Customers::UpdatedAt,
+            ])
+            .cond_where(
+                Cond::all()
+                    .add_option(Self::query_cond(Some(query)))
+                    .add(Expr::col(Customers::DeletedAt).is_null()),
+            )
+            .from(Customers::Table)
+            .order_by_expr(rank_expr(&Self::search_columns(), &terms), Order::Desc)
+            .order_by(Customers::Id, Order::Desc)
+            .limit(limit)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_after(
+        &self,
+        request: ListRequest,
+        after_id: i64,
+        limit: u64,
+    ) -> Result<Vec<Customer>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(vec![
+                Customers::Id,
+                Customers::Name,
+                Customers::Email,
+                Customers::Phone,
+                Customers::CreatedAt,
+                Customers::UpdatedAt,
+            ])
+            .cond_where(
+                Cond::all()
+                    .add_option(Self::query_cond(request.query))
+                    .add(Expr::col(Customers::DeletedAt).is_null())
+                    .add(Expr::col(Customers::Id).gt(after_id)),
+            )
+            .from(Customers::Table)
+            .order_by(Customers::Id, Order::Asc)
+            .limit(limit)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
     async fn update(&self, request: UpdateCustomerRequest) -> Result<bool> {
         let mut conn = self.session.lock().await;
         let mut update_values = vec![];
@@ -135,13 +273,13 @@ impl CustomerRepo for CustomerRepoImpl {
             return Ok(false);
         }
 
-        let sql = Query::update()
+        let (sql, values) = Query::update()
             .table(Customers::Table)
             .values(update_values)
             .and_where(Expr::col(Customers::Id).eq(request.id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        return Ok(sqlx::query(&sql)
+        return Ok(bind_query(sqlx::query(&sql), &values)
             .execute(conn.deref_mut())
             .await
             .map(|e| e.rows_affected() > 0)?);
@@ -158,16 +296,211 @@ impl CustomerRepo for CustomerRepoImpl {
             return Ok(false);
         }
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns(vec![Customers::Id])
             .from(Customers::Table)
             .and_where_option(phone.map(|phone| Expr::col(Customers::Phone).eq(phone)))
             .and_where_option(email.map(|email| Expr::col(Customers::Email).eq(email)))
-            .to_string(PostgresQueryBuilder);
+            .and_where(Expr::col(Customers::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query(&sql)
+        Ok(bind_query(sqlx::query(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await
             .map(|row| row.map_or_else(|| false, |e| e.len() > 0))?)
     }
+
+    async fn list_duplicate_candidates(
+        &self,
+        customer_id: Option<i64>,
+        limit: u64,
+    ) -> Result<Vec<Customer>> {
+        let mut conn = self.session.lock().await;
+
+        let query = match customer_id {
+            Some(id) => sqlx::query_as::<_, Customer>(CANDIDATES_OF_CUSTOMER_SQL).bind(id),
+            None => sqlx::query_as::<_, Customer>(CANDIDATE_PAIRS_SQL),
+        };
+
+        Ok(query.bind(limit as i64).fetch_all(conn.deref_mut()).await?)
+    }
+
+    async fn move_dependents(&self, source_id: i64, target_id: i64) -> Result<()> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::update()
+            .table(OrderItems::Table)
+            .values(vec![
+                (OrderItems::CustomerId, target_id.into()),
+                (OrderItems::UpdatedAt, chrono::Utc::now().into()),
+            ])
+            .and_where(Expr::col(OrderItems::CustomerId).eq(source_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        // the target customer keeps its own default address and preferred channel.
+        let (sql, values) = Query::update()
+            .table(Addresses::Table)
+            .values(vec![
+                (Addresses::CustomerId, target_id.into()),
+                (Addresses::IsDefault, false.into()),
+            ])
+            .and_where(Expr::col(Addresses::CustomerId).eq(source_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        let (sql, values) = Query::update()
+            .table(ContactChannels::Table)
+            .values(vec![
+                (ContactChannels::CustomerId, target_id.into()),
+                (ContactChannels::Preferred, false.into()),
+            ])
+            .and_where(Expr::col(ContactChannels::CustomerId).eq(source_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        let (sql, values) = Query::update()
+            .table(CustomerNotes::Table)
+            .values(vec![(CustomerNotes::CustomerId, target_id.into())])
+            .and_where(Expr::col(CustomerNotes::CustomerId).eq(source_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn set_contacts(
+        &self,
+        customer_id: i64,
+        contacts: Vec<ContactChannelRequest>,
+    ) -> Result<Vec<ContactChannel>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(ContactChannels::Table)
+            .and_where(Expr::col(ContactChannels::CustomerId).eq(customer_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        if contacts.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = {
+            let mut generator = ID_GENERATOR.lock().unwrap();
+            (0..contacts.len())
+                .map(|_| generator.next_id())
+                .collect::<Vec<_>>()
+        };
+
+        let now = chrono::Utc::now();
+
+        let cols = vec![
+            ContactChannels::Id,
+            ContactChannels::CustomerId,
+            ContactChannels::Channel,
+            ContactChannels::Handle,
+            ContactChannels::Preferred,
+            ContactChannels::CreatedAt,
+        ];
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(ContactChannels::Table)
+            .columns(cols.clone())
+            .returning(Query::select().columns(cols).take());
+
+        for (id, contact) in ids.into_iter().zip(contacts) {
+            insert.values_panic(vec![
+                id.into(),
+                customer_id.into(),
+                contact.channel.into(),
+                contact.handle.into(),
+                contact.preferred.into(),
+                now.into(),
+            ]);
+        }
+
+        let (sql, values) = insert.build(PostgresQueryBuilder);
+
+        Ok(
+            bind_query_as(sqlx::query_as::<_, ContactChannel>(&sql), &values)
+                .fetch_all(conn.deref_mut())
+                .await?,
+        )
+    }
+
+    async fn get_contacts(
+        &self,
+        customer_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, Vec<ContactChannel>>> {
+        if customer_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(vec![
+                ContactChannels::Id,
+                ContactChannels::CustomerId,
+                ContactChannels::Channel,
+                ContactChannels::Handle,
+                ContactChannels::Preferred,
+                ContactChannels::CreatedAt,
+            ])
+            .from(ContactChannels::Table)
+            .and_where(Expr::col(ContactChannels::CustomerId).is_in(customer_ids))
+            .order_by(ContactChannels::Preferred, Order::Desc)
+            .order_by(ContactChannels::Id, Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query_as(sqlx::query_as::<_, ContactChannel>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        let mut contacts: HashMap<i64, Vec<ContactChannel>> = HashMap::new();
+        for contact in rows {
+            contacts
+                .entry(contact.customer_id)
+                .or_default()
+                .push(contact);
+        }
+
+        Ok(contacts)
+    }
+
+    async fn soft_delete(&self, id: i64, merged_into: Option<i64>) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::update()
+            .table(Customers::Table)
+            .values(vec![
+                (Customers::DeletedAt, chrono::Utc::now().into()),
+                (Customers::MergedInto, merged_into.into()),
+            ])
+            .and_where(Expr::col(Customers::Id).eq(id))
+            .and_where(Expr::col(Customers::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
 }
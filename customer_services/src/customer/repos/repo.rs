@@ -1,7 +1,10 @@
+use std::collections::HashMap;
+
 use anyhow::Result;
 use async_trait::async_trait;
 
-use common::customer_pb::{CreateCustomerRequest, UpdateCustomerRequest};
+use common::customer_pb::{ContactChannelRequest, CreateCustomerRequest, UpdateCustomerRequest};
+use common::json::contact_channel::ContactChannel;
 use common::json::customer::Customer;
 use common::types::ListRequest;
 
@@ -10,10 +13,42 @@ pub(crate) trait CustomerRepo {
     async fn get(&self, id: i64) -> Result<Option<Customer>>;
     async fn create(&self, req: CreateCustomerRequest) -> Result<Customer>;
     async fn list(&self, req: ListRequest) -> Result<Vec<Customer>>;
+    /// find the customers which match the query, the most similar customer is the first one.
+    async fn search(&self, query: String, limit: u64) -> Result<Vec<Customer>>;
+    /// list the customers whose id is greater than `after_id`, ordered by id.
+    async fn list_after(
+        &self,
+        req: ListRequest,
+        after_id: i64,
+        limit: u64,
+    ) -> Result<Vec<Customer>>;
     async fn update(&self, req: UpdateCustomerRequest) -> Result<bool>;
     async fn check_customer_is_exist(
         &self,
         phone: Option<String>,
         email: Option<String>,
     ) -> Result<bool>;
+    /// list the customers which may be the same person, they have the same phone, the same
+    /// email or a similar name. the customer itself is the first one if `customer_id` is given,
+    /// otherwise the customers of at most `limit` pairs are listed.
+    async fn list_duplicate_candidates(
+        &self,
+        customer_id: Option<i64>,
+        limit: u64,
+    ) -> Result<Vec<Customer>>;
+    /// move the rows which belong to the source customer (e.g. order items, addresses, contact channels and notes) to the target customer.
+    async fn move_dependents(&self, source_id: i64, target_id: i64) -> Result<()>;
+    /// replace all of the contact channels of the customer.
+    async fn set_contacts(
+        &self,
+        customer_id: i64,
+        contacts: Vec<ContactChannelRequest>,
+    ) -> Result<Vec<ContactChannel>>;
+    /// get the contact channels of the customers, grouped by customer id.
+    async fn get_contacts(
+        &self,
+        customer_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, Vec<ContactChannel>>>;
+    /// mark the customer as deleted, `merged_into` is the customer it is merged into.
+    async fn soft_delete(&self, id: i64, merged_into: Option<i64>) -> Result<bool>;
 }
@@ -0,0 +1,215 @@
+use common::json::customer::{Customer, CustomerDuplicate};
+
+/// the min number of digits to match a phone number without its country code.
+const MIN_PHONE_DIGITS: usize = 8;
+
+/// find the customers which look like the same person.
+///
+/// params:
+/// - customers: the customers to compare with each other.
+/// - customer_id: only find the duplicates of this customer.
+///
+/// return:
+/// - the duplicates, the older customer of a pair is the `customer`.
+pub(crate) fn find_duplicates(
+    customers: &[Customer],
+    customer_id: Option<i64>,
+) -> Vec<CustomerDuplicate> {
+    let mut customers = customers.to_vec();
+    customers.sort_by_key(|c| c.id);
+
+    let mut duplicates = vec![];
+
+    for (i, a) in customers.iter().enumerate() {
+        for b in customers.iter().skip(i + 1) {
+            if let Some(id) = customer_id {
+                if a.id != id && b.id != id {
+                    continue;
+                }
+            }
+
+            let reasons = match_reasons(a, b);
+            if reasons.is_empty() {
+                continue;
+            }
+
+            // the requested customer is always the `customer` of its duplicates.
+            let (customer, duplicate) = match customer_id {
+                Some(id) if b.id == id => (b.clone(), a.clone()),
+                _ => (a.clone(), b.clone()),
+            };
+
+            duplicates.push(CustomerDuplicate {
+                customer,
+                duplicate,
+                reasons,
+            });
+        }
+    }
+
+    duplicates
+}
+
+fn match_reasons(a: &Customer, b: &Customer) -> Vec<String> {
+    let mut reasons = vec![];
+
+    if let (Some(x), Some(y)) = (&a.phone, &b.phone) {
+        if is_same_phone(x, y) {
+            reasons.push("phone".to_string());
+        }
+    }
+
+    if let (Some(x), Some(y)) = (&a.email, &b.email) {
+        let (x, y) = (x.trim().to_lowercase(), y.trim().to_lowercase());
+        if !x.is_empty() && x == y {
+            reasons.push("email".to_string());
+        }
+    }
+
+    if is_similar_name(&a.name, &b.name) {
+        reasons.push("name".to_string());
+    }
+
+    reasons
+}
+
+/// compare the digits of the phone numbers, a number without its country code
+/// matches the number with it.
+fn is_same_phone(a: &str, b: &str) -> bool {
+    let a = a.chars().filter(|c| c.is_ascii_digit()).collect::<String>();
+    let b = b.chars().filter(|c| c.is_ascii_digit()).collect::<String>();
+
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    short == long || (short.len() >= MIN_PHONE_DIGITS && long.ends_with(&short))
+}
+
+/// the names are similar if the shorter name abbreviates the longer one
+/// (e.g. "Boris", "Boris L." and "boris lok") or they only differ by a typo.
+fn is_similar_name(a: &str, b: &str) -> bool {
+    let a = name_tokens(a);
+    let b = name_tokens(b);
+
+    if a.is_empty() || b.is_empty() {
+        return false;
+    }
+
+    if levenshtein(&a.join(" "), &b.join(" ")) <= max_typos(&a.join(" ")) {
+        return true;
+    }
+
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+
+    if levenshtein(&short[0], &long[0]) > max_typos(&short[0]) {
+        return false;
+    }
+
+    short
+        .iter()
+        .zip(long.iter())
+        .skip(1)
+        .all(|(s, l)| l.starts_with(s.as_str()))
+}
+
+fn name_tokens(name: &str) -> Vec<String> {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .split_whitespace()
+        .map(|e| e.to_string())
+        .collect()
+}
+
+/// the number of typos which are allowed in a name, short names must be the same.
+fn max_typos(name: &str) -> usize {
+    match name.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(current)
+            };
+            previous = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn customer(id: i64, name: &str, email: Option<&str>, phone: Option<&str>) -> Customer {
+        Customer {
+            id,
+            name: name.to_string(),
+            email: email.map(|e| e.to_string()),
+            phone: phone.map(|e| e.to_string()),
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+            contacts: vec![],
+            notes: vec![],
+        }
+    }
+
+    #[test]
+    fn find_similar_customers() {
+        let customers = vec![
+            customer(1, "Boris", None, Some("+852 9123 4567")),
+            customer(2, "boris lok", Some("Boris@Example.com"), Some("91234567")),
+            customer(3, "Boris L.", Some("boris@example.com "), None),
+            customer(4, "Alice", None, Some("9876 5432")),
+        ];
+
+        let duplicates = find_duplicates(&customers, None);
+        let pairs = duplicates
+            .iter()
+            .map(|d| (d.customer.id, d.duplicate.id, d.reasons.join(",")))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (1, 2, "phone,name".to_string()),
+                (1, 3, "name".to_string()),
+                (2, 3, "email,name".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn find_duplicates_of_a_customer() {
+        let customers = vec![
+            customer(1, "Boris", None, None),
+            customer(2, "Borris", None, None),
+            customer(3, "Bob", None, None),
+        ];
+
+        let duplicates = find_duplicates(&customers, Some(2));
+
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].customer.id, 2);
+        assert_eq!(duplicates[0].duplicate.id, 1);
+    }
+}
@@ -1,29 +1,40 @@
 use anyhow::Result;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
 use sqlx::pool::PoolConnection;
 use sqlx::{Pool, Postgres};
 use std::sync::Arc;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use tracing::instrument;
 
 use common::customer_pb::customer_services_server::CustomerServices;
 use common::customer_pb::{
-    CreateCustomerRequest, Customer, GetCustomerResponse, ListCustomerResponse,
-    UpdateCustomerRequest,
+    Address, CreateAddressRequest, CreateCustomerRequest, Customer, CustomerDuplicate,
+    DeleteAddressRequest, GetCustomerResponse, ListAddressesRequest, ListAddressesResponse,
+    ListCustomerResponse, ListDuplicatesRequest, ListDuplicatesResponse, MergeCustomersRequest,
+    UpdateAddressRequest, UpdateCustomerRequest,
 };
-use common::types::{GetByIdRequest, ListRequest};
+use common::types::{
+    CreateNoteRequest, DeleteNoteRequest, DeleteResponse, GetByIdRequest, ImportReport,
+    ListNotesResponse, ListRequest, Note, SearchRequest,
+};
+use common::util::cache::{customer_key, customer_keys, invalidate, read_through, Cache};
 use common::util::tools::grpc_error_handler;
 
+use crate::address::services::service::{AddressService, AddressServiceImpl};
 use crate::customer::services::service::{CustomerService, CustomerServiceImpl};
+use crate::note::services::service::{NoteService, NoteServiceImpl};
 
 #[derive(Debug)]
 pub(crate) struct GrpcCustomerServicesImpl {
     pool: Pool<Postgres>,
+    cache: Arc<dyn Cache>,
 }
 
 impl GrpcCustomerServicesImpl {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, cache: Arc<dyn Cache>) -> Self {
+        Self { pool, cache }
     }
 
     async fn get_session(&self) -> Result<Arc<Mutex<PoolConnection<Postgres>>>> {
@@ -35,6 +46,8 @@ impl GrpcCustomerServicesImpl {
 
 #[tonic::async_trait]
 impl CustomerServices for GrpcCustomerServicesImpl {
+    type exportStream = BoxStream<'static, Result<Customer, Status>>;
+
     // TODO: handle get_session error.
     #[instrument]
     async fn create(
@@ -63,12 +76,12 @@ impl CustomerServices for GrpcCustomerServicesImpl {
 
         let services = CustomerServiceImpl::new(session);
 
-        services
-            .update(request)
-            .await
-            .map(|e| e.into())
-            .map(Response::new)
-            .map_err(grpc_error_handler)
+        let id = request.id;
+        let customer = services.update(request).await.map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), customer_keys(id)).await;
+
+        Ok(Response::new(customer.into()))
     }
 
     #[instrument]
@@ -79,20 +92,54 @@ impl CustomerServices for GrpcCustomerServicesImpl {
         let id = request.into_inner().id;
         let session = self.get_session().await.unwrap();
 
+        let services = CustomerServiceImpl::new(session.clone());
+
+        let customer = read_through(self.cache.as_ref(), &customer_key(id), || {
+            services.get(id as i64)
+        })
+        .await
+        .map_err(grpc_error_handler)?;
+
+        // the detail of a customer includes the notes of the staff.
+        let customer = match customer {
+            Some(mut c) => {
+                c.notes = NoteServiceImpl::new(session)
+                    .list(c.id)
+                    .await
+                    .map_err(grpc_error_handler)?;
+                Some(c.into())
+            }
+            None => None,
+        };
+
+        Ok(Response::new(GetCustomerResponse { customer }))
+    }
+
+    #[instrument]
+    async fn list(
+        &self,
+        request: Request<ListRequest>,
+    ) -> Result<Response<ListCustomerResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
         let services = CustomerServiceImpl::new(session);
 
         services
-            .get(id as i64)
+            .list(request)
             .await
-            .map(|s| s.map(|e| e.into()))
-            .map(|c| Response::new(GetCustomerResponse { customer: c }))
+            .map(|e| {
+                let c = e.into_iter().map(|e| e.into()).collect::<_>();
+                ListCustomerResponse { customers: c }
+            })
+            .map(Response::new)
             .map_err(grpc_error_handler)
     }
 
     #[instrument]
-    async fn list(
+    async fn search(
         &self,
-        request: Request<ListRequest>,
+        request: Request<SearchRequest>,
     ) -> Result<Response<ListCustomerResponse>, Status> {
         let request = request.into_inner();
         let session = self.get_session().await.unwrap();
@@ -100,7 +147,7 @@ impl CustomerServices for GrpcCustomerServicesImpl {
         let services = CustomerServiceImpl::new(session);
 
         services
-            .list(request)
+            .search(request)
             .await
             .map(|e| {
                 let c = e.into_iter().map(|e| e.into()).collect::<_>();
@@ -109,4 +156,198 @@ impl CustomerServices for GrpcCustomerServicesImpl {
             .map(Response::new)
             .map_err(grpc_error_handler)
     }
+
+    #[instrument]
+    async fn import(
+        &self,
+        request: Request<Streaming<CreateCustomerRequest>>,
+    ) -> Result<Response<ImportReport>, Status> {
+        let requests = request.into_inner().try_collect::<Vec<_>>().await?;
+        let session = self.get_session().await.unwrap();
+
+        let services = CustomerServiceImpl::new(session);
+
+        services
+            .import(requests)
+            .await
+            .map(|e| Response::new(e.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_duplicates(
+        &self,
+        request: Request<ListDuplicatesRequest>,
+    ) -> Result<Response<ListDuplicatesResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CustomerServiceImpl::new(session);
+
+        services
+            .list_duplicates(request.customer_id.map(|e| e as i64))
+            .await
+            .map(|d| {
+                let d: Vec<CustomerDuplicate> = d.into_iter().map(|e| e.into()).collect();
+                Response::new(ListDuplicatesResponse { duplicates: d })
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn merge(
+        &self,
+        request: Request<MergeCustomersRequest>,
+    ) -> Result<Response<Customer>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CustomerServiceImpl::new(session);
+
+        let customer = services
+            .merge(request.source_id as i64, request.target_id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        // the source customer is deleted and its contacts are moved to the target customer.
+        let mut keys = customer_keys(request.source_id);
+        keys.extend(customer_keys(request.target_id));
+        invalidate(self.cache.as_ref(), keys).await;
+
+        Ok(Response::new(customer.into()))
+    }
+
+    async fn export(
+        &self,
+        request: Request<ListRequest>,
+    ) -> Result<Response<Self::exportStream>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CustomerServiceImpl::new(session);
+
+        let customers = services
+            .export(request)
+            .map_ok(Customer::from)
+            .map_err(grpc_error_handler)
+            .boxed();
+
+        Ok(Response::new(customers))
+    }
+
+    async fn create_address(
+        &self,
+        request: Request<CreateAddressRequest>,
+    ) -> Result<Response<Address>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = AddressServiceImpl::new(session);
+
+        services
+            .create(request)
+            .await
+            .map(|a| Response::new(a.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn update_address(
+        &self,
+        request: Request<UpdateAddressRequest>,
+    ) -> Result<Response<Address>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = AddressServiceImpl::new(session);
+
+        services
+            .update(request)
+            .await
+            .map(|a| Response::new(a.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_address(
+        &self,
+        request: Request<DeleteAddressRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = AddressServiceImpl::new(session);
+
+        services
+            .delete(request.customer_id as i64, request.id as i64)
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_addresses(
+        &self,
+        request: Request<ListAddressesRequest>,
+    ) -> Result<Response<ListAddressesResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = AddressServiceImpl::new(session);
+
+        services
+            .list(request.customer_id as i64)
+            .await
+            .map(|a| {
+                let addresses = a.into_iter().map(|e| e.into()).collect();
+                Response::new(ListAddressesResponse { addresses })
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn create_note(
+        &self,
+        request: Request<CreateNoteRequest>,
+    ) -> Result<Response<Note>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+
+        services
+            .create(request)
+            .await
+            .map(|e| Response::new(e.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_note(
+        &self,
+        request: Request<DeleteNoteRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+
+        services
+            .delete(request.owner_id as i64, request.id as i64)
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_notes(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<ListNotesResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+
+        services
+            .list(id as i64)
+            .await
+            .map(|n| {
+                let notes = n.into_iter().map(|e| e.into()).collect();
+                Response::new(ListNotesResponse { notes })
+            })
+            .map_err(grpc_error_handler)
+    }
 }
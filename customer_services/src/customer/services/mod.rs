@@ -1,2 +1,3 @@
+pub mod duplicate;
 pub mod grpc_service;
 pub mod service;
@@ -2,18 +2,40 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
 
-use common::customer_pb::{CreateCustomerRequest, UpdateCustomerRequest};
-use common::json::customer::Customer;
-use common::types::ListRequest;
+use common::customer_pb::{
+    ContactChannelList, ContactChannelRequest, CreateCustomerRequest, UpdateCustomerRequest,
+};
+use common::json::customer::{Customer, CustomerDuplicate};
+use common::json::import::ImportReport;
+use common::types::{ListRequest, SearchRequest};
 use common::util::alias::AppResult;
 use common::util::errors::AppError;
-use common::util::tools::database_error_handler;
+use common::util::search::search_limit;
+use common::util::tools::{batch_stream, database_error_handler, in_transaction, Transaction};
+use common::util::validation::{normalize_email, normalize_optional, normalize_phone, Validator};
 
 use crate::customer::repos::postgres_repo::CustomerRepoImpl;
 use crate::customer::repos::repo::CustomerRepo;
+use crate::customer::services::duplicate::find_duplicates;
+use crate::DEFAULT_PHONE_REGION;
+
+/// the max number of candidates (or candidate pairs) which are compared to find the duplicates.
+const MAX_DUPLICATE_CANDIDATES: u64 = 1000;
+
+/// the supported contact channels except email and phone.
+const CONTACT_CHANNELS: &[&str] = &[
+    "line",
+    "instagram",
+    "whatsapp",
+    "facebook",
+    "wechat",
+    "telegram",
+    "other",
+];
 
 #[async_trait]
 pub(crate) trait CustomerService {
@@ -23,29 +45,250 @@ pub(crate) trait CustomerService {
 
     async fn list(&self, request: ListRequest) -> AppResult<Vec<Customer>>;
 
+    /// find the customers which match the query, the most similar customer is the first one.
+    async fn search(&self, request: SearchRequest) -> AppResult<Vec<Customer>>;
+
     async fn update(&self, request: UpdateCustomerRequest) -> AppResult<Customer>;
+
+    /// create the customers in a transaction, the existing customers are skipped.
+    async fn import(&self, requests: Vec<CreateCustomerRequest>) -> AppResult<ImportReport>;
+
+    /// list the customers which look like the same person.
+    async fn list_duplicates(&self, customer_id: Option<i64>) -> AppResult<Vec<CustomerDuplicate>>;
+
+    /// move the order items of the source customer to the target customer,
+    /// then delete the source customer.
+    async fn merge(&self, source_id: i64, target_id: i64) -> AppResult<Customer>;
+
+    /// stream all of the customers which match the query, ordered by id.
+    fn export(self, request: ListRequest) -> BoxStream<'static, AppResult<Customer>>;
 }
 
 pub(crate) struct CustomerServiceImpl {
+    transaction: Box<dyn Transaction>,
     repo: Box<dyn CustomerRepo + Sync + Send>,
 }
 
 impl CustomerServiceImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
-        let repo = CustomerRepoImpl::new(session);
+        let repo = CustomerRepoImpl::new(session.clone());
         Self {
+            transaction: Box::new(session),
             repo: Box::new(repo),
         }
     }
+
+    async fn import_rows(
+        &self,
+        requests: Vec<CreateCustomerRequest>,
+    ) -> anyhow::Result<ImportReport> {
+        let mut report = ImportReport::default();
+
+        for (i, request) in requests.into_iter().enumerate() {
+            let row = i as u32 + 1;
+
+            let request = match validate_create(request) {
+                Ok(request) => request,
+                Err(AppError::ValidationError(errors)) => {
+                    let reason = errors
+                        .iter()
+                        .map(|e| format!("{} {}", e.field, e.message))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    report.add_failed(row, reason);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+
+            let is_exist = self
+                .repo
+                .check_customer_is_exist(request.phone.clone(), request.email.clone())
+                .await?;
+
+            if is_exist {
+                report.add_skipped(row, "customer already exist.".to_string());
+                continue;
+            }
+
+            let customer = self.create_with_contacts(request).await?;
+            report.add_created(row, customer.id);
+        }
+
+        Ok(report)
+    }
+
+    async fn create_with_contacts(
+        &self,
+        mut request: CreateCustomerRequest,
+    ) -> anyhow::Result<Customer> {
+        let contacts = std::mem::take(&mut request.contacts);
+
+        let mut customer = self.repo.create(request).await?;
+
+        if !contacts.is_empty() {
+            customer.contacts = self.repo.set_contacts(customer.id, contacts).await?;
+        }
+
+        Ok(customer)
+    }
+
+    /// attach the contact channels to the customers.
+    async fn attach_contacts(&self, mut customers: Vec<Customer>) -> anyhow::Result<Vec<Customer>> {
+        let ids = customers.iter().map(|c| c.id).collect::<Vec<_>>();
+
+        let mut contacts = self.repo.get_contacts(ids).await?;
+
+        for c in customers.iter_mut() {
+            c.contacts = contacts.remove(&c.id).unwrap_or_default();
+        }
+
+        Ok(customers)
+    }
+
+    async fn get_existing(&self, id: i64) -> AppResult<Customer> {
+        self.repo
+            .get(id)
+            .await
+            .map_err(database_error_handler)?
+            .ok_or_else(|| AppError::BadRequest(format!("Can't find the customer by id {}", id)))
+    }
+
+    async fn merge_into(&self, source: Customer, target: &Customer) -> anyhow::Result<()> {
+        self.repo.move_dependents(source.id, target.id).await?;
+
+        // keep the contacts of the source customer if the target customer doesn't have them.
+        let request = UpdateCustomerRequest {
+            id: target.id as u64,
+            name: None,
+            email: target.email.clone().or(source.email),
+            phone: target.phone.clone().or(source.phone),
+            contacts: None,
+        };
+        self.repo.update(request).await?;
+
+        self.repo.soft_delete(source.id, Some(target.id)).await?;
+
+        Ok(())
+    }
+}
+
+/// trim the name, then normalize the email and the phone of a customer,
+/// all of the invalid fields are reported together.
+fn validate_create(request: CreateCustomerRequest) -> AppResult<CreateCustomerRequest> {
+    let mut validator = Validator::default();
+
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        validator.add("name", "can't be empty.");
+    }
+
+    let (email, phone) = validate_contacts(&mut validator, request.email, request.phone);
+    let contacts = validate_contact_channels(&mut validator, request.contacts);
+
+    validator.finish()?;
+
+    Ok(CreateCustomerRequest {
+        name,
+        email,
+        phone,
+        contacts,
+    })
+}
+
+fn validate_update(request: UpdateCustomerRequest) -> AppResult<UpdateCustomerRequest> {
+    let mut validator = Validator::default();
+
+    let name = request.name.map(|e| e.trim().to_string());
+    if name.as_deref() == Some("") {
+        validator.add("name", "can't be empty.");
+    }
+
+    let (email, phone) = validate_contacts(&mut validator, request.email, request.phone);
+    let contacts = request.contacts.map(|e| ContactChannelList {
+        contacts: validate_contact_channels(&mut validator, e.contacts),
+    });
+
+    validator.finish()?;
+
+    Ok(UpdateCustomerRequest {
+        id: request.id,
+        name,
+        email,
+        phone,
+        contacts,
+    })
+}
+
+fn validate_contacts(
+    validator: &mut Validator,
+    email: Option<String>,
+    phone: Option<String>,
+) -> (Option<String>, Option<String>) {
+    let email = validator.check("email", normalize_optional(email, normalize_email));
+    let phone = validator.check(
+        "phone",
+        normalize_optional(phone, |e| normalize_phone(e, &DEFAULT_PHONE_REGION)),
+    );
+
+    (email.flatten(), phone.flatten())
+}
+
+/// fold the channel names to lowercase and trim the handles, a customer has one preferred channel at most.
+fn validate_contact_channels(
+    validator: &mut Validator,
+    contacts: Vec<ContactChannelRequest>,
+) -> Vec<ContactChannelRequest> {
+    let contacts = contacts
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| {
+            let channel = c.channel.trim().to_lowercase();
+            if !CONTACT_CHANNELS.contains(&channel.as_str()) {
+                validator.add(
+                    &format!("contacts[{}].channel", i),
+                    format!("must be one of {}.", CONTACT_CHANNELS.join(", ")),
+                );
+            }
+
+            let handle = c.handle.trim().to_string();
+            if handle.is_empty() {
+                validator.add(&format!("contacts[{}].handle", i), "can't be empty.");
+            }
+
+            ContactChannelRequest {
+                channel,
+                handle,
+                preferred: c.preferred,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if contacts.iter().filter(|c| c.preferred).count() > 1 {
+        validator.add("contacts", "only one channel can be preferred.");
+    }
+
+    contacts
 }
 
 #[async_trait]
 impl CustomerService for CustomerServiceImpl {
     async fn get(&self, id: i64) -> AppResult<Option<Customer>> {
-        self.repo.get(id).await.map_err(database_error_handler)
+        let customer = self.repo.get(id).await.map_err(database_error_handler)?;
+
+        match customer {
+            Some(c) => Ok(self
+                .attach_contacts(vec![c])
+                .await
+                .map_err(database_error_handler)?
+                .pop()),
+            None => Ok(None),
+        }
     }
 
     async fn create(&self, request: CreateCustomerRequest) -> AppResult<Customer> {
+        let request = validate_create(request)?;
+
         let is_exist = self
             .repo
             .check_customer_is_exist(request.phone.clone(), request.email.clone())
@@ -59,21 +302,45 @@ impl CustomerService for CustomerServiceImpl {
             return Err(AppError::BadRequest("customer already exist.".to_string()));
         }
 
-        self.repo
-            .create(request)
-            .await
-            .map_err(database_error_handler)
+        in_transaction(
+            self.transaction.as_ref(),
+            self.create_with_contacts(request),
+        )
+        .await
     }
 
     async fn list(&self, request: ListRequest) -> AppResult<Vec<Customer>> {
-        self.repo
+        let customers = self
+            .repo
             .list(request)
+            .await
+            .map_err(database_error_handler)?;
+
+        self.attach_contacts(customers)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn search(&self, request: SearchRequest) -> AppResult<Vec<Customer>> {
+        if request.query.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        let customers = self
+            .repo
+            .search(request.query, search_limit(request.limit))
+            .await
+            .map_err(database_error_handler)?;
+
+        self.attach_contacts(customers)
             .await
             .map_err(database_error_handler)
     }
 
     async fn update(&self, request: UpdateCustomerRequest) -> AppResult<Customer> {
-        let old_customer = self.repo.get(request.id as i64).await.ok().flatten();
+        let request = validate_update(request)?;
+
+        let old_customer = self.get(request.id as i64).await.ok().flatten();
 
         if old_customer.is_none() {
             return Err(AppError::BadRequest(format!(
@@ -84,25 +351,90 @@ impl CustomerService for CustomerServiceImpl {
 
         let old_customer = old_customer.unwrap();
 
-        let is_affected = self.repo.update(request.clone()).await;
+        let contacts = in_transaction(self.transaction.as_ref(), async {
+            self.repo.update(request.clone()).await?;
 
-        if is_affected.is_ok() {
-            let new_customer = Customer {
-                name: request.name.unwrap_or(old_customer.name),
-                email: request.email.to_owned(),
-                phone: request.phone.to_owned(),
-                ..old_customer
-            };
+            match request.contacts.clone() {
+                Some(list) => self
+                    .repo
+                    .set_contacts(old_customer.id, list.contacts)
+                    .await
+                    .map(Some),
+                None => Ok(None),
+            }
+        })
+        .await?;
+
+        Ok(Customer {
+            name: request.name.unwrap_or(old_customer.name),
+            email: request.email.or(old_customer.email),
+            phone: request.phone.or(old_customer.phone),
+            contacts: contacts.unwrap_or(old_customer.contacts),
+            ..old_customer
+        })
+    }
+
+    async fn import(&self, requests: Vec<CreateCustomerRequest>) -> AppResult<ImportReport> {
+        in_transaction(self.transaction.as_ref(), self.import_rows(requests)).await
+    }
 
-            return Ok(new_customer);
+    async fn list_duplicates(&self, customer_id: Option<i64>) -> AppResult<Vec<CustomerDuplicate>> {
+        let candidates = self
+            .repo
+            .list_duplicate_candidates(customer_id, MAX_DUPLICATE_CANDIDATES)
+            .await
+            .map_err(database_error_handler)?;
+
+        Ok(find_duplicates(&candidates, customer_id))
+    }
+
+    async fn merge(&self, source_id: i64, target_id: i64) -> AppResult<Customer> {
+        if source_id == target_id {
+            return Err(AppError::BadRequest(
+                "Can't merge a customer into itself.".to_string(),
+            ));
         }
 
-        return Ok(old_customer);
+        let source = self.get_existing(source_id).await?;
+        let target = self.get_existing(target_id).await?;
+
+        in_transaction(self.transaction.as_ref(), self.merge_into(source, &target)).await?;
+
+        self.get(target.id)
+            .await?
+            .ok_or_else(|| AppError::DatabaseError("failed to merge the customers.".to_string()))
+    }
+
+    fn export(self, request: ListRequest) -> BoxStream<'static, AppResult<Customer>> {
+        let service = Arc::new(self);
+
+        batch_stream(
+            move |after_id, limit| {
+                let service = service.clone();
+                let request = request.clone();
+
+                async move {
+                    let customers = service
+                        .repo
+                        .list_after(request, after_id, limit)
+                        .await
+                        .map_err(database_error_handler)?;
+
+                    service
+                        .attach_contacts(customers)
+                        .await
+                        .map_err(database_error_handler)
+                }
+            },
+            |c| c.id,
+        )
     }
 }
 
 #[cfg(test)]
 mod test {
+    use common::util::tools::NoTransaction;
+
     use crate::customer::repos::fake_repo::FakeCustomerRepo;
 
     use super::*;
@@ -111,6 +443,7 @@ mod test {
         fn fake() -> Self {
             let repo = FakeCustomerRepo::new();
             Self {
+                transaction: Box::new(NoTransaction),
                 repo: Box::new(repo),
             }
         }
@@ -124,6 +457,7 @@ mod test {
             name: "boris".to_string(),
             email: None,
             phone: None,
+            contacts: vec![],
         };
 
         let res = fake_service.create(req).await;
@@ -138,4 +472,199 @@ mod test {
         let customer = customer.unwrap();
         assert!(customer.is_some());
     }
+
+    #[tokio::test]
+    async fn can_import_customers() {
+        let fake_service = CustomerServiceImpl::fake();
+
+        let customer = |name: &str, phone: &str| CreateCustomerRequest {
+            name: name.to_string(),
+            email: None,
+            phone: Some(phone.to_string()),
+            contacts: vec![],
+        };
+
+        let reqs = vec![
+            customer("boris", "0912345678"),
+            customer("alice", "0987654321"),
+            customer("boris lok", "0912-345-678"),
+            customer("bob", "123"),
+        ];
+
+        let report = fake_service.import(reqs).await.unwrap();
+
+        assert_eq!(report.created, 2);
+        assert_eq!(report.skipped, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.rows[2].row, 3);
+        assert!(report.rows[2].id.is_none());
+    }
+
+    #[tokio::test]
+    async fn normalize_customer_contacts() {
+        let fake_service = CustomerServiceImpl::fake();
+
+        let req = CreateCustomerRequest {
+            name: " boris ".to_string(),
+            email: Some("Boris@Example.com".to_string()),
+            phone: Some("0912 345 678".to_string()),
+            contacts: vec![],
+        };
+
+        let customer = fake_service.create(req).await.unwrap();
+
+        assert_eq!(customer.name, "boris");
+        assert_eq!(customer.email, Some("boris@example.com".to_string()));
+        assert_eq!(customer.phone, Some("+886912345678".to_string()));
+
+        let req = UpdateCustomerRequest {
+            id: customer.id as u64,
+            name: Some("".to_string()),
+            email: Some("boris@".to_string()),
+            phone: None,
+            contacts: None,
+        };
+
+        match fake_service.update(req).await {
+            Err(AppError::ValidationError(errors)) => {
+                let fields = errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+                assert_eq!(fields, vec!["name", "email"]);
+            }
+            _ => panic!("expect a validation error."),
+        }
+
+        let req = UpdateCustomerRequest {
+            id: customer.id as u64,
+            name: Some("boris lok".to_string()),
+            email: None,
+            phone: None,
+            contacts: None,
+        };
+
+        let updated = fake_service.update(req).await.unwrap();
+
+        assert_eq!(updated.name, "boris lok");
+        assert_eq!(updated.email, customer.email);
+        assert_eq!(updated.phone, customer.phone);
+    }
+
+    #[tokio::test]
+    async fn can_search_customers_by_contact_channels() {
+        let fake_service = CustomerServiceImpl::fake();
+
+        let contact = |channel: &str, handle: &str, preferred: bool| ContactChannelRequest {
+            channel: channel.to_string(),
+            handle: handle.to_string(),
+            preferred,
+        };
+
+        let req = CreateCustomerRequest {
+            name: "boris".to_string(),
+            email: None,
+            phone: None,
+            contacts: vec![
+                contact("LINE", " boris.lok ", true),
+                contact("instagram", "boris_ig", false),
+            ],
+        };
+
+        let customer = fake_service.create(req).await.unwrap();
+
+        assert_eq!(customer.contacts.len(), 2);
+        assert_eq!(customer.contacts[0].channel, "line");
+        assert_eq!(customer.contacts[0].handle, "boris.lok");
+
+        let req = ListRequest {
+            query: Some("boris_ig".to_string()),
+            page: 0,
+            page_size: 10,
+        };
+
+        let customers = fake_service.list(req).await.unwrap();
+        assert_eq!(customers.len(), 1);
+        assert_eq!(customers[0].contacts.len(), 2);
+
+        let req = UpdateCustomerRequest {
+            id: customer.id as u64,
+            name: None,
+            email: None,
+            phone: None,
+            contacts: Some(ContactChannelList {
+                contacts: vec![contact("fax", "", true), contact("line", "boris", true)],
+            }),
+        };
+
+        match fake_service.update(req).await {
+            Err(AppError::ValidationError(errors)) => {
+                let fields = errors.iter().map(|e| e.field.as_str()).collect::<Vec<_>>();
+                assert_eq!(
+                    fields,
+                    vec!["contacts[0].channel", "contacts[0].handle", "contacts"]
+                );
+            }
+            _ => panic!("expect a validation error."),
+        }
+    }
+
+    #[tokio::test]
+    async fn can_merge_customers() {
+        let fake_service = CustomerServiceImpl::fake();
+
+        let create = |name: &str, email: Option<&str>, phone: Option<&str>| CreateCustomerRequest {
+            name: name.to_string(),
+            email: email.map(|e| e.to_string()),
+            phone: phone.map(|e| e.to_string()),
+            contacts: vec![],
+        };
+
+        let source = fake_service
+            .create(create("boris lok", Some("boris@example.com"), None))
+            .await
+            .unwrap();
+        let target = fake_service
+            .create(create("Boris", None, Some("0912345678")))
+            .await
+            .unwrap();
+
+        let customer = fake_service.merge(source.id, target.id).await.unwrap();
+
+        assert_eq!(customer.id, target.id);
+        assert_eq!(customer.email, Some("boris@example.com".to_string()));
+        assert_eq!(customer.phone, Some("+886912345678".to_string()));
+        assert!(fake_service.get(source.id).await.unwrap().is_none());
+        assert!(fake_service.merge(target.id, target.id).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn can_export_customers() {
+        use futures::TryStreamExt;
+
+        let fake_service = CustomerServiceImpl::fake();
+
+        for i in 0..1200 {
+            let req = CreateCustomerRequest {
+                name: format!("boris:{}", i),
+                email: None,
+                phone: Some(i.to_string()),
+                contacts: vec![],
+            };
+            fake_service.repo.create(req).await.unwrap();
+        }
+
+        let req = ListRequest {
+            query: None,
+            page: 0,
+            page_size: 10,
+        };
+
+        let customers = fake_service
+            .export(req)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        // the paging fields are ignored, all of the customers are exported across batches.
+        assert_eq!(customers.len(), 1200);
+        assert!(customers.windows(2).all(|e| e[0].id < e[1].id));
+    }
 }
@@ -0,0 +1,96 @@
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::note::{CustomerNotes, Note};
+use common::types::CreateNoteRequest;
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::note::repos::repo::NoteRepo;
+use crate::ID_GENERATOR;
+
+const NOTE_COLUMNS: [CustomerNotes; 4] = [
+    CustomerNotes::Id,
+    CustomerNotes::Author,
+    CustomerNotes::Body,
+    CustomerNotes::CreatedAt,
+];
+
+pub struct NoteRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl NoteRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl NoteRepo for NoteRepoImpl {
+    async fn create(&self, request: CreateNoteRequest) -> Result<Note> {
+        let id = ID_GENERATOR.lock().unwrap().next_id();
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::insert()
+            .into_table(CustomerNotes::Table)
+            .columns(vec![
+                CustomerNotes::Id,
+                CustomerNotes::CustomerId,
+                CustomerNotes::Author,
+                CustomerNotes::Body,
+                CustomerNotes::CreatedAt,
+            ])
+            .values_panic(vec![
+                id.into(),
+                request.owner_id.into(),
+                request.author.into(),
+                request.body.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(NOTE_COLUMNS).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Note>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn delete(&self, customer_id: i64, id: i64) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(CustomerNotes::Table)
+            .and_where(Expr::col(CustomerNotes::Id).eq(id))
+            .and_where(Expr::col(CustomerNotes::CustomerId).eq(customer_id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, customer_id: i64) -> Result<Vec<Note>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(NOTE_COLUMNS)
+            .from(CustomerNotes::Table)
+            .and_where(Expr::col(CustomerNotes::CustomerId).eq(customer_id))
+            .order_by(CustomerNotes::CreatedAt, Order::Desc)
+            .order_by(CustomerNotes::Id, Order::Desc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Note>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+}
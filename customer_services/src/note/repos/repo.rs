@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::note::Note;
+use common::types::CreateNoteRequest;
+
+#[async_trait]
+pub trait NoteRepo {
+    /// the owner id of the request is the customer id.
+    async fn create(&self, request: CreateNoteRequest) -> Result<Note>;
+
+    async fn delete(&self, customer_id: i64, id: i64) -> Result<bool>;
+
+    /// list the notes of the customer, the newest note is the first one.
+    async fn list(&self, customer_id: i64) -> Result<Vec<Note>>;
+}
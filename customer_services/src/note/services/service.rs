@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::note::Note;
+use common::types::CreateNoteRequest;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::database_error_handler;
+use common::util::validation::validate_note;
+
+use crate::customer::repos::postgres_repo::CustomerRepoImpl;
+use crate::customer::repos::repo::CustomerRepo;
+use crate::note::repos::postgres_repo::NoteRepoImpl;
+use crate::note::repos::repo::NoteRepo;
+
+#[async_trait]
+pub trait NoteService {
+    async fn create(&self, request: CreateNoteRequest) -> AppResult<Note>;
+
+    async fn delete(&self, customer_id: i64, id: i64) -> AppResult<bool>;
+
+    async fn list(&self, customer_id: i64) -> AppResult<Vec<Note>>;
+}
+
+pub(crate) struct NoteServiceImpl {
+    repo: Box<dyn NoteRepo + Send + Sync>,
+    customer_repo: Box<dyn CustomerRepo + Send + Sync>,
+}
+
+impl NoteServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(NoteRepoImpl::new(session.clone()));
+        let customer_repo = Box::new(CustomerRepoImpl::new(session));
+
+        Self {
+            repo,
+            customer_repo,
+        }
+    }
+
+    async fn check_customer_is_exist(&self, customer_id: i64) -> AppResult<()> {
+        let customer = self
+            .customer_repo
+            .get(customer_id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if customer.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the customer by id {}",
+                customer_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NoteService for NoteServiceImpl {
+    async fn create(&self, request: CreateNoteRequest) -> AppResult<Note> {
+        self.check_customer_is_exist(request.owner_id as i64)
+            .await?;
+
+        let request = validate_note(request)?;
+
+        self.repo
+            .create(request)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn delete(&self, customer_id: i64, id: i64) -> AppResult<bool> {
+        let is_deleted = self
+            .repo
+            .delete(customer_id, id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if !is_deleted {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the note by id {}",
+                id
+            )));
+        }
+
+        Ok(is_deleted)
+    }
+
+    async fn list(&self, customer_id: i64) -> AppResult<Vec<Note>> {
+        self.check_customer_is_exist(customer_id).await?;
+
+        self.repo
+            .list(customer_id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
@@ -1,5 +1,23 @@
 #!/bin/bash
 
-/opt/customer_services &
-/opt/product_services &
-/opt/web_api_gateway &
+# a small deployment sets ALL_IN_ONE=true to run /opt/all_in_one instead of the three services,
+# with the client addresses of the gateway pointed at it.
+pids=()
+
+if [ "${ALL_IN_ONE:-false}" = "true" ]; then
+    /opt/all_in_one & pids+=($!)
+else
+    /opt/customer_services & pids+=($!)
+    /opt/product_services & pids+=($!)
+    /opt/order_services & pids+=($!)
+fi
+
+/opt/web_api_gateway & pids+=($!)
+
+# the script is the main process of the container, so it forwards the stop signal to the services.
+trap 'kill -TERM "${pids[@]}" 2>/dev/null' TERM INT
+
+# the container stops once any of the processes exits, the others are shut down gracefully.
+wait -n
+kill -TERM "${pids[@]}" 2>/dev/null
+wait
@@ -6,6 +6,8 @@ pub struct CreateCustomerRequest {
     pub email: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="3")]
     pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="4")]
+    pub contacts: ::prost::alloc::vec::Vec<ContactChannelRequest>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateCustomerRequest {
@@ -17,6 +19,37 @@ pub struct UpdateCustomerRequest {
     pub email: ::core::option::Option<::prost::alloc::string::String>,
     #[prost(string, optional, tag="4")]
     pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    /// replace all of the contact channels of the customer if it is set.
+    #[prost(message, optional, tag="5")]
+    pub contacts: ::core::option::Option<ContactChannelList>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContactChannelRequest {
+    /// e.g. "line", "instagram" and "whatsapp".
+    #[prost(string, tag="1")]
+    pub channel: ::prost::alloc::string::String,
+    #[prost(string, tag="2")]
+    pub handle: ::prost::alloc::string::String,
+    #[prost(bool, tag="3")]
+    pub preferred: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContactChannelList {
+    #[prost(message, repeated, tag="1")]
+    pub contacts: ::prost::alloc::vec::Vec<ContactChannelRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContactChannel {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, tag="2")]
+    pub channel: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub handle: ::prost::alloc::string::String,
+    #[prost(bool, tag="4")]
+    pub preferred: bool,
+    #[prost(uint64, tag="5")]
+    pub created_at: u64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetCustomerResponse {
@@ -42,6 +75,136 @@ pub struct Customer {
     pub created_at: u64,
     #[prost(uint64, optional, tag="6")]
     pub updated_at: ::core::option::Option<u64>,
+    #[prost(message, repeated, tag="7")]
+    pub contacts: ::prost::alloc::vec::Vec<ContactChannel>,
+    /// the notes are only included in the response of get.
+    #[prost(message, repeated, tag="8")]
+    pub notes: ::prost::alloc::vec::Vec<super::types::Note>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDuplicatesRequest {
+    /// only list the duplicates of this customer.
+    #[prost(uint64, optional, tag="1")]
+    pub customer_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListDuplicatesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub duplicates: ::prost::alloc::vec::Vec<CustomerDuplicate>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustomerDuplicate {
+    #[prost(message, optional, tag="1")]
+    pub customer: ::core::option::Option<Customer>,
+    #[prost(message, optional, tag="2")]
+    pub duplicate: ::core::option::Option<Customer>,
+    /// why they look like the same customer, e.g. "phone", "email" and "name".
+    #[prost(string, repeated, tag="3")]
+    pub reasons: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeCustomersRequest {
+    #[prost(uint64, tag="1")]
+    pub source_id: u64,
+    #[prost(uint64, tag="2")]
+    pub target_id: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Address {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(uint64, tag="2")]
+    pub customer_id: u64,
+    #[prost(string, tag="3")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="4")]
+    pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="5")]
+    pub line1: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="6")]
+    pub line2: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="7")]
+    pub city: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="8")]
+    pub region: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub postal_code: ::core::option::Option<::prost::alloc::string::String>,
+    /// ISO 3166-1 alpha-2 country code, e.g. "TW".
+    #[prost(string, tag="10")]
+    pub country: ::prost::alloc::string::String,
+    #[prost(bool, tag="11")]
+    pub is_default: bool,
+    #[prost(uint64, tag="12")]
+    pub created_at: u64,
+    #[prost(uint64, optional, tag="13")]
+    pub updated_at: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateAddressRequest {
+    #[prost(uint64, tag="1")]
+    pub customer_id: u64,
+    #[prost(string, tag="2")]
+    pub recipient: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="4")]
+    pub line1: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="5")]
+    pub line2: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="6")]
+    pub city: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="7")]
+    pub region: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub postal_code: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, tag="9")]
+    pub country: ::prost::alloc::string::String,
+    /// the first address of a customer is always the default address.
+    #[prost(bool, tag="10")]
+    pub is_default: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateAddressRequest {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(uint64, tag="2")]
+    pub customer_id: u64,
+    #[prost(string, optional, tag="3")]
+    pub recipient: ::core::option::Option<::prost::alloc::string::String>,
+    /// an empty string removes the optional fields.
+    #[prost(string, optional, tag="4")]
+    pub phone: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub line1: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="6")]
+    pub line2: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="7")]
+    pub city: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="8")]
+    pub region: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="9")]
+    pub postal_code: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="10")]
+    pub country: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, optional, tag="11")]
+    pub is_default: ::core::option::Option<bool>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteAddressRequest {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(uint64, tag="2")]
+    pub customer_id: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAddressesRequest {
+    #[prost(uint64, tag="1")]
+    pub customer_id: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAddressesResponse {
+    #[prost(message, repeated, tag="1")]
+    pub addresses: ::prost::alloc::vec::Vec<Address>,
 }
 /// Generated client implementations.
 pub mod customer_services_client {
@@ -183,6 +346,253 @@ pub mod customer_services_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// find the customers by name, email, phone and contact channels, the most similar customer is the first one.
+        pub async fn search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListCustomerResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/search",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::CreateCustomerRequest>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/import",
+            );
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
+        /// stream all of the customers which match the query, the paging fields are ignored.
+        pub async fn export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::ListRequest>,
+        ) -> Result<
+                tonic::Response<tonic::codec::Streaming<super::Customer>>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/export",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
+        pub async fn list_duplicates(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListDuplicatesRequest>,
+        ) -> Result<tonic::Response<super::ListDuplicatesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/list_duplicates",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// move the order items of the source customer to the target customer and delete the source customer.
+        pub async fn merge(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MergeCustomersRequest>,
+        ) -> Result<tonic::Response<super::Customer>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/merge",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateAddressRequest>,
+        ) -> Result<tonic::Response<super::Address>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/create_address",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn update_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateAddressRequest>,
+        ) -> Result<tonic::Response<super::Address>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/update_address",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_address(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteAddressRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/delete_address",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// list the addresses of a customer, the default address is the first one.
+        pub async fn list_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListAddressesRequest>,
+        ) -> Result<tonic::Response<super::ListAddressesResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/list_addresses",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_note(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::CreateNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::Note>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/create_note",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_note(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::DeleteNoteRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/delete_note",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// list the notes of a customer by the customer id.
+        pub async fn list_notes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::ListNotesResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.customer.CustomerServices/list_notes",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -208,21 +618,83 @@ pub mod customer_services_server {
             &self,
             request: tonic::Request<super::super::types::ListRequest>,
         ) -> Result<tonic::Response<super::ListCustomerResponse>, tonic::Status>;
-    }
-    #[derive(Debug)]
-    pub struct CustomerServicesServer<T: CustomerServices> {
-        inner: _Inner<T>,
-        accept_compression_encodings: (),
-        send_compression_encodings: (),
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: CustomerServices> CustomerServicesServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
+        /// find the customers by name, email, phone and contact channels, the most similar customer is the first one.
+        async fn search(
+            &self,
+            request: tonic::Request<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListCustomerResponse>, tonic::Status>;
+        async fn import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::CreateCustomerRequest>>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status>;
+        ///Server streaming response type for the export method.
+        type exportStream: futures_core::Stream<
+                Item = Result<super::Customer, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// stream all of the customers which match the query, the paging fields are ignored.
+        async fn export(
+            &self,
+            request: tonic::Request<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<Self::exportStream>, tonic::Status>;
+        async fn list_duplicates(
+            &self,
+            request: tonic::Request<super::ListDuplicatesRequest>,
+        ) -> Result<tonic::Response<super::ListDuplicatesResponse>, tonic::Status>;
+        /// move the order items of the source customer to the target customer and delete the source customer.
+        async fn merge(
+            &self,
+            request: tonic::Request<super::MergeCustomersRequest>,
+        ) -> Result<tonic::Response<super::Customer>, tonic::Status>;
+        async fn create_address(
+            &self,
+            request: tonic::Request<super::CreateAddressRequest>,
+        ) -> Result<tonic::Response<super::Address>, tonic::Status>;
+        async fn update_address(
+            &self,
+            request: tonic::Request<super::UpdateAddressRequest>,
+        ) -> Result<tonic::Response<super::Address>, tonic::Status>;
+        async fn delete_address(
+            &self,
+            request: tonic::Request<super::DeleteAddressRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        /// list the addresses of a customer, the default address is the first one.
+        async fn list_addresses(
+            &self,
+            request: tonic::Request<super::ListAddressesRequest>,
+        ) -> Result<tonic::Response<super::ListAddressesResponse>, tonic::Status>;
+        async fn create_note(
+            &self,
+            request: tonic::Request<super::super::types::CreateNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::Note>, tonic::Status>;
+        async fn delete_note(
+            &self,
+            request: tonic::Request<super::super::types::DeleteNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        /// list the notes of a customer by the customer id.
+        async fn list_notes(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::ListNotesResponse>,
+                tonic::Status,
+            >;
+    }
+    #[derive(Debug)]
+    pub struct CustomerServicesServer<T: CustomerServices> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: CustomerServices> CustomerServicesServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
                 inner,
                 accept_compression_encodings: Default::default(),
                 send_compression_encodings: Default::default(),
@@ -408,6 +880,473 @@ pub mod customer_services_server {
                     };
                     Box::pin(fut)
                 }
+                "/grpc.customer.CustomerServices/search" => {
+                    #[allow(non_camel_case_types)]
+                    struct searchSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::super::types::SearchRequest>
+                    for searchSvc<T> {
+                        type Response = super::ListCustomerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::SearchRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).search(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = searchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/import" => {
+                    #[allow(non_camel_case_types)]
+                    struct importSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::ClientStreamingService<super::CreateCustomerRequest>
+                    for importSvc<T> {
+                        type Response = super::super::types::ImportReport;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::CreateCustomerRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).import(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = importSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/export" => {
+                    #[allow(non_camel_case_types)]
+                    struct exportSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::ServerStreamingService<super::super::types::ListRequest>
+                    for exportSvc<T> {
+                        type Response = super::Customer;
+                        type ResponseStream = T::exportStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::ListRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).export(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = exportSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/list_duplicates" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_duplicatesSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::ListDuplicatesRequest>
+                    for list_duplicatesSvc<T> {
+                        type Response = super::ListDuplicatesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListDuplicatesRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).list_duplicates(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_duplicatesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/merge" => {
+                    #[allow(non_camel_case_types)]
+                    struct mergeSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::MergeCustomersRequest>
+                    for mergeSvc<T> {
+                        type Response = super::Customer;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeCustomersRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).merge(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = mergeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/create_address" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_addressSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::CreateAddressRequest>
+                    for create_addressSvc<T> {
+                        type Response = super::Address;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).create_address(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_addressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/update_address" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_addressSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::UpdateAddressRequest>
+                    for update_addressSvc<T> {
+                        type Response = super::Address;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).update_address(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = update_addressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/delete_address" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_addressSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::DeleteAddressRequest>
+                    for delete_addressSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteAddressRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).delete_address(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_addressSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/list_addresses" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_addressesSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::ListAddressesRequest>
+                    for list_addressesSvc<T> {
+                        type Response = super::ListAddressesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAddressesRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).list_addresses(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_addressesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/create_note" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_noteSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::super::types::CreateNoteRequest>
+                    for create_noteSvc<T> {
+                        type Response = super::super::types::Note;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::CreateNoteRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_note(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_noteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/delete_note" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_noteSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::super::types::DeleteNoteRequest>
+                    for delete_noteSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::DeleteNoteRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_note(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_noteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.customer.CustomerServices/list_notes" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_notesSvc<T: CustomerServices>(pub Arc<T>);
+                    impl<
+                        T: CustomerServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for list_notesSvc<T> {
+                        type Response = super::super::types::ListNotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_notes(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_notesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
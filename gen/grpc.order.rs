@@ -20,6 +20,9 @@ pub struct UpdateOrderItemRequest {
     pub quantity: ::core::option::Option<u32>,
     #[prost(uint32, optional, tag="5")]
     pub status: ::core::option::Option<u32>,
+    /// the shipping address, it must belong to the customer of the order item.
+    #[prost(uint64, optional, tag="6")]
+    pub address_id: ::core::option::Option<u64>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateOrderItemRequest {
@@ -31,6 +34,33 @@ pub struct CreateOrderItemRequest {
     pub quantity: u32,
     #[prost(uint32, tag="4")]
     pub status: u32,
+    /// the shipping address, it must belong to the customer.
+    #[prost(uint64, optional, tag="5")]
+    pub address_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateOrderItemRequest {
+    #[prost(message, repeated, tag="1")]
+    pub items: ::prost::alloc::vec::Vec<CreateOrderItemRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchCreateOrderItemResponse {
+    /// the created order items, it is empty if any item is invalid.
+    #[prost(message, repeated, tag="1")]
+    pub items: ::prost::alloc::vec::Vec<OrderItem>,
+    #[prost(message, repeated, tag="2")]
+    pub errors: ::prost::alloc::vec::Vec<batch_create_order_item_response::ItemError>,
+}
+/// Nested message and enum types in `BatchCreateOrderItemResponse`.
+pub mod batch_create_order_item_response {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ItemError {
+        /// the 0-based position of the item in the request.
+        #[prost(uint32, tag="1")]
+        pub index: u32,
+        #[prost(string, tag="2")]
+        pub reason: ::prost::alloc::string::String,
+    }
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateOrderItemsStatusRequest {
@@ -62,6 +92,8 @@ pub struct OrderItem {
     pub deleted_at: ::core::option::Option<u64>,
     #[prost(uint32, tag="8")]
     pub status: u32,
+    #[prost(uint64, optional, tag="9")]
+    pub address_id: ::core::option::Option<u64>,
 }
 /// Nested message and enum types in `OrderItem`.
 pub mod order_item {
@@ -88,6 +120,84 @@ pub mod order_item {
         pub created_at: u64,
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShoppingListRequest {
+    #[prost(uint64, optional, tag="1")]
+    pub supplier_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShoppingListResponse {
+    #[prost(message, repeated, tag="1")]
+    pub items: ::prost::alloc::vec::Vec<ShoppingListItem>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ShoppingListItem {
+    #[prost(uint64, tag="1")]
+    pub product_id: u64,
+    #[prost(string, tag="2")]
+    pub product_name: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="3")]
+    pub supplier_id: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="4")]
+    pub supplier_name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="5")]
+    pub source_url: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, tag="6")]
+    pub quantity: u32,
+    #[prost(message, repeated, tag="7")]
+    pub customers: ::prost::alloc::vec::Vec<shopping_list_item::WaitingCustomer>,
+}
+/// Nested message and enum types in `ShoppingListItem`.
+pub mod shopping_list_item {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct WaitingCustomer {
+        #[prost(uint64, tag="1")]
+        pub order_item_id: u64,
+        #[prost(uint64, tag="2")]
+        pub customer_id: u64,
+        #[prost(string, tag="3")]
+        pub customer_name: ::prost::alloc::string::String,
+        #[prost(uint32, tag="4")]
+        pub quantity: u32,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustomerHistoryRequest {
+    #[prost(uint64, tag="1")]
+    pub customer_id: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CustomerHistoryResponse {
+    /// the order items are sorted by the created time, the newest one is the first.
+    #[prost(message, repeated, tag="1")]
+    pub items: ::prost::alloc::vec::Vec<OrderItem>,
+    #[prost(message, repeated, tag="2")]
+    pub status_counts: ::prost::alloc::vec::Vec<customer_history_response::StatusCount>,
+    #[prost(message, repeated, tag="3")]
+    pub totals: ::prost::alloc::vec::Vec<customer_history_response::CurrencyTotal>,
+    #[prost(uint64, optional, tag="4")]
+    pub first_ordered_at: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="5")]
+    pub last_ordered_at: ::core::option::Option<u64>,
+}
+/// Nested message and enum types in `CustomerHistoryResponse`.
+pub mod customer_history_response {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StatusCount {
+        #[prost(uint32, tag="1")]
+        pub status: u32,
+        #[prost(uint32, tag="2")]
+        pub count: u32,
+    }
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CurrencyTotal {
+        #[prost(uint32, tag="1")]
+        pub currency: u32,
+        /// the sum of price * quantity of the order items in the currency, except the out of stock ones.
+        #[prost(double, tag="2")]
+        pub total: f64,
+    }
+}
 /// Generated client implementations.
 pub mod order_services_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
@@ -190,6 +300,49 @@ pub mod order_services_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// find the order items by the names of their customers and products, the most similar one is the first.
+        pub async fn search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListOrderItemResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/search",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// stream all of the order items which match the query, the paging fields are ignored.
+        pub async fn export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::ListRequest>,
+        ) -> Result<
+                tonic::Response<tonic::codec::Streaming<super::OrderItem>>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/export",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
+        }
         pub async fn update(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateOrderItemRequest>,
@@ -228,6 +381,50 @@ pub mod order_services_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        /// create all of the order items or none of them.
+        pub async fn batch_create(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchCreateOrderItemRequest>,
+        ) -> Result<
+                tonic::Response<super::BatchCreateOrderItemResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/batch_create",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::CreateOrderItemRequest>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/import",
+            );
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
+        }
         pub async fn update_order_items_status(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateOrderItemsStatusRequest>,
@@ -250,6 +447,109 @@ pub mod order_services_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
+        pub async fn shopping_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ShoppingListRequest>,
+        ) -> Result<tonic::Response<super::ShoppingListResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/shopping_list",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// list all of the order items of a customer with the aggregates of them.
+        pub async fn customer_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CustomerHistoryRequest>,
+        ) -> Result<tonic::Response<super::CustomerHistoryResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/customer_history",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_note(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::CreateNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::Note>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/create_note",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_note(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::DeleteNoteRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/delete_note",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        /// list the notes of an order item by the order item id.
+        pub async fn list_notes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::ListNotesResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.order.OrderServices/list_notes",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -267,6 +567,22 @@ pub mod order_services_server {
             &self,
             request: tonic::Request<super::super::types::ListRequest>,
         ) -> Result<tonic::Response<super::ListOrderItemResponse>, tonic::Status>;
+        /// find the order items by the names of their customers and products, the most similar one is the first.
+        async fn search(
+            &self,
+            request: tonic::Request<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListOrderItemResponse>, tonic::Status>;
+        ///Server streaming response type for the export method.
+        type exportStream: futures_core::Stream<
+                Item = Result<super::OrderItem, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// stream all of the order items which match the query, the paging fields are ignored.
+        async fn export(
+            &self,
+            request: tonic::Request<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<Self::exportStream>, tonic::Status>;
         async fn update(
             &self,
             request: tonic::Request<super::UpdateOrderItemRequest>,
@@ -275,6 +591,15 @@ pub mod order_services_server {
             &self,
             request: tonic::Request<super::CreateOrderItemRequest>,
         ) -> Result<tonic::Response<super::OrderItem>, tonic::Status>;
+        /// create all of the order items or none of them.
+        async fn batch_create(
+            &self,
+            request: tonic::Request<super::BatchCreateOrderItemRequest>,
+        ) -> Result<tonic::Response<super::BatchCreateOrderItemResponse>, tonic::Status>;
+        async fn import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::CreateOrderItemRequest>>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status>;
         async fn update_order_items_status(
             &self,
             request: tonic::Request<super::UpdateOrderItemsStatusRequest>,
@@ -282,6 +607,31 @@ pub mod order_services_server {
                 tonic::Response<super::UpdateOrderItemsStatusResponse>,
                 tonic::Status,
             >;
+        async fn shopping_list(
+            &self,
+            request: tonic::Request<super::ShoppingListRequest>,
+        ) -> Result<tonic::Response<super::ShoppingListResponse>, tonic::Status>;
+        /// list all of the order items of a customer with the aggregates of them.
+        async fn customer_history(
+            &self,
+            request: tonic::Request<super::CustomerHistoryRequest>,
+        ) -> Result<tonic::Response<super::CustomerHistoryResponse>, tonic::Status>;
+        async fn create_note(
+            &self,
+            request: tonic::Request<super::super::types::CreateNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::Note>, tonic::Status>;
+        async fn delete_note(
+            &self,
+            request: tonic::Request<super::super::types::DeleteNoteRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        /// list the notes of an order item by the order item id.
+        async fn list_notes(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::ListNotesResponse>,
+                tonic::Status,
+            >;
     }
     #[derive(Debug)]
     pub struct OrderServicesServer<T: OrderServices> {
@@ -406,6 +756,83 @@ pub mod order_services_server {
                     };
                     Box::pin(fut)
                 }
+                "/grpc.order.OrderServices/search" => {
+                    #[allow(non_camel_case_types)]
+                    struct searchSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::super::types::SearchRequest>
+                    for searchSvc<T> {
+                        type Response = super::ListOrderItemResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::SearchRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).search(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = searchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/export" => {
+                    #[allow(non_camel_case_types)]
+                    struct exportSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::ServerStreamingService<super::super::types::ListRequest>
+                    for exportSvc<T> {
+                        type Response = super::OrderItem;
+                        type ResponseStream = T::exportStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::ListRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).export(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = exportSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/grpc.order.OrderServices/update" => {
                     #[allow(non_camel_case_types)]
                     struct updateSvc<T: OrderServices>(pub Arc<T>);
@@ -482,6 +909,84 @@ pub mod order_services_server {
                     };
                     Box::pin(fut)
                 }
+                "/grpc.order.OrderServices/batch_create" => {
+                    #[allow(non_camel_case_types)]
+                    struct batch_createSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::BatchCreateOrderItemRequest>
+                    for batch_createSvc<T> {
+                        type Response = super::BatchCreateOrderItemResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchCreateOrderItemRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).batch_create(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = batch_createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/import" => {
+                    #[allow(non_camel_case_types)]
+                    struct importSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::ClientStreamingService<super::CreateOrderItemRequest>
+                    for importSvc<T> {
+                        type Response = super::super::types::ImportReport;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::CreateOrderItemRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).import(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = importSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/grpc.order.OrderServices/update_order_items_status" => {
                     #[allow(non_camel_case_types)]
                     struct update_order_items_statusSvc<T: OrderServices>(pub Arc<T>);
@@ -522,6 +1027,200 @@ pub mod order_services_server {
                     };
                     Box::pin(fut)
                 }
+                "/grpc.order.OrderServices/shopping_list" => {
+                    #[allow(non_camel_case_types)]
+                    struct shopping_listSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::ShoppingListRequest>
+                    for shopping_listSvc<T> {
+                        type Response = super::ShoppingListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ShoppingListRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).shopping_list(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = shopping_listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/customer_history" => {
+                    #[allow(non_camel_case_types)]
+                    struct customer_historySvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::CustomerHistoryRequest>
+                    for customer_historySvc<T> {
+                        type Response = super::CustomerHistoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CustomerHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).customer_history(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = customer_historySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/create_note" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_noteSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::super::types::CreateNoteRequest>
+                    for create_noteSvc<T> {
+                        type Response = super::super::types::Note;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::CreateNoteRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_note(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_noteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/delete_note" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_noteSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::super::types::DeleteNoteRequest>
+                    for delete_noteSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::DeleteNoteRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).delete_note(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_noteSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.order.OrderServices/list_notes" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_notesSvc<T: OrderServices>(pub Arc<T>);
+                    impl<
+                        T: OrderServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for list_notesSvc<T> {
+                        type Response = super::super::types::ListNotesResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_notes(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_notesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -6,6 +6,10 @@ pub struct CreateProductRequest {
     pub currency: i32,
     #[prost(double, tag="3")]
     pub price: f64,
+    #[prost(uint64, optional, tag="4")]
+    pub category_id: ::core::option::Option<u64>,
+    #[prost(string, repeated, tag="5")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateProductRequest {
@@ -17,6 +21,23 @@ pub struct UpdateProductRequest {
     pub currency: ::core::option::Option<i32>,
     #[prost(double, optional, tag="4")]
     pub price: ::core::option::Option<f64>,
+    #[prost(uint64, optional, tag="5")]
+    pub category_id: ::core::option::Option<u64>,
+    #[prost(message, optional, tag="6")]
+    pub tags: ::core::option::Option<TagList>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListProductRequest {
+    #[prost(string, optional, tag="1")]
+    pub query: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="2")]
+    pub page: u64,
+    #[prost(uint64, tag="3")]
+    pub page_size: u64,
+    #[prost(uint64, optional, tag="4")]
+    pub category_id: ::core::option::Option<u64>,
+    #[prost(string, repeated, tag="5")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetProductResponse {
@@ -44,6 +65,187 @@ pub struct Product {
     pub updated_at: ::core::option::Option<u64>,
     #[prost(uint64, optional, tag="7")]
     pub deleted_at: ::core::option::Option<u64>,
+    #[prost(uint64, optional, tag="8")]
+    pub category_id: ::core::option::Option<u64>,
+    #[prost(string, repeated, tag="9")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag="10")]
+    pub images: ::prost::alloc::vec::Vec<ProductImage>,
+    #[prost(message, optional, tag="11")]
+    pub supplier: ::core::option::Option<ProductSupplier>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagList {
+    #[prost(string, repeated, tag="1")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCategoryRequest {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint64, optional, tag="2")]
+    pub parent_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateCategoryRequest {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, optional, tag="2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, optional, tag="3")]
+    pub parent_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoryRequest {
+    #[prost(uint64, optional, tag="1")]
+    pub parent_id: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoryResponse {
+    #[prost(message, repeated, tag="1")]
+    pub categories: ::prost::alloc::vec::Vec<Category>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Category {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(uint64, optional, tag="2")]
+    pub parent_id: ::core::option::Option<u64>,
+    #[prost(string, tag="3")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at: u64,
+    #[prost(uint64, optional, tag="5")]
+    pub updated_at: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTagRequest {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateTagRequest {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTagResponse {
+    #[prost(message, repeated, tag="1")]
+    pub tags: ::prost::alloc::vec::Vec<Tag>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Tag {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint64, tag="3")]
+    pub created_at: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddProductImageRequest {
+    #[prost(uint64, tag="1")]
+    pub product_id: u64,
+    #[prost(string, tag="2")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub thumbnail_key: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub thumbnail_url: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub content_type: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProductImage {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(uint64, tag="2")]
+    pub product_id: u64,
+    #[prost(string, tag="3")]
+    pub key: ::prost::alloc::string::String,
+    #[prost(string, tag="4")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag="5")]
+    pub thumbnail_key: ::prost::alloc::string::String,
+    #[prost(string, tag="6")]
+    pub thumbnail_url: ::prost::alloc::string::String,
+    #[prost(string, tag="7")]
+    pub content_type: ::prost::alloc::string::String,
+    #[prost(uint64, tag="8")]
+    pub created_at: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateSupplierRequest {
+    #[prost(string, tag="1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="2")]
+    pub website: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSupplierRequest {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, optional, tag="2")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(string, optional, tag="3")]
+    pub website: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSupplierResponse {
+    #[prost(message, repeated, tag="1")]
+    pub suppliers: ::prost::alloc::vec::Vec<Supplier>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Supplier {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, tag="2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub website: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint64, tag="4")]
+    pub created_at: u64,
+    #[prost(uint64, optional, tag="5")]
+    pub updated_at: ::core::option::Option<u64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetProductSupplierRequest {
+    #[prost(uint64, tag="1")]
+    pub product_id: u64,
+    #[prost(uint64, tag="2")]
+    pub supplier_id: u64,
+    #[prost(string, optional, tag="3")]
+    pub source_url: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(double, tag="4")]
+    pub cost_price: f64,
+    #[prost(int32, tag="5")]
+    pub cost_currency: i32,
+    #[prost(double, optional, tag="6")]
+    pub exchange_rate: ::core::option::Option<f64>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProductSupplier {
+    #[prost(uint64, tag="1")]
+    pub supplier_id: u64,
+    #[prost(string, tag="2")]
+    pub supplier_name: ::prost::alloc::string::String,
+    #[prost(string, optional, tag="3")]
+    pub source_url: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(double, tag="4")]
+    pub cost_price: f64,
+    #[prost(int32, tag="5")]
+    pub cost_currency: i32,
+    #[prost(double, tag="6")]
+    pub exchange_rate: f64,
+    /// the margins are missing if they overflow.
+    #[prost(double, optional, tag="7")]
+    pub margin: ::core::option::Option<f64>,
+    #[prost(double, optional, tag="8")]
+    pub margin_rate: ::core::option::Option<f64>,
 }
 /// Generated client implementations.
 pub mod product_services_client {
@@ -168,7 +370,7 @@ pub mod product_services_client {
         }
         pub async fn list(
             &mut self,
-            request: impl tonic::IntoRequest<super::super::types::ListRequest>,
+            request: impl tonic::IntoRequest<super::ListProductRequest>,
         ) -> Result<tonic::Response<super::ListProductResponse>, tonic::Status> {
             self.inner
                 .ready()
@@ -185,135 +387,1064 @@ pub mod product_services_client {
             );
             self.inner.unary(request.into_request(), path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod product_services_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    ///Generated trait containing gRPC methods that should be implemented for use with ProductServicesServer.
-    #[async_trait]
-    pub trait ProductServices: Send + Sync + 'static {
-        async fn create(
-            &self,
-            request: tonic::Request<super::CreateProductRequest>,
-        ) -> Result<tonic::Response<super::Product>, tonic::Status>;
-        async fn update(
-            &self,
-            request: tonic::Request<super::UpdateProductRequest>,
-        ) -> Result<tonic::Response<super::Product>, tonic::Status>;
-        async fn get(
-            &self,
-            request: tonic::Request<super::super::types::GetByIdRequest>,
-        ) -> Result<tonic::Response<super::GetProductResponse>, tonic::Status>;
-        async fn list(
-            &self,
-            request: tonic::Request<super::super::types::ListRequest>,
-        ) -> Result<tonic::Response<super::ListProductResponse>, tonic::Status>;
-    }
-    #[derive(Debug)]
-    pub struct ProductServicesServer<T: ProductServices> {
-        inner: _Inner<T>,
-        accept_compression_encodings: (),
-        send_compression_encodings: (),
-    }
-    struct _Inner<T>(Arc<T>);
-    impl<T: ProductServices> ProductServicesServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+        /// find the products by name and tags, a romaji query also matches the names in kana.
+        pub async fn search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListProductResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/search",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            let inner = _Inner(inner);
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-            }
+        /// stream all of the products which match the filters, the paging fields are ignored.
+        pub async fn export(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListProductRequest>,
+        ) -> Result<
+                tonic::Response<tonic::codec::Streaming<super::Product>>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/export",
+            );
+            self.inner.server_streaming(request.into_request(), path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        pub async fn import(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::CreateProductRequest>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/import",
+            );
+            self.inner
+                .client_streaming(request.into_streaming_request(), path, codec)
+                .await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for ProductServicesServer<T>
-    where
-        T: ProductServices,
-        B: Body + Send + 'static,
-        B::Error: Into<StdError> + Send + 'static,
-    {
-        type Response = http::Response<tonic::body::BoxBody>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        pub async fn create_category(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::CreateCategoryRequest>,
+        ) -> Result<tonic::Response<super::Category>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/create_category",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            let inner = self.inner.clone();
-            match req.uri().path() {
-                "/grpc.product.ProductServices/create" => {
-                    #[allow(non_camel_case_types)]
-                    struct createSvc<T: ProductServices>(pub Arc<T>);
-                    impl<
-                        T: ProductServices,
-                    > tonic::server::UnaryService<super::CreateProductRequest>
-                    for createSvc<T> {
-                        type Response = super::Product;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CreateProductRequest>,
-                        ) -> Self::Future {
-                            let inner = self.0.clone();
-                            let fut = async move { (*inner).create(request).await };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let inner = inner.0;
-                        let method = createSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/grpc.product.ProductServices/update" => {
-                    #[allow(non_camel_case_types)]
-                    struct updateSvc<T: ProductServices>(pub Arc<T>);
-                    impl<
-                        T: ProductServices,
-                    > tonic::server::UnaryService<super::UpdateProductRequest>
-                    for updateSvc<T> {
+        pub async fn update_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateCategoryRequest>,
+        ) -> Result<tonic::Response<super::Category>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/update_category",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/delete_category",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_categories(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCategoryRequest>,
+        ) -> Result<tonic::Response<super::ListCategoryResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/list_categories",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTagRequest>,
+        ) -> Result<tonic::Response<super::Tag>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/create_tag",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn update_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateTagRequest>,
+        ) -> Result<tonic::Response<super::Tag>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/update_tag",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_tag(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/delete_tag",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_tags(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<super::ListTagResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/list_tags",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn add_image(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddProductImageRequest>,
+        ) -> Result<tonic::Response<super::ProductImage>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/add_image",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_image(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::ProductImage>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/delete_image",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn create_supplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateSupplierRequest>,
+        ) -> Result<tonic::Response<super::Supplier>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/create_supplier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn update_supplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateSupplierRequest>,
+        ) -> Result<tonic::Response<super::Supplier>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/update_supplier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn delete_supplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/delete_supplier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn list_suppliers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<super::ListSupplierResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/list_suppliers",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn set_product_supplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetProductSupplierRequest>,
+        ) -> Result<tonic::Response<super::Product>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/set_product_supplier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        pub async fn remove_product_supplier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::super::types::GetByIdRequest>,
+        ) -> Result<
+                tonic::Response<super::super::types::DeleteResponse>,
+                tonic::Status,
+            > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/grpc.product.ProductServices/remove_product_supplier",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod product_services_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    ///Generated trait containing gRPC methods that should be implemented for use with ProductServicesServer.
+    #[async_trait]
+    pub trait ProductServices: Send + Sync + 'static {
+        async fn create(
+            &self,
+            request: tonic::Request<super::CreateProductRequest>,
+        ) -> Result<tonic::Response<super::Product>, tonic::Status>;
+        async fn update(
+            &self,
+            request: tonic::Request<super::UpdateProductRequest>,
+        ) -> Result<tonic::Response<super::Product>, tonic::Status>;
+        async fn get(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::GetProductResponse>, tonic::Status>;
+        async fn list(
+            &self,
+            request: tonic::Request<super::ListProductRequest>,
+        ) -> Result<tonic::Response<super::ListProductResponse>, tonic::Status>;
+        /// find the products by name and tags, a romaji query also matches the names in kana.
+        async fn search(
+            &self,
+            request: tonic::Request<super::super::types::SearchRequest>,
+        ) -> Result<tonic::Response<super::ListProductResponse>, tonic::Status>;
+        ///Server streaming response type for the export method.
+        type exportStream: futures_core::Stream<
+                Item = Result<super::Product, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// stream all of the products which match the filters, the paging fields are ignored.
+        async fn export(
+            &self,
+            request: tonic::Request<super::ListProductRequest>,
+        ) -> Result<tonic::Response<Self::exportStream>, tonic::Status>;
+        async fn import(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::CreateProductRequest>>,
+        ) -> Result<tonic::Response<super::super::types::ImportReport>, tonic::Status>;
+        async fn create_category(
+            &self,
+            request: tonic::Request<super::CreateCategoryRequest>,
+        ) -> Result<tonic::Response<super::Category>, tonic::Status>;
+        async fn update_category(
+            &self,
+            request: tonic::Request<super::UpdateCategoryRequest>,
+        ) -> Result<tonic::Response<super::Category>, tonic::Status>;
+        async fn delete_category(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        async fn list_categories(
+            &self,
+            request: tonic::Request<super::ListCategoryRequest>,
+        ) -> Result<tonic::Response<super::ListCategoryResponse>, tonic::Status>;
+        async fn create_tag(
+            &self,
+            request: tonic::Request<super::CreateTagRequest>,
+        ) -> Result<tonic::Response<super::Tag>, tonic::Status>;
+        async fn update_tag(
+            &self,
+            request: tonic::Request<super::UpdateTagRequest>,
+        ) -> Result<tonic::Response<super::Tag>, tonic::Status>;
+        async fn delete_tag(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        async fn list_tags(
+            &self,
+            request: tonic::Request<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<super::ListTagResponse>, tonic::Status>;
+        async fn add_image(
+            &self,
+            request: tonic::Request<super::AddProductImageRequest>,
+        ) -> Result<tonic::Response<super::ProductImage>, tonic::Status>;
+        async fn delete_image(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::ProductImage>, tonic::Status>;
+        async fn create_supplier(
+            &self,
+            request: tonic::Request<super::CreateSupplierRequest>,
+        ) -> Result<tonic::Response<super::Supplier>, tonic::Status>;
+        async fn update_supplier(
+            &self,
+            request: tonic::Request<super::UpdateSupplierRequest>,
+        ) -> Result<tonic::Response<super::Supplier>, tonic::Status>;
+        async fn delete_supplier(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+        async fn list_suppliers(
+            &self,
+            request: tonic::Request<super::super::types::ListRequest>,
+        ) -> Result<tonic::Response<super::ListSupplierResponse>, tonic::Status>;
+        async fn set_product_supplier(
+            &self,
+            request: tonic::Request<super::SetProductSupplierRequest>,
+        ) -> Result<tonic::Response<super::Product>, tonic::Status>;
+        async fn remove_product_supplier(
+            &self,
+            request: tonic::Request<super::super::types::GetByIdRequest>,
+        ) -> Result<tonic::Response<super::super::types::DeleteResponse>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ProductServicesServer<T: ProductServices> {
+        inner: _Inner<T>,
+        accept_compression_encodings: (),
+        send_compression_encodings: (),
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: ProductServices> ProductServicesServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for ProductServicesServer<T>
+    where
+        T: ProductServices,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/grpc.product.ProductServices/create" => {
+                    #[allow(non_camel_case_types)]
+                    struct createSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::CreateProductRequest>
+                    for createSvc<T> {
+                        type Response = super::Product;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateProductRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = createSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/update" => {
+                    #[allow(non_camel_case_types)]
+                    struct updateSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::UpdateProductRequest>
+                    for updateSvc<T> {
+                        type Response = super::Product;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateProductRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).update(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = updateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for getSvc<T> {
+                        type Response = super::GetProductResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/list" => {
+                    #[allow(non_camel_case_types)]
+                    struct listSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::ListProductRequest>
+                    for listSvc<T> {
+                        type Response = super::ListProductResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListProductRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = listSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/search" => {
+                    #[allow(non_camel_case_types)]
+                    struct searchSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::SearchRequest>
+                    for searchSvc<T> {
+                        type Response = super::ListProductResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::SearchRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).search(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = searchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/export" => {
+                    #[allow(non_camel_case_types)]
+                    struct exportSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::ServerStreamingService<super::ListProductRequest>
+                    for exportSvc<T> {
                         type Response = super::Product;
+                        type ResponseStream = T::exportStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListProductRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).export(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = exportSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/import" => {
+                    #[allow(non_camel_case_types)]
+                    struct importSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::ClientStreamingService<super::CreateProductRequest>
+                    for importSvc<T> {
+                        type Response = super::super::types::ImportReport;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::CreateProductRequest>>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).import(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = importSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/create_category" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_categorySvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::CreateCategoryRequest>
+                    for create_categorySvc<T> {
+                        type Response = super::Category;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).create_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_categorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/update_category" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_categorySvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::UpdateCategoryRequest>
+                    for update_categorySvc<T> {
+                        type Response = super::Category;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).update_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = update_categorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/delete_category" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_categorySvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for delete_categorySvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).delete_category(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_categorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/list_categories" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_categoriesSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::ListCategoryRequest>
+                    for list_categoriesSvc<T> {
+                        type Response = super::ListCategoryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).list_categories(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_categoriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/create_tag" => {
+                    #[allow(non_camel_case_types)]
+                    struct create_tagSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::CreateTagRequest>
+                    for create_tagSvc<T> {
+                        type Response = super::Tag;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTagRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).create_tag(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_tagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/update_tag" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_tagSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::UpdateTagRequest>
+                    for update_tagSvc<T> {
+                        type Response = super::Tag;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateTagRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).update_tag(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = update_tagSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/delete_tag" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_tagSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for delete_tagSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::UpdateProductRequest>,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).update(request).await };
+                            let fut = async move { (*inner).delete_tag(request).await };
                             Box::pin(fut)
                         }
                     }
@@ -322,7 +1453,7 @@ pub mod product_services_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = updateSvc(inner);
+                        let method = delete_tagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -334,14 +1465,90 @@ pub mod product_services_server {
                     };
                     Box::pin(fut)
                 }
-                "/grpc.product.ProductServices/get" => {
+                "/grpc.product.ProductServices/list_tags" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: ProductServices>(pub Arc<T>);
+                    struct list_tagsSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::ListRequest>
+                    for list_tagsSvc<T> {
+                        type Response = super::ListTagResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::ListRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).list_tags(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = list_tagsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/add_image" => {
+                    #[allow(non_camel_case_types)]
+                    struct add_imageSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::AddProductImageRequest>
+                    for add_imageSvc<T> {
+                        type Response = super::ProductImage;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddProductImageRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).add_image(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = add_imageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/delete_image" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_imageSvc<T: ProductServices>(pub Arc<T>);
                     impl<
                         T: ProductServices,
                     > tonic::server::UnaryService<super::super::types::GetByIdRequest>
-                    for getSvc<T> {
-                        type Response = super::GetProductResponse;
+                    for delete_imageSvc<T> {
+                        type Response = super::ProductImage;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -351,7 +1558,9 @@ pub mod product_services_server {
                             request: tonic::Request<super::super::types::GetByIdRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).get(request).await };
+                            let fut = async move {
+                                (*inner).delete_image(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -360,7 +1569,7 @@ pub mod product_services_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = getSvc(inner);
+                        let method = delete_imageSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -372,14 +1581,134 @@ pub mod product_services_server {
                     };
                     Box::pin(fut)
                 }
-                "/grpc.product.ProductServices/list" => {
+                "/grpc.product.ProductServices/create_supplier" => {
                     #[allow(non_camel_case_types)]
-                    struct listSvc<T: ProductServices>(pub Arc<T>);
+                    struct create_supplierSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::CreateSupplierRequest>
+                    for create_supplierSvc<T> {
+                        type Response = super::Supplier;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateSupplierRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).create_supplier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = create_supplierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/update_supplier" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_supplierSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::UpdateSupplierRequest>
+                    for update_supplierSvc<T> {
+                        type Response = super::Supplier;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateSupplierRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).update_supplier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = update_supplierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/delete_supplier" => {
+                    #[allow(non_camel_case_types)]
+                    struct delete_supplierSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for delete_supplierSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).delete_supplier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = delete_supplierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/list_suppliers" => {
+                    #[allow(non_camel_case_types)]
+                    struct list_suppliersSvc<T: ProductServices>(pub Arc<T>);
                     impl<
                         T: ProductServices,
                     > tonic::server::UnaryService<super::super::types::ListRequest>
-                    for listSvc<T> {
-                        type Response = super::ListProductResponse;
+                    for list_suppliersSvc<T> {
+                        type Response = super::ListSupplierResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -389,7 +1718,9 @@ pub mod product_services_server {
                             request: tonic::Request<super::super::types::ListRequest>,
                         ) -> Self::Future {
                             let inner = self.0.clone();
-                            let fut = async move { (*inner).list(request).await };
+                            let fut = async move {
+                                (*inner).list_suppliers(request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -398,7 +1729,87 @@ pub mod product_services_server {
                     let inner = self.inner.clone();
                     let fut = async move {
                         let inner = inner.0;
-                        let method = listSvc(inner);
+                        let method = list_suppliersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/set_product_supplier" => {
+                    #[allow(non_camel_case_types)]
+                    struct set_product_supplierSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::SetProductSupplierRequest>
+                    for set_product_supplierSvc<T> {
+                        type Response = super::Product;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetProductSupplierRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).set_product_supplier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = set_product_supplierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/grpc.product.ProductServices/remove_product_supplier" => {
+                    #[allow(non_camel_case_types)]
+                    struct remove_product_supplierSvc<T: ProductServices>(pub Arc<T>);
+                    impl<
+                        T: ProductServices,
+                    > tonic::server::UnaryService<super::super::types::GetByIdRequest>
+                    for remove_product_supplierSvc<T> {
+                        type Response = super::super::types::DeleteResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::super::types::GetByIdRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move {
+                                (*inner).remove_product_supplier(request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = remove_product_supplierSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
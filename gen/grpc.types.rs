@@ -12,3 +12,81 @@ pub struct ListRequest {
     #[prost(uint64, tag="3")]
     pub page_size: u64,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchRequest {
+    #[prost(string, tag="1")]
+    pub query: ::prost::alloc::string::String,
+    /// the number of the results, it is 10 by default and 50 at most.
+    #[prost(uint64, tag="2")]
+    pub limit: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteResponse {
+    #[prost(bool, tag="1")]
+    pub result: bool,
+}
+/// an internal comment of the staff, e.g. "prefers pickup on weekends".
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Note {
+    #[prost(uint64, tag="1")]
+    pub id: u64,
+    #[prost(string, tag="2")]
+    pub author: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub body: ::prost::alloc::string::String,
+    #[prost(uint64, tag="4")]
+    pub created_at: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateNoteRequest {
+    /// the id of the customer or the order item which the note is attached to.
+    #[prost(uint64, tag="1")]
+    pub owner_id: u64,
+    #[prost(string, tag="2")]
+    pub author: ::prost::alloc::string::String,
+    #[prost(string, tag="3")]
+    pub body: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DeleteNoteRequest {
+    #[prost(uint64, tag="1")]
+    pub owner_id: u64,
+    #[prost(uint64, tag="2")]
+    pub id: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListNotesResponse {
+    /// the newest note is the first one.
+    #[prost(message, repeated, tag="1")]
+    pub notes: ::prost::alloc::vec::Vec<Note>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportReport {
+    #[prost(message, repeated, tag="1")]
+    pub rows: ::prost::alloc::vec::Vec<ImportRowResult>,
+    #[prost(uint32, tag="2")]
+    pub created: u32,
+    #[prost(uint32, tag="3")]
+    pub skipped: u32,
+    #[prost(uint32, tag="4")]
+    pub failed: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportRowResult {
+    /// the 1-based position of the row in the import stream.
+    #[prost(uint32, tag="1")]
+    pub row: u32,
+    #[prost(enumeration="ImportRowStatus", tag="2")]
+    pub status: i32,
+    #[prost(uint64, optional, tag="3")]
+    pub id: ::core::option::Option<u64>,
+    #[prost(string, optional, tag="4")]
+    pub reason: ::core::option::Option<::prost::alloc::string::String>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ImportRowStatus {
+    Created = 0,
+    Skipped = 1,
+    Failed = 2,
+}
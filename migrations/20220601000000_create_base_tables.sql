@@ -0,0 +1,32 @@
+CREATE TABLE IF NOT EXISTS customers
+(
+    id         BIGINT PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    email      VARCHAR,
+    phone      VARCHAR,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS products
+(
+    id         BIGINT PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    currency   SMALLINT    NOT NULL,
+    price      NUMERIC     NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ,
+    deleted_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS order_items
+(
+    id          BIGINT PRIMARY KEY,
+    customer_id BIGINT      NOT NULL REFERENCES customers (id),
+    product_id  BIGINT      NOT NULL REFERENCES products (id),
+    quantity    SMALLINT    NOT NULL,
+    status      SMALLINT    NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL,
+    updated_at  TIMESTAMPTZ,
+    deleted_at  TIMESTAMPTZ
+);
@@ -0,0 +1,29 @@
+CREATE TABLE IF NOT EXISTS categories
+(
+    id         BIGINT PRIMARY KEY,
+    parent_id  BIGINT REFERENCES categories (id),
+    name       VARCHAR     NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS categories_parent_id_idx ON categories (parent_id);
+
+CREATE TABLE IF NOT EXISTS tags
+(
+    id         BIGINT PRIMARY KEY,
+    name       VARCHAR     NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS product_tags
+(
+    product_id BIGINT NOT NULL REFERENCES products (id),
+    tag_id     BIGINT NOT NULL REFERENCES tags (id) ON DELETE CASCADE,
+    PRIMARY KEY (product_id, tag_id)
+);
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS category_id BIGINT REFERENCES categories (id);
+
+CREATE INDEX IF NOT EXISTS products_category_id_idx ON products (category_id);
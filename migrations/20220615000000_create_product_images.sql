@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS product_images
+(
+    id            BIGINT PRIMARY KEY,
+    product_id    BIGINT      NOT NULL REFERENCES products (id),
+    key           VARCHAR     NOT NULL,
+    url           VARCHAR     NOT NULL,
+    thumbnail_key VARCHAR     NOT NULL,
+    thumbnail_url VARCHAR     NOT NULL,
+    content_type  VARCHAR     NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS product_images_product_id_idx ON product_images (product_id);
@@ -0,0 +1,22 @@
+CREATE TABLE IF NOT EXISTS suppliers
+(
+    id         BIGINT PRIMARY KEY,
+    name       VARCHAR     NOT NULL,
+    website    VARCHAR,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS product_suppliers
+(
+    product_id    BIGINT PRIMARY KEY REFERENCES products (id),
+    supplier_id   BIGINT      NOT NULL REFERENCES suppliers (id) ON DELETE CASCADE,
+    source_url    VARCHAR,
+    cost_price    NUMERIC     NOT NULL,
+    cost_currency SMALLINT    NOT NULL,
+    exchange_rate NUMERIC     NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL,
+    updated_at    TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS product_suppliers_supplier_id_idx ON product_suppliers (supplier_id);
@@ -0,0 +1,5 @@
+ALTER TABLE customers
+    ADD COLUMN IF NOT EXISTS deleted_at  TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS merged_into BIGINT REFERENCES customers (id);
+
+CREATE INDEX IF NOT EXISTS order_items_customer_id_idx ON order_items (customer_id);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS contact_channels
+(
+    id          BIGINT PRIMARY KEY,
+    customer_id BIGINT      NOT NULL REFERENCES customers (id),
+    channel     VARCHAR     NOT NULL,
+    handle      VARCHAR     NOT NULL,
+    preferred   BOOLEAN     NOT NULL DEFAULT FALSE,
+    created_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS contact_channels_customer_id_idx ON contact_channels (customer_id);
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS addresses
+(
+    id          BIGINT PRIMARY KEY,
+    customer_id BIGINT      NOT NULL REFERENCES customers (id),
+    recipient   VARCHAR     NOT NULL,
+    phone       VARCHAR,
+    line1       VARCHAR     NOT NULL,
+    line2       VARCHAR,
+    city        VARCHAR     NOT NULL,
+    region      VARCHAR,
+    postal_code VARCHAR,
+    country     VARCHAR(2)  NOT NULL,
+    is_default  BOOLEAN     NOT NULL DEFAULT FALSE,
+    created_at  TIMESTAMPTZ NOT NULL,
+    updated_at  TIMESTAMPTZ,
+    deleted_at  TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS addresses_customer_id_idx ON addresses (customer_id);
+
+-- a customer has one default address at most.
+CREATE UNIQUE INDEX IF NOT EXISTS addresses_default_idx ON addresses (customer_id)
+    WHERE is_default AND deleted_at IS NULL;
+
+ALTER TABLE order_items
+    ADD COLUMN IF NOT EXISTS address_id BIGINT REFERENCES addresses (id);
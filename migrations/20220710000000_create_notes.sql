@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS customer_notes
+(
+    id          BIGINT PRIMARY KEY,
+    customer_id BIGINT      NOT NULL REFERENCES customers (id),
+    author      VARCHAR     NOT NULL,
+    body        TEXT        NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS customer_notes_customer_id_idx ON customer_notes (customer_id);
+
+CREATE TABLE IF NOT EXISTS order_item_notes
+(
+    id            BIGINT PRIMARY KEY,
+    order_item_id BIGINT      NOT NULL REFERENCES order_items (id),
+    author        VARCHAR     NOT NULL,
+    body          TEXT        NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS order_item_notes_order_item_id_idx ON order_item_notes (order_item_id);
@@ -0,0 +1,11 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS customers_name_trgm_idx ON customers USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS customers_email_trgm_idx ON customers USING GIN (email gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS customers_phone_trgm_idx ON customers USING GIN (phone gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS contact_channels_handle_trgm_idx ON contact_channels USING GIN (handle gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS products_name_trgm_idx ON products USING GIN (name gin_trgm_ops);
+
+CREATE INDEX IF NOT EXISTS tags_name_trgm_idx ON tags USING GIN (name gin_trgm_ops);
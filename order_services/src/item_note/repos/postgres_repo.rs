@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use common::json::note::{Note, OrderItemNotes};
+use common::types::CreateNoteRequest;
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::item_note::repos::repo::NoteRepo;
+use crate::ID_GENERATOR;
+
+const NOTE_COLUMNS: [OrderItemNotes; 4] = [
+    OrderItemNotes::Id,
+    OrderItemNotes::Author,
+    OrderItemNotes::Body,
+    OrderItemNotes::CreatedAt,
+];
+
+pub(crate) struct NoteRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl NoteRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl NoteRepo for NoteRepoImpl {
+    async fn create(&self, req: CreateNoteRequest) -> anyhow::Result<Note> {
+        let id = ID_GENERATOR.lock().unwrap().next_id();
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::insert()
+            .into_table(OrderItemNotes::Table)
+            .columns(vec![
+                OrderItemNotes::Id,
+                OrderItemNotes::OrderItemId,
+                OrderItemNotes::Author,
+                OrderItemNotes::Body,
+                OrderItemNotes::CreatedAt,
+            ])
+            .values_panic(vec![
+                id.into(),
+                req.owner_id.into(),
+                req.author.into(),
+                req.body.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(NOTE_COLUMNS).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Note>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn delete(&self, order_item_id: u64, id: u64) -> anyhow::Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(OrderItemNotes::Table)
+            .and_where(Expr::col(OrderItemNotes::Id).eq(id))
+            .and_where(Expr::col(OrderItemNotes::OrderItemId).eq(order_item_id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, order_item_id: u64) -> anyhow::Result<Vec<Note>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(NOTE_COLUMNS)
+            .from(OrderItemNotes::Table)
+            .and_where(Expr::col(OrderItemNotes::OrderItemId).eq(order_item_id))
+            .order_by(OrderItemNotes::CreatedAt, Order::Desc)
+            .order_by(OrderItemNotes::Id, Order::Desc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Note>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+}
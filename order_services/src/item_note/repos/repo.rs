@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::note::Note;
+use common::types::CreateNoteRequest;
+
+#[async_trait]
+pub trait NoteRepo {
+    /// the owner id of the request is the order item id.
+    async fn create(&self, req: CreateNoteRequest) -> Result<Note>;
+
+    async fn delete(&self, order_item_id: u64, id: u64) -> Result<bool>;
+
+    /// list the notes of the order item, the newest note is the first one.
+    async fn list(&self, order_item_id: u64) -> Result<Vec<Note>>;
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+use std::sync::Arc;
+
+use common::json::note::Note;
+use common::types::CreateNoteRequest;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::database_error_handler;
+use common::util::validation::validate_note;
+
+use crate::item_note::repos::postgres_repo::NoteRepoImpl;
+use crate::item_note::repos::repo::NoteRepo;
+use crate::order::repos::postgres_repo::OrderItemRepoImpl;
+use crate::order::repos::repo::OrderItemRepo;
+
+#[async_trait]
+pub trait NoteService {
+    async fn create(self, req: CreateNoteRequest) -> AppResult<Note>;
+
+    async fn delete(self, order_item_id: u64, id: u64) -> AppResult<bool>;
+
+    async fn list(self, order_item_id: u64) -> AppResult<Vec<Note>>;
+}
+
+pub(crate) struct NoteServiceImpl {
+    repo: Box<dyn NoteRepo + Send + Sync>,
+    order_repo: Box<dyn OrderItemRepo + Send + Sync>,
+}
+
+impl NoteServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(NoteRepoImpl::new(session.clone()));
+        let order_repo = Box::new(OrderItemRepoImpl::new(session));
+
+        Self { repo, order_repo }
+    }
+
+    async fn check_order_item_is_exist(&self, order_item_id: u64) -> AppResult<()> {
+        let order_item = self
+            .order_repo
+            .get(order_item_id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if order_item.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the order item by id: {}",
+                order_item_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl NoteService for NoteServiceImpl {
+    async fn create(self, req: CreateNoteRequest) -> AppResult<Note> {
+        self.check_order_item_is_exist(req.owner_id).await?;
+
+        let req = validate_note(req)?;
+
+        self.repo.create(req).await.map_err(database_error_handler)
+    }
+
+    async fn delete(self, order_item_id: u64, id: u64) -> AppResult<bool> {
+        let is_deleted = self
+            .repo
+            .delete(order_item_id, id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if !is_deleted {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the note by id: {}",
+                id
+            )));
+        }
+
+        Ok(is_deleted)
+    }
+
+    async fn list(self, order_item_id: u64) -> AppResult<Vec<Note>> {
+        self.check_order_item_is_exist(order_item_id).await?;
+
+        self.repo
+            .list(order_item_id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
@@ -1,57 +1,89 @@
 use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
+use clap::Parser;
 use lazy_static::lazy_static;
 use snowflake::SnowflakeGenerator;
-use tonic::transport::Server;
+use tonic::transport::{NamedService, Server};
 
-use common::config::id_generator_config::IdGeneratorConfig;
+use common::config::settings::Settings;
 use common::order_item_pb::order_services_server::OrderServicesServer;
-use common::util::connections::{create_database_connection, create_id_generator};
-use common::util::tools::{
-    read_config_from_env, read_postgresql_config_from_env, tracing_initialize,
-};
+use common::util::cli::{run_command, Cli, Command};
+use common::util::connections::{create_cache, create_database_connection, create_id_generator};
+use common::util::health::health_service;
+use common::util::metrics::{serve_metrics, GrpcMetricsLayer};
+use common::util::shutdown::Shutdown;
+use common::util::telemetry::grpc_request_span;
+use common::util::tools::tracing_initialize;
 
 use crate::order::services::grpc_service::GrpcOrderServiceImpl;
 
+mod item_note;
 mod order;
 
 lazy_static! {
+    static ref CLI: Cli = Cli::parse();
+    #[cfg(not(test))]
+    static ref SETTINGS: Settings = Settings::load_or_exit(&CLI.source());
+    #[cfg(test)]
+    static ref SETTINGS: Settings = Settings::default();
     static ref ID_GENERATOR: Arc<Mutex<SnowflakeGenerator>> = {
-        let config = IdGeneratorConfig::new(0, 0, 0);
-        let generator = create_id_generator(config);
+        let generator = create_id_generator(SETTINGS.id_generator.clone());
         Arc::new(Mutex::new(generator))
     };
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let env_file = concat!(env!("CARGO_MANIFEST_DIR"), "/", "env", "/", "dev.env");
-    let _ = dotenv::from_path(env_file);
+    let settings = &*SETTINGS;
 
-    let config = read_config_from_env();
-
-    tracing_initialize(config.debug, "logs", "customers");
+    match CLI.command() {
+        Command::Serve => serve(settings).await,
+        command => run_command(command, settings).await,
+    }
+}
 
-    let database_config = read_postgresql_config_from_env();
+async fn serve(settings: &Settings) -> Result<()> {
+    let _tracing_guard = tracing_initialize(settings, &CLI.log_dir, "customers");
 
-    let database_connection = create_database_connection(database_config)
+    let database_connection = create_database_connection(settings.postgres.clone())
         .await
         .expect("Can't connect to database.");
 
-    let order_item_service = GrpcOrderServiceImpl::new(database_connection);
+    let cache = create_cache(settings.cache.clone()).await;
+
+    let order_item_service = GrpcOrderServiceImpl::new(database_connection.clone(), cache);
+
+    let shutdown = Shutdown::listen();
 
-    let addr = dotenv::var("ORDER_HOST_ADDRESS")
-        .unwrap_or_else(|_| "127.0.0.1:10003".to_string())
-        .parse()
-        .expect("Can't parse hosting address.");
+    let health_service = health_service(
+        database_connection.clone(),
+        vec![OrderServicesServer::<GrpcOrderServiceImpl>::NAME],
+        &shutdown,
+    );
+
+    let addr = CLI.listen_or(settings.addresses.order_host);
 
     tracing::info!(message = "starting customer server", %addr);
 
-    Server::builder()
+    serve_metrics(
+        settings.addresses.order_metrics,
+        database_connection.clone(),
+        &shutdown,
+    );
+
+    let server = Server::builder()
+        .trace_fn(grpc_request_span)
+        .layer(GrpcMetricsLayer)
+        .add_service(health_service)
         .add_service(OrderServicesServer::new(order_item_service))
-        .serve(addr)
-        .await?;
+        .serve_with_shutdown(addr, shutdown.triggered());
+
+    let drained = shutdown.drain(server, settings.shutdown_timeout).await;
+    if let Some(result) = drained {
+        database_connection.close().await;
+        result?;
+    }
 
     Ok(())
 }
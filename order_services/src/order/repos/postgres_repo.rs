@@ -1,19 +1,25 @@
 use async_trait::async_trait;
 use futures::lock::Mutex;
 use futures::FutureExt;
-use sea_query::{Cond, Expr, JoinType, PostgresQueryBuilder, Query};
+use sea_query::{Alias, Cond, Expr, JoinType, Order, PostgresQueryBuilder, Query, SelectStatement};
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
+use std::collections::{HashMap, HashSet};
 use std::ops::DerefMut;
 use std::sync::Arc;
 
+use common::json::address::Addresses;
 use common::json::customer::{Customer, Customers};
 use common::json::order_item::{OrderItem, OrderItems};
 use common::json::product::{Product, Products};
+use common::json::shopping_list::ShoppingListEntry;
+use common::json::supplier::{ProductSuppliers, Suppliers};
 use common::order_item_pb::{
     CreateOrderItemRequest, UpdateOrderItemRequest, UpdateOrderItemsStatusRequest,
 };
 use common::types::ListRequest;
+use common::util::search::{column_sql, match_expr, rank_expr, search_terms};
+use common::util::sql::{bind_query, bind_query_as};
 
 use crate::order::repos::repo::{CustomerRepo, OrderItemRepo, ProductRepo};
 use crate::ID_GENERATOR;
@@ -26,6 +32,70 @@ impl OrderItemRepoImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
         Self { session }
     }
+
+    /// select the order items with their customers and products which match the query.
+    fn filtered_select(query: Option<String>) -> SelectStatement {
+        let order_item_cols = vec![
+            (OrderItems::Table, OrderItems::Id),
+            (OrderItems::Table, OrderItems::Quantity),
+            (OrderItems::Table, OrderItems::Status),
+            (OrderItems::Table, OrderItems::CreatedAt),
+            (OrderItems::Table, OrderItems::UpdatedAt),
+            (OrderItems::Table, OrderItems::DeletedAt),
+            (OrderItems::Table, OrderItems::CustomerId),
+            (OrderItems::Table, OrderItems::ProductId),
+        ];
+        let customer_cols = vec![
+            (Customers::Table, Customers::Name),
+            (Customers::Table, Customers::CreatedAt),
+        ];
+        let product_cols = vec![
+            (Products::Table, Products::Name),
+            (Products::Table, Products::Currency),
+            (Products::Table, Products::Price),
+            (Products::Table, Products::CreatedAt),
+        ];
+
+        let query_cond = query
+            .map(|q| search_terms(&q))
+            .filter(|terms| !terms.is_empty())
+            .map(|terms| {
+                Self::search_columns()
+                    .iter()
+                    .fold(Cond::any(), |cond, column| {
+                        cond.add(match_expr(column, &terms))
+                    })
+            });
+
+        Query::select()
+            .columns(order_item_cols)
+            .columns(customer_cols)
+            .columns(product_cols)
+            .column((OrderItems::Table, OrderItems::AddressId))
+            .from(OrderItems::Table)
+            .join(
+                JoinType::InnerJoin,
+                Customers::Table,
+                Expr::tbl(OrderItems::Table, OrderItems::CustomerId)
+                    .equals(Customers::Table, Customers::Id),
+            )
+            .join(
+                JoinType::InnerJoin,
+                Products::Table,
+                Expr::tbl(OrderItems::Table, OrderItems::ProductId)
+                    .equals(Products::Table, Products::Id),
+            )
+            .cond_where(Cond::all().add_option(query_cond))
+            .take()
+    }
+
+    fn search_columns() -> Vec<String> {
+        vec![
+            column_sql(Customers::Table, Customers::Name),
+            column_sql(Customers::Table, Customers::Phone),
+            column_sql(Products::Table, Products::Name),
+        ]
+    }
 }
 
 pub(crate) struct ProductRepoImpl {
@@ -74,10 +144,11 @@ impl OrderItemRepo for OrderItemRepoImpl {
             (Products::Table, Products::CreatedAt),
         ];
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns(order_item_cols)
             .columns(customer_cols)
             .columns(product_cols)
+            .column((OrderItems::Table, OrderItems::AddressId))
             .from(OrderItems::Table)
             .join(
                 JoinType::InnerJoin,
@@ -92,9 +163,9 @@ impl OrderItemRepo for OrderItemRepoImpl {
                     .equals(Products::Table, Products::Id),
             )
             .and_where(Expr::tbl(OrderItems::Table, OrderItems::Id).eq(id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, OrderItem>(&sql)
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await?)
     }
@@ -106,7 +177,7 @@ impl OrderItemRepo for OrderItemRepoImpl {
             .boxed()
             .await as u64;
 
-        let sql = Query::insert()
+        let (sql, values) = Query::insert()
             .into_table(OrderItems::Table)
             .columns(vec![
                 OrderItems::Id,
@@ -115,6 +186,7 @@ impl OrderItemRepo for OrderItemRepoImpl {
                 OrderItems::Quantity,
                 OrderItems::Status,
                 OrderItems::CreatedAt,
+                OrderItems::AddressId,
             ])
             .values_panic(vec![
                 id.into(),
@@ -123,78 +195,150 @@ impl OrderItemRepo for OrderItemRepoImpl {
                 req.quantity.into(),
                 req.status.into(),
                 chrono::Utc::now().into(),
+                req.address_id.into(),
             ])
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        let _ = sqlx::query(&sql).execute(conn.deref_mut()).await?;
+        let _ = bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
 
         Ok(id)
     }
 
-    async fn list(&self, req: ListRequest) -> anyhow::Result<Vec<OrderItem>> {
+    async fn create_many(&self, reqs: Vec<CreateOrderItemRequest>) -> anyhow::Result<Vec<u64>> {
+        if reqs.is_empty() {
+            return Ok(vec![]);
+        }
+
         let mut conn = self.session.lock().await;
 
-        let order_item_cols = vec![
-            (OrderItems::Table, OrderItems::Id),
-            (OrderItems::Table, OrderItems::Quantity),
-            (OrderItems::Table, OrderItems::Status),
-            (OrderItems::Table, OrderItems::CreatedAt),
-            (OrderItems::Table, OrderItems::UpdatedAt),
-            (OrderItems::Table, OrderItems::DeletedAt),
-            (OrderItems::Table, OrderItems::CustomerId),
-            (OrderItems::Table, OrderItems::ProductId),
-        ];
-        let customer_cols = vec![
-            (Customers::Table, Customers::Name),
-            (Customers::Table, Customers::CreatedAt),
-        ];
-        let product_cols = vec![
-            (Products::Table, Products::Name),
-            (Products::Table, Products::Currency),
-            (Products::Table, Products::Price),
-            (Products::Table, Products::CreatedAt),
-        ];
+        let ids = {
+            let mut generator = ID_GENERATOR.lock().unwrap();
+            (0..reqs.len())
+                .map(|_| generator.next_id() as u64)
+                .collect::<Vec<_>>()
+        };
+
+        let now = chrono::Utc::now();
+
+        let mut insert = Query::insert();
+        insert.into_table(OrderItems::Table).columns(vec![
+            OrderItems::Id,
+            OrderItems::CustomerId,
+            OrderItems::ProductId,
+            OrderItems::Quantity,
+            OrderItems::Status,
+            OrderItems::CreatedAt,
+            OrderItems::AddressId,
+        ]);
+
+        for (id, req) in ids.iter().zip(reqs) {
+            insert.values_panic(vec![
+                (*id).into(),
+                req.customer_id.into(),
+                req.product_id.into(),
+                req.quantity.into(),
+                req.status.into(),
+                now.into(),
+                req.address_id.into(),
+            ]);
+        }
+
+        let (sql, values) = insert.build(PostgresQueryBuilder);
+
+        let _ = bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(ids)
+    }
+
+    async fn list_by_ids(&self, ids: Vec<u64>) -> anyhow::Result<Vec<OrderItem>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Self::filtered_select(None)
+            .cond_where(Cond::all().add(Expr::tbl(OrderItems::Table, OrderItems::Id).is_in(ids)))
+            .order_by((OrderItems::Table, OrderItems::Id), Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list(&self, req: ListRequest) -> anyhow::Result<Vec<OrderItem>> {
+        let mut conn = self.session.lock().await;
 
         let limit = req.page_size;
         let offset = req.page * req.page_size;
-        let query = req.query.map(|q| format!("%{}%", q));
 
-        let sql = Query::select()
-            .columns(order_item_cols)
-            .columns(customer_cols)
-            .columns(product_cols)
-            .from(OrderItems::Table)
-            .join(
-                JoinType::InnerJoin,
-                Customers::Table,
-                Expr::tbl(OrderItems::Table, OrderItems::CustomerId)
-                    .equals(Customers::Table, Customers::Id),
-            )
-            .join(
-                JoinType::InnerJoin,
-                Products::Table,
-                Expr::tbl(OrderItems::Table, OrderItems::ProductId)
-                    .equals(Products::Table, Products::Id),
+        let (sql, values) = Self::filtered_select(req.query)
+            .limit(limit)
+            .offset(offset)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_after(
+        &self,
+        req: ListRequest,
+        after_id: i64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<OrderItem>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Self::filtered_select(req.query)
+            .cond_where(Cond::all().add(Expr::tbl(OrderItems::Table, OrderItems::Id).gt(after_id)))
+            .order_by((OrderItems::Table, OrderItems::Id), Order::Asc)
+            .limit(limit)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_by_customer(&self, customer_id: u64) -> anyhow::Result<Vec<OrderItem>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Self::filtered_select(None)
+            .cond_where(
+                Cond::all()
+                    .add(Expr::tbl(OrderItems::Table, OrderItems::CustomerId).eq(customer_id))
+                    .add(Expr::tbl(OrderItems::Table, OrderItems::DeletedAt).is_null()),
             )
+            .order_by((OrderItems::Table, OrderItems::CreatedAt), Order::Desc)
+            .order_by((OrderItems::Table, OrderItems::Id), Order::Desc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn search(&self, query: String, limit: u64) -> anyhow::Result<Vec<OrderItem>> {
+        let mut conn = self.session.lock().await;
+
+        let terms = search_terms(&query);
+        let columns = vec![
+            column_sql(Customers::Table, Customers::Name),
+            column_sql(Products::Table, Products::Name),
+        ];
+
+        let (sql, values) = Self::filtered_select(Some(query))
             .cond_where(
-                Cond::any()
-                    .add_option(
-                        query
-                            .clone()
-                            .map(|q| Expr::tbl(Customers::Table, Customers::Name).like(&q)),
-                    )
-                    .add_option(
-                        query
-                            .clone()
-                            .map(|q| Expr::tbl(Customers::Table, Customers::Phone).like(&q)),
-                    )
-                    .add_option(query.map(|q| Expr::tbl(Products::Table, Products::Name).like(&q))),
+                Cond::all().add(Expr::tbl(OrderItems::Table, OrderItems::DeletedAt).is_null()),
             )
+            .order_by_expr(rank_expr(&columns, &terms), Order::Desc)
+            .order_by((OrderItems::Table, OrderItems::Id), Order::Desc)
             .limit(limit)
-            .offset(offset)
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, OrderItem>(&sql)
+        Ok(bind_query_as(sqlx::query_as::<_, OrderItem>(&sql), &values)
             .fetch_all(conn.deref_mut())
             .await?)
     }
@@ -220,17 +364,21 @@ impl OrderItemRepo for OrderItemRepoImpl {
             update_values.push((OrderItems::Status, status.into()));
         }
 
+        if let Some(address_id) = req.address_id {
+            update_values.push((OrderItems::AddressId, address_id.into()));
+        }
+
         if update_values.is_empty() {
             return Ok(false);
         }
 
-        let sql = Query::update()
+        let (sql, values) = Query::update()
             .table(OrderItems::Table)
             .values(update_values)
             .and_where(Expr::tbl(OrderItems::Table, OrderItems::Id).eq(req.id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query(&sql)
+        Ok(bind_query(sqlx::query(&sql), &values)
             .execute(conn.deref_mut())
             .await
             .map(|e| e.rows_affected() > 0)?)
@@ -242,17 +390,87 @@ impl OrderItemRepo for OrderItemRepoImpl {
     ) -> anyhow::Result<bool> {
         let mut conn = self.session.lock().await;
 
-        let sql = Query::update()
+        let (sql, values) = Query::update()
             .table(OrderItems::Table)
             .values(vec![(OrderItems::Status, req.status.into())])
             .and_where(Expr::tbl(OrderItems::Table, OrderItems::Id).is_in(req.ids))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query(&sql)
+        Ok(bind_query(sqlx::query(&sql), &values)
             .execute(conn.deref_mut())
             .await
             .map(|e| e.rows_affected() > 0)?)
     }
+
+    async fn list_shopping_list_entries(
+        &self,
+        status: u32,
+        supplier_id: Option<u64>,
+    ) -> anyhow::Result<Vec<ShoppingListEntry>> {
+        let mut conn = self.session.lock().await;
+
+        let supplier_cond = supplier_id
+            .map(|id| Expr::tbl(ProductSuppliers::Table, ProductSuppliers::SupplierId).eq(id));
+
+        let (sql, values) = Query::select()
+            .expr_as(
+                Expr::tbl(OrderItems::Table, OrderItems::Id),
+                Alias::new("order_item_id"),
+            )
+            .column((OrderItems::Table, OrderItems::Quantity))
+            .column((OrderItems::Table, OrderItems::CustomerId))
+            .expr_as(
+                Expr::tbl(Customers::Table, Customers::Name),
+                Alias::new("customer_name"),
+            )
+            .column((OrderItems::Table, OrderItems::ProductId))
+            .expr_as(
+                Expr::tbl(Products::Table, Products::Name),
+                Alias::new("product_name"),
+            )
+            .column((ProductSuppliers::Table, ProductSuppliers::SupplierId))
+            .expr_as(
+                Expr::tbl(Suppliers::Table, Suppliers::Name),
+                Alias::new("supplier_name"),
+            )
+            .column((ProductSuppliers::Table, ProductSuppliers::SourceUrl))
+            .from(OrderItems::Table)
+            .join(
+                JoinType::InnerJoin,
+                Customers::Table,
+                Expr::tbl(OrderItems::Table, OrderItems::CustomerId)
+                    .equals(Customers::Table, Customers::Id),
+            )
+            .join(
+                JoinType::InnerJoin,
+                Products::Table,
+                Expr::tbl(OrderItems::Table, OrderItems::ProductId)
+                    .equals(Products::Table, Products::Id),
+            )
+            .join(
+                JoinType::LeftJoin,
+                ProductSuppliers::Table,
+                Expr::tbl(OrderItems::Table, OrderItems::ProductId)
+                    .equals(ProductSuppliers::Table, ProductSuppliers::ProductId),
+            )
+            .join(
+                JoinType::LeftJoin,
+                Suppliers::Table,
+                Expr::tbl(ProductSuppliers::Table, ProductSuppliers::SupplierId)
+                    .equals(Suppliers::Table, Suppliers::Id),
+            )
+            .and_where(Expr::tbl(OrderItems::Table, OrderItems::Status).eq(status))
+            .and_where(Expr::tbl(OrderItems::Table, OrderItems::DeletedAt).is_null())
+            .and_where_option(supplier_cond)
+            .order_by((OrderItems::Table, OrderItems::CreatedAt), Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        Ok(
+            bind_query_as(sqlx::query_as::<_, ShoppingListEntry>(&sql), &values)
+                .fetch_all(conn.deref_mut())
+                .await?,
+        )
+    }
 }
 
 #[async_trait]
@@ -260,7 +478,7 @@ impl ProductRepo for ProductRepoImpl {
     async fn get(&self, id: u64) -> anyhow::Result<Option<Product>> {
         let mut conn = self.session.lock().await;
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns([
                 Products::Id,
                 Products::Name,
@@ -269,15 +487,32 @@ impl ProductRepo for ProductRepoImpl {
                 Products::CreatedAt,
                 Products::UpdatedAt,
                 Products::DeletedAt,
+                Products::CategoryId,
             ])
             .from(Products::Table)
             .and_where(Expr::col(Products::Id).eq(id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, Product>(sql.as_str())
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await?)
     }
+
+    async fn existing_ids(&self, ids: Vec<u64>) -> anyhow::Result<HashSet<u64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column(Products::Id)
+            .from(Products::Table)
+            .and_where(Expr::col(Products::Id).is_in(ids))
+            .build(PostgresQueryBuilder);
+
+        let ids = bind_query_as(sqlx::query_as::<_, (i64,)>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(ids.into_iter().map(|(e,)| e as u64).collect())
+    }
 }
 
 #[async_trait]
@@ -285,7 +520,7 @@ impl CustomerRepo for CustomerRepoImpl {
     async fn get(&self, id: u64) -> anyhow::Result<Option<Customer>> {
         let mut conn = self.session.lock().await;
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns(vec![
                 Customers::Id,
                 Customers::Name,
@@ -296,10 +531,47 @@ impl CustomerRepo for CustomerRepoImpl {
             ])
             .from(Customers::Table)
             .and_where(Expr::col(Customers::Id).eq(id))
-            .to_string(PostgresQueryBuilder);
+            .and_where(Expr::col(Customers::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, Customer>(&sql)
+        Ok(bind_query_as(sqlx::query_as::<_, Customer>(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await?)
     }
+
+    async fn existing_ids(&self, ids: Vec<u64>) -> anyhow::Result<HashSet<u64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column(Customers::Id)
+            .from(Customers::Table)
+            .and_where(Expr::col(Customers::Id).is_in(ids))
+            .and_where(Expr::col(Customers::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        let ids = bind_query_as(sqlx::query_as::<_, (i64,)>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(ids.into_iter().map(|(e,)| e as u64).collect())
+    }
+    async fn address_owners(&self, address_ids: Vec<u64>) -> anyhow::Result<HashMap<u64, u64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns(vec![Addresses::Id, Addresses::CustomerId])
+            .from(Addresses::Table)
+            .and_where(Expr::col(Addresses::Id).is_in(address_ids))
+            .and_where(Expr::col(Addresses::DeletedAt).is_null())
+            .build(PostgresQueryBuilder);
+
+        let owners = bind_query_as(sqlx::query_as::<_, (i64, i64)>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(owners
+            .into_iter()
+            .map(|(id, customer_id)| (id as u64, customer_id as u64))
+            .collect())
+    }
 }
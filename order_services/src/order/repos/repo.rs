@@ -1,9 +1,12 @@
+use std::collections::{HashMap, HashSet};
+
 use anyhow::Result;
 use async_trait::async_trait;
 
 use common::json::customer::Customer;
 use common::json::order_item::OrderItem;
 use common::json::product::Product;
+use common::json::shopping_list::ShoppingListEntry;
 use common::order_item_pb::{
     CreateOrderItemRequest, UpdateOrderItemRequest, UpdateOrderItemsStatusRequest,
 };
@@ -15,19 +18,60 @@ pub trait OrderItemRepo {
 
     async fn create(&self, req: CreateOrderItemRequest) -> Result<u64>;
 
+    /// insert all of the order items in one statement.
+    ///
+    /// return:
+    /// - the ids of the new order items, in the order of the requests.
+    async fn create_many(&self, reqs: Vec<CreateOrderItemRequest>) -> Result<Vec<u64>>;
+
+    async fn list_by_ids(&self, ids: Vec<u64>) -> Result<Vec<OrderItem>>;
+
     async fn list(&self, req: ListRequest) -> Result<Vec<OrderItem>>;
 
+    /// list the order items whose id is greater than `after_id`, ordered by id.
+    async fn list_after(
+        &self,
+        req: ListRequest,
+        after_id: i64,
+        limit: u64,
+    ) -> Result<Vec<OrderItem>>;
+
+    /// list the order items of the customer which aren't deleted, the newest one is the first.
+    async fn list_by_customer(&self, customer_id: u64) -> Result<Vec<OrderItem>>;
+
+    /// find the order items which match the query by the customer or the product,
+    /// the most similar order item is the first one.
+    async fn search(&self, query: String, limit: u64) -> Result<Vec<OrderItem>>;
+
     async fn update(&self, req: UpdateOrderItemRequest) -> Result<bool>;
 
     async fn update_items_status(&self, req: UpdateOrderItemsStatusRequest) -> Result<bool>;
+
+    /// list the order items in the status with their product supplier.
+    async fn list_shopping_list_entries(
+        &self,
+        status: u32,
+        supplier_id: Option<u64>,
+    ) -> Result<Vec<ShoppingListEntry>>;
 }
 
 #[async_trait]
 pub trait ProductRepo {
     async fn get(&self, id: u64) -> Result<Option<Product>>;
+
+    /// find which of the product ids exist.
+    async fn existing_ids(&self, ids: Vec<u64>) -> Result<HashSet<u64>>;
 }
 
 #[async_trait]
 pub trait CustomerRepo {
     async fn get(&self, id: u64) -> Result<Option<Customer>>;
+
+    /// find which of the customer ids exist.
+    async fn existing_ids(&self, ids: Vec<u64>) -> Result<HashSet<u64>>;
+    /// find the owners of the addresses which aren't deleted.
+    ///
+    /// return:
+    /// - the map from an address id to its customer id.
+    async fn address_owners(&self, address_ids: Vec<u64>) -> Result<HashMap<u64, u64>>;
 }
@@ -1,28 +1,38 @@
 use async_trait::async_trait;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
 use sqlx::pool::PoolConnection;
 use sqlx::{Pool, Postgres};
 use std::sync::Arc;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 
 use anyhow::Result;
 use common::order_item_pb::order_services_server::OrderServices;
 use common::order_item_pb::{
-    CreateOrderItemRequest, GetOrderItemResponse, ListOrderItemResponse, OrderItem,
-    UpdateOrderItemRequest, UpdateOrderItemsStatusRequest, UpdateOrderItemsStatusResponse,
+    BatchCreateOrderItemRequest, BatchCreateOrderItemResponse, CreateOrderItemRequest,
+    CustomerHistoryRequest, CustomerHistoryResponse, GetOrderItemResponse, ListOrderItemResponse,
+    OrderItem, ShoppingListRequest, ShoppingListResponse, UpdateOrderItemRequest,
+    UpdateOrderItemsStatusRequest, UpdateOrderItemsStatusResponse,
 };
-use common::types::{GetByIdRequest, ListRequest};
+use common::types::{
+    CreateNoteRequest, DeleteNoteRequest, DeleteResponse, GetByIdRequest, ImportReport,
+    ListNotesResponse, ListRequest, Note, SearchRequest,
+};
+use common::util::cache::Cache;
 use common::util::tools::grpc_error_handler;
 
+use crate::item_note::services::service::{NoteService, NoteServiceImpl};
 use crate::order::services::service::{OrderItemService, OrderItemServiceImpl};
 
 pub struct GrpcOrderServiceImpl {
     pool: Pool<Postgres>,
+    cache: Arc<dyn Cache>,
 }
 
 impl GrpcOrderServiceImpl {
-    pub(crate) fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub(crate) fn new(pool: Pool<Postgres>, cache: Arc<dyn Cache>) -> Self {
+        Self { pool, cache }
     }
 
     async fn get_session(&self) -> Result<Arc<Mutex<PoolConnection<Postgres>>>> {
@@ -34,6 +44,8 @@ impl GrpcOrderServiceImpl {
 
 #[async_trait]
 impl OrderServices for GrpcOrderServiceImpl {
+    type exportStream = BoxStream<'static, Result<OrderItem, Status>>;
+
     async fn get(
         &self,
         request: Request<GetByIdRequest>,
@@ -69,6 +81,25 @@ impl OrderServices for GrpcOrderServiceImpl {
             .map_err(grpc_error_handler)
     }
 
+    async fn search(
+        &self,
+        request: Request<SearchRequest>,
+    ) -> Result<Response<ListOrderItemResponse>, Status> {
+        let req = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        services
+            .search(req)
+            .await
+            .map(|e| {
+                let elements = e.into_iter().map(|o| o.into()).collect::<Vec<_>>();
+                ListOrderItemResponse { items: elements }
+            })
+            .map(Response::new)
+            .map_err(grpc_error_handler)
+    }
+
     async fn update(
         &self,
         request: Request<UpdateOrderItemRequest>,
@@ -90,7 +121,7 @@ impl OrderServices for GrpcOrderServiceImpl {
     ) -> Result<Response<OrderItem>, Status> {
         let session = self.get_session().await.unwrap();
 
-        let services = OrderItemServiceImpl::new(session);
+        let services = OrderItemServiceImpl::new(session).with_cache(self.cache.clone());
         services
             .create(request.into_inner())
             .await
@@ -98,6 +129,52 @@ impl OrderServices for GrpcOrderServiceImpl {
             .map_err(grpc_error_handler)
     }
 
+    async fn batch_create(
+        &self,
+        request: Request<BatchCreateOrderItemRequest>,
+    ) -> Result<Response<BatchCreateOrderItemResponse>, Status> {
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        services
+            .batch_create(request.into_inner().items)
+            .await
+            .map(|r| Response::new(r.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn import(
+        &self,
+        request: Request<Streaming<CreateOrderItemRequest>>,
+    ) -> Result<Response<ImportReport>, Status> {
+        let reqs = request.into_inner().try_collect::<Vec<_>>().await?;
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        services
+            .import(reqs)
+            .await
+            .map(|r| Response::new(r.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn export(
+        &self,
+        request: Request<ListRequest>,
+    ) -> Result<Response<Self::exportStream>, Status> {
+        let req = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        let items = services
+            .export(req)
+            .map_ok(OrderItem::from)
+            .map_err(grpc_error_handler)
+            .boxed();
+
+        Ok(Response::new(items))
+    }
+
     async fn update_order_items_status(
         &self,
         request: Request<UpdateOrderItemsStatusRequest>,
@@ -112,4 +189,82 @@ impl OrderServices for GrpcOrderServiceImpl {
             .map(Response::new)
             .map_err(grpc_error_handler)
     }
+
+    async fn shopping_list(
+        &self,
+        request: Request<ShoppingListRequest>,
+    ) -> Result<Response<ShoppingListResponse>, Status> {
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        services
+            .shopping_list(request.into_inner())
+            .await
+            .map(|e| {
+                let items = e.into_iter().map(|i| i.into()).collect::<Vec<_>>();
+                ShoppingListResponse { items }
+            })
+            .map(Response::new)
+            .map_err(grpc_error_handler)
+    }
+    async fn customer_history(
+        &self,
+        request: Request<CustomerHistoryRequest>,
+    ) -> Result<Response<CustomerHistoryResponse>, Status> {
+        let session = self.get_session().await.unwrap();
+
+        let services = OrderItemServiceImpl::new(session);
+        services
+            .customer_history(request.into_inner().customer_id)
+            .await
+            .map(|e| e.into())
+            .map(Response::new)
+            .map_err(grpc_error_handler)
+    }
+    async fn create_note(
+        &self,
+        request: Request<CreateNoteRequest>,
+    ) -> Result<Response<Note>, Status> {
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+        services
+            .create(request.into_inner())
+            .await
+            .map(|e| Response::new(e.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_note(
+        &self,
+        request: Request<DeleteNoteRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let req = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+        services
+            .delete(req.owner_id, req.id)
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_notes(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<ListNotesResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = NoteServiceImpl::new(session);
+        services
+            .list(id)
+            .await
+            .map(|n| {
+                let notes = n.into_iter().map(|e| e.into()).collect();
+                Response::new(ListNotesResponse { notes })
+            })
+            .map_err(grpc_error_handler)
+    }
 }
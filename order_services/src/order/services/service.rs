@@ -1,17 +1,28 @@
 use async_trait::async_trait;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
+use rust_decimal::Decimal;
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use common::json::order_item::OrderItem;
+use common::json::customer_history::{CurrencyTotal, CustomerHistory, StatusCount};
+use common::json::import::ImportReport;
+use common::json::order_item::{
+    BatchCreateOrderItemResult, BatchItemError, OrderItem, OrderItemStatus,
+};
+use common::json::shopping_list::{ShoppingListEntry, ShoppingListItem, WaitingCustomer};
 use common::order_item_pb::{
-    CreateOrderItemRequest, UpdateOrderItemRequest, UpdateOrderItemsStatusRequest,
+    CreateOrderItemRequest, ShoppingListRequest, UpdateOrderItemRequest,
+    UpdateOrderItemsStatusRequest,
 };
-use common::types::ListRequest;
+use common::types::{ListRequest, SearchRequest};
 use common::util::alias::AppResult;
+use common::util::cache::{customer_exists_key, product_exists_key, read_through, Cache, NoCache};
 use common::util::errors::AppError;
-use common::util::tools::database_error_handler;
+use common::util::search::search_limit;
+use common::util::tools::{batch_stream, database_error_handler, in_transaction, Transaction};
 
 use crate::order::repos::postgres_repo::{CustomerRepoImpl, OrderItemRepoImpl, ProductRepoImpl};
 use crate::order::repos::repo::{CustomerRepo, OrderItemRepo, ProductRepo};
@@ -22,50 +33,99 @@ pub trait OrderItemService {
 
     async fn create(self, req: CreateOrderItemRequest) -> AppResult<OrderItem>;
 
+    /// validate all of the order items, then create all of them or none of them.
+    async fn batch_create(
+        self,
+        reqs: Vec<CreateOrderItemRequest>,
+    ) -> AppResult<BatchCreateOrderItemResult>;
+
     async fn list(self, req: ListRequest) -> AppResult<Vec<OrderItem>>;
 
+    /// find the order items which match the query, the most similar order item is the first one.
+    async fn search(self, req: SearchRequest) -> AppResult<Vec<OrderItem>>;
+
     async fn update(self, req: UpdateOrderItemRequest) -> AppResult<OrderItem>;
 
     async fn update_items_status(self, req: UpdateOrderItemsStatusRequest) -> AppResult<bool>;
+
+    async fn shopping_list(self, req: ShoppingListRequest) -> AppResult<Vec<ShoppingListItem>>;
+
+    /// create the order items in a transaction, the invalid rows are reported as failed.
+    async fn import(self, reqs: Vec<CreateOrderItemRequest>) -> AppResult<ImportReport>;
+
+    /// stream all of the order items which match the query, ordered by id.
+    fn export(self, req: ListRequest) -> BoxStream<'static, AppResult<OrderItem>>;
+
+    /// list all of the order items of the customer with the aggregates of them.
+    async fn customer_history(self, customer_id: u64) -> AppResult<CustomerHistory>;
 }
 
 pub(crate) struct OrderItemServiceImpl {
+    transaction: Box<dyn Transaction>,
     order_repo: Box<dyn OrderItemRepo + Send + Sync>,
     product_repo: Box<dyn ProductRepo + Send + Sync>,
     customer_repo: Box<dyn CustomerRepo + Send + Sync>,
+    cache: Arc<dyn Cache>,
 }
 
 impl OrderItemServiceImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
         let order_repo = Box::new(OrderItemRepoImpl::new(session.clone()));
         let product_repo = Box::new(ProductRepoImpl::new(session.clone()));
-        let customer_repo = Box::new(CustomerRepoImpl::new(session));
+        let customer_repo = Box::new(CustomerRepoImpl::new(session.clone()));
 
         Self {
+            transaction: Box::new(session),
             order_repo,
             product_repo,
             customer_repo,
+            cache: Arc::new(NoCache),
         }
     }
-}
 
-#[async_trait]
-impl OrderItemService for OrderItemServiceImpl {
-    async fn get(&self, id: u64) -> AppResult<Option<OrderItem>> {
-        self.order_repo
-            .get(id)
-            .await
-            .map_err(database_error_handler)
+    /// read the existence of the products and the customers through the cache.
+    pub(crate) fn with_cache(self, cache: Arc<dyn Cache>) -> Self {
+        Self { cache, ..self }
     }
 
-    async fn create(self, req: CreateOrderItemRequest) -> AppResult<OrderItem> {
-        let product = self.product_repo.get(req.product_id).await;
+    async fn is_product_exist(&self, product_id: u64) -> AppResult<bool> {
+        let is_exist = read_through(
+            self.cache.as_ref(),
+            &product_exists_key(product_id),
+            || async {
+                self.product_repo
+                    .get(product_id)
+                    .await
+                    .map(|p| p.map(|_| true))
+                    .map_err(|e| AppError::DatabaseError(e.to_string()))
+            },
+        )
+        .await?;
+
+        Ok(is_exist.is_some())
+    }
 
-        if product.is_err() {
-            return Err(AppError::DatabaseError(product.err().unwrap().to_string()));
-        }
+    async fn is_customer_exist(&self, customer_id: u64) -> AppResult<bool> {
+        let is_exist = read_through(
+            self.cache.as_ref(),
+            &customer_exists_key(customer_id),
+            || async {
+                self.customer_repo
+                    .get(customer_id)
+                    .await
+                    .map(|c| c.map(|_| true))
+                    .map_err(|e| AppError::DatabaseError(e.to_string()))
+            },
+        )
+        .await?;
+
+        Ok(is_exist.is_some())
+    }
 
-        if product.unwrap().is_none() {
+    /// check the product and the customer of the new order item are exist,
+    /// and the shipping address belongs to the customer.
+    async fn validate_create_request(&self, req: &CreateOrderItemRequest) -> AppResult<()> {
+        if !self.is_product_exist(req.product_id).await? {
             let msg = format!(
                 "Product {} doesn't exist, when create a order.",
                 req.product_id
@@ -73,13 +133,7 @@ impl OrderItemService for OrderItemServiceImpl {
             return Err(AppError::BadRequest(msg));
         }
 
-        let customer = self.customer_repo.get(req.customer_id).await;
-
-        if customer.is_err() {
-            return Err(AppError::DatabaseError(customer.err().unwrap().to_string()));
-        }
-
-        if customer.unwrap().is_none() {
+        if !self.is_customer_exist(req.customer_id).await? {
             let msg = format!(
                 "Customer {} doesn't exist, when create a order",
                 req.customer_id
@@ -87,6 +141,73 @@ impl OrderItemService for OrderItemServiceImpl {
             return Err(AppError::BadRequest(msg));
         }
 
+        self.check_address_owner(req.customer_id, req.address_id)
+            .await
+    }
+
+    /// check the shipping address belongs to the customer of the order item.
+    async fn check_address_owner(
+        &self,
+        customer_id: u64,
+        address_id: Option<u64>,
+    ) -> AppResult<()> {
+        let address_id = match address_id {
+            Some(address_id) => address_id,
+            None => return Ok(()),
+        };
+
+        let owners = self
+            .customer_repo
+            .address_owners(vec![address_id])
+            .await
+            .map_err(database_error_handler)?;
+
+        if owners.get(&address_id) != Some(&customer_id) {
+            return Err(AppError::BadRequest(format!(
+                "Address {} doesn't belong to customer {}.",
+                address_id, customer_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn import_rows(&self, reqs: Vec<CreateOrderItemRequest>) -> anyhow::Result<ImportReport> {
+        let mut report = ImportReport::default();
+
+        for (i, req) in reqs.into_iter().enumerate() {
+            let row = i as u32 + 1;
+
+            match self.validate_create_request(&req).await {
+                Err(AppError::BadRequest(reason)) => {
+                    report.add_failed(row, reason);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+                Ok(_) => {}
+            }
+
+            let id = self.order_repo.create(req).await?;
+
+            report.add_created(row, id as i64);
+        }
+
+        Ok(report)
+    }
+}
+
+#[async_trait]
+impl OrderItemService for OrderItemServiceImpl {
+    async fn get(&self, id: u64) -> AppResult<Option<OrderItem>> {
+        self.order_repo
+            .get(id)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn create(self, req: CreateOrderItemRequest) -> AppResult<OrderItem> {
+        self.validate_create_request(&req).await?;
+
         let result = self
             .order_repo
             .create(req)
@@ -107,6 +228,64 @@ impl OrderItemService for OrderItemServiceImpl {
         Err(AppError::DatabaseError(result.err().unwrap().to_string()))
     }
 
+    async fn batch_create(
+        self,
+        reqs: Vec<CreateOrderItemRequest>,
+    ) -> AppResult<BatchCreateOrderItemResult> {
+        if reqs.is_empty() {
+            return Err(AppError::BadRequest(
+                "there is no order item to create.".to_string(),
+            ));
+        }
+
+        let product_ids = self
+            .product_repo
+            .existing_ids(reqs.iter().map(|e| e.product_id).collect())
+            .await
+            .map_err(database_error_handler)?;
+
+        let customer_ids = self
+            .customer_repo
+            .existing_ids(reqs.iter().map(|e| e.customer_id).collect())
+            .await
+            .map_err(database_error_handler)?;
+
+        let address_owners = self
+            .customer_repo
+            .address_owners(reqs.iter().filter_map(|e| e.address_id).collect())
+            .await
+            .map_err(database_error_handler)?;
+
+        let errors = validate_batch(&reqs, &product_ids, &customer_ids, &address_owners);
+
+        if !errors.is_empty() {
+            return Ok(BatchCreateOrderItemResult {
+                items: vec![],
+                errors,
+            });
+        }
+
+        // the order items are inserted by one statement, so they are created atomically.
+        let ids = self
+            .order_repo
+            .create_many(reqs)
+            .await
+            .map_err(database_error_handler)?;
+
+        let mut items = self
+            .order_repo
+            .list_by_ids(ids.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        items.sort_by_key(|e| ids.iter().position(|&id| id == e.id as u64));
+
+        Ok(BatchCreateOrderItemResult {
+            items,
+            errors: vec![],
+        })
+    }
+
     async fn list(self, req: ListRequest) -> AppResult<Vec<OrderItem>> {
         self.order_repo
             .list(req)
@@ -114,6 +293,17 @@ impl OrderItemService for OrderItemServiceImpl {
             .map_err(database_error_handler)
     }
 
+    async fn search(self, req: SearchRequest) -> AppResult<Vec<OrderItem>> {
+        if req.query.trim().is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.order_repo
+            .search(req.query, search_limit(req.limit))
+            .await
+            .map_err(database_error_handler)
+    }
+
     async fn update(self, req: UpdateOrderItemRequest) -> AppResult<OrderItem> {
         let old_order_item = self.order_repo.get(req.id).await.ok().flatten();
 
@@ -146,6 +336,17 @@ impl OrderItemService for OrderItemServiceImpl {
             }
         }
 
+        // the address is checked against the customer after the update.
+        if req.customer_id.is_some() || req.address_id.is_some() {
+            let old_order_item = old_order_item.as_ref().unwrap();
+            let customer_id = req.customer_id.unwrap_or(old_order_item.customer.id as u64);
+            let address_id = req
+                .address_id
+                .or_else(|| old_order_item.address_id.map(|e| e as u64));
+
+            self.check_address_owner(customer_id, address_id).await?;
+        }
+
         let id = req.id;
         let is_affected = self.order_repo.update(req).await;
 
@@ -171,4 +372,368 @@ impl OrderItemService for OrderItemServiceImpl {
             .await
             .map_err(database_error_handler)
     }
+
+    async fn shopping_list(self, req: ShoppingListRequest) -> AppResult<Vec<ShoppingListItem>> {
+        self.order_repo
+            .list_shopping_list_entries(OrderItemStatus::Ordering as u32, req.supplier_id)
+            .await
+            .map(build_shopping_list)
+            .map_err(database_error_handler)
+    }
+
+    async fn import(self, reqs: Vec<CreateOrderItemRequest>) -> AppResult<ImportReport> {
+        in_transaction(self.transaction.as_ref(), self.import_rows(reqs)).await
+    }
+
+    fn export(self, req: ListRequest) -> BoxStream<'static, AppResult<OrderItem>> {
+        let service = Arc::new(self);
+
+        batch_stream(
+            move |after_id, limit| {
+                let service = service.clone();
+                let req = req.clone();
+
+                async move {
+                    service
+                        .order_repo
+                        .list_after(req, after_id, limit)
+                        .await
+                        .map_err(database_error_handler)
+                }
+            },
+            |o| o.id,
+        )
+    }
+
+    async fn customer_history(self, customer_id: u64) -> AppResult<CustomerHistory> {
+        let customer = self
+            .customer_repo
+            .get(customer_id)
+            .await
+            .map_err(database_error_handler)?;
+
+        if customer.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the customer by id {}",
+                customer_id
+            )));
+        }
+
+        self.order_repo
+            .list_by_customer(customer_id)
+            .await
+            .map_err(database_error_handler)
+            .and_then(build_customer_history)
+    }
+}
+
+/// check the products and the customers of the order items are exist,
+/// and the shipping addresses belong to the customers.
+///
+/// params:
+/// - product_ids: the ids of the existing products.
+/// - customer_ids: the ids of the existing customers.
+/// - address_owners: the customer ids of the existing addresses.
+pub(crate) fn validate_batch(
+    reqs: &[CreateOrderItemRequest],
+    product_ids: &HashSet<u64>,
+    customer_ids: &HashSet<u64>,
+    address_owners: &HashMap<u64, u64>,
+) -> Vec<BatchItemError> {
+    let mut errors = vec![];
+
+    for (i, req) in reqs.iter().enumerate() {
+        let index = i as u32;
+
+        if !product_ids.contains(&req.product_id) {
+            errors.push(BatchItemError {
+                index,
+                reason: format!("Product {} doesn't exist.", req.product_id),
+            });
+        }
+
+        if !customer_ids.contains(&req.customer_id) {
+            errors.push(BatchItemError {
+                index,
+                reason: format!("Customer {} doesn't exist.", req.customer_id),
+            });
+        }
+
+        if let Some(address_id) = req.address_id {
+            if address_owners.get(&address_id) != Some(&req.customer_id) {
+                errors.push(BatchItemError {
+                    index,
+                    reason: format!(
+                        "Address {} doesn't belong to customer {}.",
+                        address_id, req.customer_id
+                    ),
+                });
+            }
+        }
+    }
+
+    errors
+}
+
+/// group the order items by product, the items are sorted by supplier and product name.
+/// the products without a supplier are listed at the end.
+pub(crate) fn build_shopping_list(entries: Vec<ShoppingListEntry>) -> Vec<ShoppingListItem> {
+    let mut items: Vec<ShoppingListItem> = vec![];
+
+    for e in entries {
+        let customer = WaitingCustomer {
+            order_item_id: e.order_item_id,
+            customer_id: e.customer_id,
+            customer_name: e.customer_name,
+            quantity: e.quantity as u32,
+        };
+
+        match items.iter_mut().find(|i| i.product_id == e.product_id) {
+            Some(item) => {
+                item.quantity += customer.quantity;
+                item.customers.push(customer);
+            }
+            None => items.push(ShoppingListItem {
+                product_id: e.product_id,
+                product_name: e.product_name,
+                supplier_id: e.supplier_id,
+                supplier_name: e.supplier_name,
+                source_url: e.source_url,
+                quantity: customer.quantity,
+                customers: vec![customer],
+            }),
+        }
+    }
+
+    items.sort_by(|a, b| {
+        let a_key = (a.supplier_name.is_none(), &a.supplier_name, &a.product_name);
+        let b_key = (b.supplier_name.is_none(), &b.supplier_name, &b.product_name);
+        a_key.cmp(&b_key)
+    });
+
+    items
+}
+
+/// count the order items by status, sum the price * quantity of the items which the customer
+/// pays for by currency, and find the time of the first and the last order.
+pub(crate) fn build_customer_history(items: Vec<OrderItem>) -> AppResult<CustomerHistory> {
+    let mut status_counts: Vec<StatusCount> = vec![];
+    let mut totals: Vec<CurrencyTotal> = vec![];
+
+    for item in &items {
+        match status_counts.iter_mut().find(|e| e.status == item.status) {
+            Some(e) => e.count += 1,
+            None => status_counts.push(StatusCount {
+                status: item.status,
+                count: 1,
+            }),
+        }
+
+        if item.status == OrderItemStatus::OutOfStock as u32 {
+            continue;
+        }
+
+        let currency = item.product.currency;
+        let overflow =
+            || AppError::BadRequest(format!("The total of currency {} overflows.", currency));
+
+        let amount = item
+            .product
+            .price
+            .checked_mul(Decimal::from(item.quantity))
+            .ok_or_else(overflow)?;
+
+        match totals.iter_mut().find(|e| e.currency == currency) {
+            Some(e) => e.total = e.total.checked_add(amount).ok_or_else(overflow)?,
+            None => totals.push(CurrencyTotal {
+                currency,
+                total: amount,
+            }),
+        }
+    }
+
+    status_counts.sort_by_key(|e| e.status);
+    totals.sort_by_key(|e| e.currency);
+
+    Ok(CustomerHistory {
+        first_ordered_at: items.iter().map(|e| e.created_at).min(),
+        last_ordered_at: items.iter().map(|e| e.created_at).max(),
+        items,
+        status_counts,
+        totals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::json::customer::Customer;
+    use common::json::product::Product;
+    use common::util::tools::timestamp2datetime;
+
+    use super::*;
+
+    fn entry(
+        order_item_id: i64,
+        customer_name: &str,
+        product_id: i64,
+        supplier_name: Option<&str>,
+        quantity: i16,
+    ) -> ShoppingListEntry {
+        ShoppingListEntry {
+            order_item_id,
+            quantity,
+            customer_id: order_item_id * 10,
+            customer_name: customer_name.to_string(),
+            product_id,
+            product_name: format!("product {}", product_id),
+            supplier_id: supplier_name.map(|_| 1),
+            supplier_name: supplier_name.map(|e| e.to_string()),
+            source_url: None,
+        }
+    }
+
+    #[test]
+    fn group_order_items_by_product() {
+        let items = build_shopping_list(vec![
+            entry(1, "alice", 1, None, 2),
+            entry(2, "bob", 2, Some("Don Quijote"), 1),
+            entry(3, "carol", 1, None, 3),
+        ]);
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].product_id, 2);
+        assert_eq!(items[1].product_id, 1);
+        assert_eq!(items[1].quantity, 5);
+        assert_eq!(
+            items[1]
+                .customers
+                .iter()
+                .map(|c| c.customer_name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["alice", "carol"]
+        );
+    }
+
+    #[test]
+    fn aggregate_order_items_of_customer() {
+        let item = |id: i64, status: u32, currency: i16, price: i64, quantity: u32| {
+            let created_at = timestamp2datetime(1_650_000_000 + id as u64 * 3600);
+
+            OrderItem {
+                id,
+                customer: Customer {
+                    id: 1,
+                    name: "alice".to_string(),
+                    email: None,
+                    phone: None,
+                    created_at,
+                    updated_at: None,
+                    contacts: vec![],
+                    notes: vec![],
+                },
+                product: Product {
+                    id,
+                    name: format!("product {}", id),
+                    currency,
+                    price: Decimal::from(price),
+                    created_at,
+                    updated_at: None,
+                    deleted_at: None,
+                    category_id: None,
+                    tags: vec![],
+                    images: vec![],
+                    supplier: None,
+                },
+                quantity,
+                status,
+                created_at,
+                updated_at: None,
+                deleted_at: None,
+                address_id: None,
+            }
+        };
+
+        let history = build_customer_history(vec![
+            item(4, 3, 0, 1000, 1),
+            item(3, 1, 0, 100, 2),
+            item(2, 2, 1, 30, 1),
+            item(1, 1, 0, 50, 1),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            history.status_counts,
+            vec![
+                StatusCount {
+                    status: 1,
+                    count: 2
+                },
+                StatusCount {
+                    status: 2,
+                    count: 1
+                },
+                StatusCount {
+                    status: 3,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            history.totals,
+            vec![
+                CurrencyTotal {
+                    currency: 0,
+                    total: Decimal::from(250)
+                },
+                CurrencyTotal {
+                    currency: 1,
+                    total: Decimal::from(30)
+                },
+            ]
+        );
+        assert_eq!(history.first_ordered_at, Some(history.items[3].created_at));
+        assert_eq!(history.last_ordered_at, Some(history.items[0].created_at));
+
+        let history = build_customer_history(vec![]).unwrap();
+
+        assert!(history.totals.is_empty());
+        assert_eq!(history.first_ordered_at, None);
+    }
+
+    #[test]
+    fn report_invalid_items_of_batch() {
+        let item = |customer_id: u64, product_id: u64| CreateOrderItemRequest {
+            customer_id,
+            product_id,
+            quantity: 1,
+            status: 1,
+            address_id: None,
+        };
+
+        let reqs = vec![
+            item(1, 10),
+            item(2, 10),
+            item(1, 20),
+            CreateOrderItemRequest {
+                address_id: Some(100),
+                ..item(1, 10)
+            },
+        ];
+        let product_ids = HashSet::from([10]);
+        let customer_ids = HashSet::from([1]);
+        let address_owners = HashMap::from([(100, 2)]);
+
+        let errors = validate_batch(&reqs, &product_ids, &customer_ids, &address_owners);
+
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].index, 1);
+        assert_eq!(errors[0].reason, "Customer 2 doesn't exist.");
+        assert_eq!(errors[1].index, 2);
+        assert_eq!(errors[1].reason, "Product 20 doesn't exist.");
+        assert_eq!(errors[2].index, 3);
+        assert_eq!(
+            errors[2].reason,
+            "Address 100 doesn't belong to customer 1."
+        );
+    }
 }
@@ -0,0 +1,209 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::{Postgres, Row};
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use common::json::category::{Categories, Category};
+use common::json::product::Products;
+use common::product_pb::{CreateCategoryRequest, UpdateCategoryRequest};
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::category::repos::repo::CategoryRepo;
+use crate::ID_GENERATOR;
+
+pub struct CategoryRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl CategoryRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl CategoryRepo for CategoryRepoImpl {
+    async fn get(&self, id: i64) -> Result<Option<Category>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Categories::Id,
+                Categories::ParentId,
+                Categories::Name,
+                Categories::CreatedAt,
+                Categories::UpdatedAt,
+            ])
+            .from(Categories::Table)
+            .and_where(Expr::col(Categories::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Category>(&sql), &values)
+            .fetch_optional(conn.deref_mut())
+            .await?)
+    }
+
+    async fn create(&self, request: CreateCategoryRequest) -> Result<Category> {
+        let id = async move { ID_GENERATOR.lock().unwrap().next_id() as u64 }.await;
+
+        let mut conn = self.session.lock().await;
+
+        let parent_id = request.parent_id.map(|e| e as i64).into();
+        let name = request.name.into();
+        let now = chrono::Utc::now().into();
+
+        let cols: Vec<Categories> = vec![
+            Categories::Id,
+            Categories::ParentId,
+            Categories::Name,
+            Categories::CreatedAt,
+            Categories::UpdatedAt,
+        ];
+
+        let (sql, values) = Query::insert()
+            .into_table(Categories::Table)
+            .columns(cols.clone().into_iter().take(4).collect::<Vec<_>>())
+            .values_panic(vec![id.into(), parent_id, name, now])
+            .returning(Query::select().columns(cols).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Category>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn update(&self, request: UpdateCategoryRequest) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let mut update_values = vec![];
+        if let Some(name) = request.name {
+            update_values.push((Categories::Name, name.into()));
+        }
+
+        // parent id 0 moves the category to the root.
+        if let Some(parent_id) = request.parent_id {
+            let parent_id = Some(parent_id as i64).filter(|&e| e != 0);
+            update_values.push((Categories::ParentId, parent_id.into()));
+        }
+
+        if update_values.is_empty() {
+            return Ok(false);
+        }
+
+        update_values.push((Categories::UpdatedAt, chrono::Utc::now().into()));
+
+        let (sql, values) = Query::update()
+            .table(Categories::Table)
+            .values(update_values)
+            .and_where(Expr::col(Categories::Id).eq(request.id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn delete(&self, id: i64, parent_id: Option<i64>) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (move_children_sql, move_children_values) = Query::update()
+            .table(Categories::Table)
+            .values(vec![(Categories::ParentId, parent_id.into())])
+            .and_where(Expr::col(Categories::ParentId).eq(id))
+            .build(PostgresQueryBuilder);
+
+        let (move_products_sql, move_products_values) = Query::update()
+            .table(Products::Table)
+            .values(vec![(Products::CategoryId, parent_id.into())])
+            .and_where(Expr::col(Products::CategoryId).eq(id))
+            .build(PostgresQueryBuilder);
+
+        let (delete_sql, delete_values) = Query::delete()
+            .from_table(Categories::Table)
+            .and_where(Expr::col(Categories::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&move_children_sql), &move_children_values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        bind_query(sqlx::query(&move_products_sql), &move_products_values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(bind_query(sqlx::query(&delete_sql), &delete_values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, parent_id: Option<i64>) -> Result<Vec<Category>> {
+        let mut conn = self.session.lock().await;
+
+        let parent_cond = match parent_id {
+            Some(id) => Expr::col(Categories::ParentId).eq(id),
+            None => Expr::col(Categories::ParentId).is_null(),
+        };
+
+        let (sql, values) = Query::select()
+            .columns([
+                Categories::Id,
+                Categories::ParentId,
+                Categories::Name,
+                Categories::CreatedAt,
+                Categories::UpdatedAt,
+            ])
+            .from(Categories::Table)
+            .and_where(parent_cond)
+            .order_by(Categories::Name, Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Category>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_all(&self) -> Result<Vec<Category>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Categories::Id,
+                Categories::ParentId,
+                Categories::Name,
+                Categories::CreatedAt,
+                Categories::UpdatedAt,
+            ])
+            .from(Categories::Table)
+            .order_by(Categories::Name, Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Category>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_product_ids(&self, id: i64) -> Result<Vec<i64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column(Products::Id)
+            .from(Products::Table)
+            .and_where(Expr::col(Products::CategoryId).eq(id))
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect::<Result<Vec<i64>, _>>()?)
+    }
+}
@@ -0,0 +1,24 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::category::Category;
+use common::product_pb::{CreateCategoryRequest, UpdateCategoryRequest};
+
+#[async_trait]
+pub trait CategoryRepo {
+    async fn get(&self, id: i64) -> Result<Option<Category>>;
+
+    async fn create(&self, request: CreateCategoryRequest) -> Result<Category>;
+
+    async fn update(&self, request: UpdateCategoryRequest) -> Result<bool>;
+
+    /// delete the category. its children and products are moved to `parent_id`.
+    async fn delete(&self, id: i64, parent_id: Option<i64>) -> Result<bool>;
+
+    async fn list(&self, parent_id: Option<i64>) -> Result<Vec<Category>>;
+
+    async fn list_all(&self) -> Result<Vec<Category>>;
+
+    /// the ids of the products in the category.
+    async fn list_product_ids(&self, id: i64) -> Result<Vec<i64>>;
+}
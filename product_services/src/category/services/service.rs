@@ -0,0 +1,217 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::category::Category;
+use common::product_pb::{CreateCategoryRequest, ListCategoryRequest, UpdateCategoryRequest};
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::{database_error_handler, in_transaction, Transaction};
+
+use crate::category::repos::postgres_repo::CategoryRepoImpl;
+use crate::category::repos::repo::CategoryRepo;
+
+#[async_trait]
+pub trait CategoryService {
+    async fn get(&self, id: i64) -> AppResult<Option<Category>>;
+
+    async fn create(&self, request: CreateCategoryRequest) -> AppResult<Category>;
+
+    async fn update(&self, request: UpdateCategoryRequest) -> AppResult<Category>;
+
+    async fn delete(&self, id: i64) -> AppResult<bool>;
+
+    async fn list(&self, request: ListCategoryRequest) -> AppResult<Vec<Category>>;
+
+    /// the ids of the products in the category, their cached details keep the category.
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>>;
+}
+
+pub(crate) struct CategoryServiceImpl {
+    transaction: Box<dyn Transaction>,
+    repo: Box<dyn CategoryRepo + Send + Sync>,
+}
+
+impl CategoryServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(CategoryRepoImpl::new(session.clone()));
+        Self {
+            transaction: Box::new(session),
+            repo,
+        }
+    }
+}
+
+#[async_trait]
+impl CategoryService for CategoryServiceImpl {
+    async fn get(&self, id: i64) -> AppResult<Option<Category>> {
+        self.repo.get(id).await.map_err(database_error_handler)
+    }
+
+    async fn create(&self, request: CreateCategoryRequest) -> AppResult<Category> {
+        if request.name.trim().is_empty() {
+            return Err(AppError::BadRequest(
+                "category name can't be empty.".to_string(),
+            ));
+        }
+
+        if let Some(parent_id) = request.parent_id {
+            let parent = self
+                .repo
+                .get(parent_id as i64)
+                .await
+                .map_err(database_error_handler)?;
+
+            if parent.is_none() {
+                return Err(AppError::BadRequest(format!(
+                    "Can't find the parent category by id {}",
+                    parent_id
+                )));
+            }
+        }
+
+        self.repo
+            .create(request)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn update(&self, request: UpdateCategoryRequest) -> AppResult<Category> {
+        let old_category = self
+            .repo
+            .get(request.id as i64)
+            .await
+            .map_err(database_error_handler)?
+            .ok_or_else(|| {
+                AppError::BadRequest(format!("Can't find the category by id {}", request.id))
+            })?;
+
+        if let Some(parent_id) = request.parent_id.filter(|&e| e != 0) {
+            let categories = self.repo.list_all().await.map_err(database_error_handler)?;
+
+            if !categories.iter().any(|c| c.id == parent_id as i64) {
+                return Err(AppError::BadRequest(format!(
+                    "Can't find the parent category by id {}",
+                    parent_id
+                )));
+            }
+
+            let descendants = collect_descendant_ids(&categories, old_category.id);
+            if descendants.contains(&(parent_id as i64)) {
+                return Err(AppError::BadRequest(format!(
+                    "Can't move the category {} under its own subtree.",
+                    request.id
+                )));
+            }
+        }
+
+        self.repo
+            .update(request.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        let parent_id = match request.parent_id {
+            Some(0) => None,
+            Some(id) => Some(id as i64),
+            None => old_category.parent_id,
+        };
+
+        Ok(Category {
+            name: request.name.unwrap_or(old_category.name),
+            parent_id,
+            ..old_category
+        })
+    }
+
+    async fn delete(&self, id: i64) -> AppResult<bool> {
+        let category = self.repo.get(id).await.map_err(database_error_handler)?;
+
+        if category.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the category by id {}",
+                id
+            )));
+        }
+
+        let parent_id = category.unwrap().parent_id;
+
+        in_transaction(self.transaction.as_ref(), self.repo.delete(id, parent_id)).await
+    }
+
+    async fn list(&self, request: ListCategoryRequest) -> AppResult<Vec<Category>> {
+        let result = match request.parent_id {
+            None => self.repo.list_all().await,
+            Some(0) => self.repo.list(None).await,
+            Some(id) => self.repo.list(Some(id as i64)).await,
+        };
+
+        result.map_err(database_error_handler)
+    }
+
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>> {
+        self.repo
+            .list_product_ids(id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
+
+/// collect the id of the category and all of its descendants.
+///
+/// params:
+/// - categories: all categories.
+/// - root: the id of the category.
+///
+/// return:
+/// - ids
+pub(crate) fn collect_descendant_ids(categories: &[Category], root: i64) -> Vec<i64> {
+    let mut ids = vec![root];
+    let mut index = 0;
+
+    while index < ids.len() {
+        let current = ids[index];
+        for c in categories.iter().filter(|c| c.parent_id == Some(current)) {
+            if !ids.contains(&c.id) {
+                ids.push(c.id);
+            }
+        }
+        index += 1;
+    }
+
+    ids
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn category(id: i64, parent_id: Option<i64>) -> Category {
+        Category {
+            id,
+            parent_id,
+            name: format!("category:{}", id),
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn can_collect_descendant_ids() {
+        let categories = vec![
+            category(1, None),
+            category(2, Some(1)),
+            category(3, Some(2)),
+            category(4, None),
+            category(5, Some(1)),
+        ];
+
+        let mut ids = collect_descendant_ids(&categories, 1);
+        ids.sort();
+
+        assert_eq!(ids, vec![1, 2, 3, 5]);
+        assert_eq!(collect_descendant_ids(&categories, 4), vec![4]);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod repos;
+pub mod services;
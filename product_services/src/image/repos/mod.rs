@@ -0,0 +1,2 @@
+pub mod postgres_repo;
+pub mod repo;
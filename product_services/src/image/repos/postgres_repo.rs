@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::product_image::{ProductImage, ProductImages};
+use common::product_pb::AddProductImageRequest;
+use common::util::sql::bind_query_as;
+
+use crate::image::repos::repo::ImageRepo;
+use crate::ID_GENERATOR;
+
+pub struct ImageRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl ImageRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl ImageRepo for ImageRepoImpl {
+    async fn create(&self, request: AddProductImageRequest) -> Result<ProductImage> {
+        let id = async move { ID_GENERATOR.lock().unwrap().next_id() as u64 }.await;
+
+        let mut conn = self.session.lock().await;
+
+        let cols = vec![
+            ProductImages::Id,
+            ProductImages::ProductId,
+            ProductImages::Key,
+            ProductImages::Url,
+            ProductImages::ThumbnailKey,
+            ProductImages::ThumbnailUrl,
+            ProductImages::ContentType,
+            ProductImages::CreatedAt,
+        ];
+
+        let (sql, values) = Query::insert()
+            .into_table(ProductImages::Table)
+            .columns(cols.clone())
+            .values_panic(vec![
+                id.into(),
+                request.product_id.into(),
+                request.key.into(),
+                request.url.into(),
+                request.thumbnail_key.into(),
+                request.thumbnail_url.into(),
+                request.content_type.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(cols).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(
+            bind_query_as(sqlx::query_as::<_, ProductImage>(&sql), &values)
+                .fetch_one(conn.deref_mut())
+                .await?,
+        )
+    }
+
+    async fn delete(&self, id: i64) -> Result<Option<ProductImage>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(ProductImages::Table)
+            .and_where(Expr::col(ProductImages::Id).eq(id))
+            .returning(
+                Query::select()
+                    .columns([
+                        ProductImages::Id,
+                        ProductImages::ProductId,
+                        ProductImages::Key,
+                        ProductImages::Url,
+                        ProductImages::ThumbnailKey,
+                        ProductImages::ThumbnailUrl,
+                        ProductImages::ContentType,
+                        ProductImages::CreatedAt,
+                    ])
+                    .take(),
+            )
+            .build(PostgresQueryBuilder);
+
+        Ok(
+            bind_query_as(sqlx::query_as::<_, ProductImage>(&sql), &values)
+                .fetch_optional(conn.deref_mut())
+                .await?,
+        )
+    }
+
+    async fn get_product_images(
+        &self,
+        product_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, Vec<ProductImage>>> {
+        if product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([
+                ProductImages::Id,
+                ProductImages::ProductId,
+                ProductImages::Key,
+                ProductImages::Url,
+                ProductImages::ThumbnailKey,
+                ProductImages::ThumbnailUrl,
+                ProductImages::ContentType,
+                ProductImages::CreatedAt,
+            ])
+            .from(ProductImages::Table)
+            .and_where(Expr::col(ProductImages::ProductId).is_in(product_ids))
+            .order_by(ProductImages::CreatedAt, Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        let images = bind_query_as(sqlx::query_as::<_, ProductImage>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        let mut result: HashMap<i64, Vec<ProductImage>> = HashMap::new();
+        for image in images {
+            result.entry(image.product_id).or_default().push(image);
+        }
+
+        Ok(result)
+    }
+}
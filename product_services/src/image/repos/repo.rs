@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::product_image::ProductImage;
+use common::product_pb::AddProductImageRequest;
+
+#[async_trait]
+pub trait ImageRepo {
+    async fn create(&self, request: AddProductImageRequest) -> Result<ProductImage>;
+
+    /// delete the image and return it, so the caller can remove the stored files.
+    async fn delete(&self, id: i64) -> Result<Option<ProductImage>>;
+
+    /// get the images of the products, grouped by product id.
+    async fn get_product_images(
+        &self,
+        product_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, Vec<ProductImage>>>;
+}
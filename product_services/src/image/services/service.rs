@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::product_image::ProductImage;
+use common::product_pb::AddProductImageRequest;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::database_error_handler;
+
+use crate::image::repos::postgres_repo::ImageRepoImpl;
+use crate::image::repos::repo::ImageRepo;
+use crate::product::repos::postgres_repo::ProductRepoImpl;
+use crate::product::repos::repo::ProductRepo;
+
+#[async_trait]
+pub trait ImageService {
+    async fn add(&self, request: AddProductImageRequest) -> AppResult<ProductImage>;
+
+    async fn delete(&self, id: i64) -> AppResult<ProductImage>;
+}
+
+pub(crate) struct ImageServiceImpl {
+    repo: Box<dyn ImageRepo + Send + Sync>,
+    product_repo: Box<dyn ProductRepo + Send + Sync>,
+}
+
+impl ImageServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(ImageRepoImpl::new(session.clone()));
+        let product_repo = Box::new(ProductRepoImpl::new(session));
+        Self { repo, product_repo }
+    }
+}
+
+#[async_trait]
+impl ImageService for ImageServiceImpl {
+    async fn add(&self, request: AddProductImageRequest) -> AppResult<ProductImage> {
+        let product = self
+            .product_repo
+            .get(request.product_id as i64)
+            .await
+            .map_err(database_error_handler)?;
+
+        if product.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the product by id {}",
+                request.product_id
+            )));
+        }
+
+        self.repo
+            .create(request)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn delete(&self, id: i64) -> AppResult<ProductImage> {
+        self.repo
+            .delete(id)
+            .await
+            .map_err(database_error_handler)?
+            .ok_or_else(|| AppError::BadRequest(format!("Can't find the image by id {}", id)))
+    }
+}
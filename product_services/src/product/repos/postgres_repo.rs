@@ -1,15 +1,17 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use futures::lock::Mutex;
-use sea_query::{Expr, PostgresQueryBuilder, Query};
+use sea_query::{Expr, JoinType, Order, PostgresQueryBuilder, Query, SelectStatement, SimpleExpr};
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
 use std::ops::DerefMut;
 use std::sync::Arc;
 
 use common::json::product::{Product, Products};
-use common::product_pb::{CreateProductRequest, UpdateProductRequest};
-use common::types::ListRequest;
+use common::json::tag::{ProductTags, Tags};
+use common::product_pb::{CreateProductRequest, ListProductRequest, UpdateProductRequest};
+use common::util::search::{column_sql, match_expr, rank_expr, search_terms};
+use common::util::sql::{bind_query, bind_query_as};
 
 use crate::product::repos::repo::ProductRepo;
 use crate::ID_GENERATOR;
@@ -22,6 +24,73 @@ impl ProductRepoImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
         Self { session }
     }
+
+    /// the products whose name or the name of a tag matches the query.
+    fn query_expr(query: &str) -> Option<SimpleExpr> {
+        let terms = search_terms(query);
+
+        if terms.is_empty() {
+            return None;
+        }
+
+        let tagged_products = Query::select()
+            .column((ProductTags::Table, ProductTags::ProductId))
+            .from(ProductTags::Table)
+            .join(
+                JoinType::InnerJoin,
+                Tags::Table,
+                Expr::tbl(ProductTags::Table, ProductTags::TagId).equals(Tags::Table, Tags::Id),
+            )
+            .and_where(match_expr(&column_sql(Tags::Table, Tags::Name), &terms))
+            .take();
+
+        let expr = match_expr(&column_sql(Products::Table, Products::Name), &terms)
+            .or(Expr::col(Products::Id).in_subquery(tagged_products));
+
+        Some(expr)
+    }
+
+    /// select the products which match the filters of the request.
+    fn filtered_select(
+        request: ListProductRequest,
+        category_ids: Option<Vec<i64>>,
+    ) -> SelectStatement {
+        let query = request.query.and_then(|q| Self::query_expr(&q));
+
+        let mut select = Query::select();
+        select
+            .columns(vec![
+                Products::Id,
+                Products::Name,
+                Products::Currency,
+                Products::Price,
+                Products::CreatedAt,
+                Products::UpdatedAt,
+                Products::DeletedAt,
+                Products::CategoryId,
+            ])
+            .and_where_option(query)
+            .and_where_option(category_ids.map(|ids| Expr::col(Products::CategoryId).is_in(ids)))
+            .from(Products::Table);
+
+        // a product must have all of the requested tags.
+        for tag in request.tags {
+            let tagged_products = Query::select()
+                .column((ProductTags::Table, ProductTags::ProductId))
+                .from(ProductTags::Table)
+                .join(
+                    JoinType::InnerJoin,
+                    Tags::Table,
+                    Expr::tbl(ProductTags::Table, ProductTags::TagId).equals(Tags::Table, Tags::Id),
+                )
+                .and_where(Expr::tbl(Tags::Table, Tags::Name).eq(tag))
+                .take();
+
+            select.and_where(Expr::col(Products::Id).in_subquery(tagged_products));
+        }
+
+        select
+    }
 }
 
 #[async_trait]
@@ -29,7 +98,7 @@ impl ProductRepo for ProductRepoImpl {
     async fn get(&self, id: i64) -> Result<Option<Product>> {
         let mut conn = self.session.lock().await;
 
-        let sql = Query::select()
+        let (sql, values) = Query::select()
             .columns([
                 Products::Id,
                 Products::Name,
@@ -38,12 +107,13 @@ impl ProductRepo for ProductRepoImpl {
                 Products::CreatedAt,
                 Products::UpdatedAt,
                 Products::DeletedAt,
+                Products::CategoryId,
             ])
             .from(Products::Table)
             .and_where(Expr::col(Products::Id).eq(id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, Product>(sql.as_str())
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
             .fetch_optional(conn.deref_mut())
             .await?)
     }
@@ -57,6 +127,11 @@ impl ProductRepo for ProductRepoImpl {
         let currency = request.currency.into();
         let price = request.price.into();
         let now = chrono::Utc::now().into();
+        let category_id = request
+            .category_id
+            .filter(|&e| e != 0)
+            .map(|e| e as i64)
+            .into();
 
         let cols: Vec<Products> = vec![
             Products::Id,
@@ -64,18 +139,19 @@ impl ProductRepo for ProductRepoImpl {
             Products::Currency,
             Products::Price,
             Products::CreatedAt,
+            Products::CategoryId,
             Products::UpdatedAt,
             Products::DeletedAt,
         ];
 
-        let sql = Query::insert()
+        let (sql, values) = Query::insert()
             .into_table(Products::Table)
-            .columns(cols.clone().into_iter().take(5).collect::<Vec<_>>())
-            .values_panic(vec![id.into(), name, currency, price, now])
+            .columns(cols.clone().into_iter().take(6).collect::<Vec<_>>())
+            .values_panic(vec![id.into(), name, currency, price, now, category_id])
             .returning(Query::select().columns(cols).take())
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, Product>(&sql)
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
             .fetch_one(conn.deref_mut())
             .await?)
     }
@@ -96,30 +172,55 @@ impl ProductRepo for ProductRepoImpl {
             update_values.push((Products::Price, price.into()));
         }
 
+        // category id 0 removes the product from its category.
+        if let Some(category_id) = request.category_id {
+            let category_id = Some(category_id as i64).filter(|&e| e != 0);
+            update_values.push((Products::CategoryId, category_id.into()));
+        }
+
         if update_values.is_empty() {
             return Ok(false);
         }
 
-        let sql = Query::update()
+        let (sql, values) = Query::update()
             .table(Products::Table)
             .values(update_values)
             .and_where(Expr::col(Products::Id).eq(request.id))
-            .to_string(PostgresQueryBuilder);
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query(&sql)
+        Ok(bind_query(sqlx::query(&sql), &values)
             .execute(conn.deref_mut())
             .await
             .map(|e| e.rows_affected() > 0)?)
     }
 
-    async fn list(&self, request: ListRequest) -> Result<Vec<Product>> {
+    async fn list(
+        &self,
+        request: ListProductRequest,
+        category_ids: Option<Vec<i64>>,
+    ) -> Result<Vec<Product>> {
         let mut conn = self.session.lock().await;
 
-        let query = request.query.map(|q| format!("%{}%", q));
         let page_size = request.page_size;
         let offset = request.page as u64 * page_size;
 
-        let sql = Query::select()
+        let (sql, values) = Self::filtered_select(request, category_ids)
+            .offset(offset)
+            .limit(page_size)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn search(&self, query: String, limit: u64) -> Result<Vec<Product>> {
+        let mut conn = self.session.lock().await;
+
+        let terms = search_terms(&query);
+        let columns = vec![column_sql(Products::Table, Products::Name)];
+
+        let (sql, values) = Query::select()
             .columns(vec![
                 Products::Id,
                 Products::Name,
@@ -127,14 +228,38 @@ impl ProductRepo for ProductRepoImpl {
                 Products::Price,
                 Products::CreatedAt,
                 Products::UpdatedAt,
+                Products::DeletedAt,
+                Products::CategoryId,
             ])
-            .and_where_option(query.map(|e| Expr::col(Products::Name).like(&e)))
             .from(Products::Table)
-            .offset(offset)
-            .limit(page_size)
-            .to_string(PostgresQueryBuilder);
+            .and_where_option(Self::query_expr(&query))
+            .and_where(Expr::col(Products::DeletedAt).is_null())
+            .order_by_expr(rank_expr(&columns, &terms), Order::Desc)
+            .order_by(Products::Id, Order::Desc)
+            .limit(limit)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn list_after(
+        &self,
+        request: ListProductRequest,
+        category_ids: Option<Vec<i64>>,
+        after_id: i64,
+        limit: u64,
+    ) -> Result<Vec<Product>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Self::filtered_select(request, category_ids)
+            .and_where(Expr::col(Products::Id).gt(after_id))
+            .order_by(Products::Id, Order::Asc)
+            .limit(limit)
+            .build(PostgresQueryBuilder);
 
-        Ok(sqlx::query_as::<_, Product>(&sql)
+        Ok(bind_query_as(sqlx::query_as::<_, Product>(&sql), &values)
             .fetch_all(conn.deref_mut())
             .await?)
     }
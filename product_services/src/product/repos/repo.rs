@@ -2,8 +2,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 
 use common::json::product::Product;
-use common::product_pb::{CreateProductRequest, UpdateProductRequest};
-use common::types::ListRequest;
+use common::product_pb::{CreateProductRequest, ListProductRequest, UpdateProductRequest};
 
 #[async_trait]
 pub trait ProductRepo {
@@ -13,5 +12,23 @@ pub trait ProductRepo {
 
     async fn update(&self, request: UpdateProductRequest) -> Result<bool>;
 
-    async fn list(&self, request: ListRequest) -> Result<Vec<Product>>;
+    /// list products. if `category_ids` is set, only products in these categories are returned.
+    async fn list(
+        &self,
+        request: ListProductRequest,
+        category_ids: Option<Vec<i64>>,
+    ) -> Result<Vec<Product>>;
+
+    /// find the products which match the query, the most similar product is the first one.
+    async fn search(&self, query: String, limit: u64) -> Result<Vec<Product>>;
+
+    /// list the products whose id is greater than `after_id`, ordered by id.
+    /// the filters are the same as `list`, the paging fields are ignored.
+    async fn list_after(
+        &self,
+        request: ListProductRequest,
+        category_ids: Option<Vec<i64>>,
+        after_id: i64,
+        limit: u64,
+    ) -> Result<Vec<Product>>;
 }
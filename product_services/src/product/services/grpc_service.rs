@@ -3,28 +3,42 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
 use sqlx::{Pool, Postgres};
 use sqlx::pool::PoolConnection;
-use tonic::{Request, Response, Status};
+use tonic::{Request, Response, Status, Streaming};
 use tracing::instrument;
 
 use common::product_pb::{
-    CreateProductRequest, GetProductResponse, ListProductResponse, Product, UpdateProductRequest,
+    AddProductImageRequest, Category, CreateCategoryRequest, CreateProductRequest,
+    CreateSupplierRequest, CreateTagRequest, GetProductResponse, ListCategoryRequest,
+    ListCategoryResponse, ListProductRequest, ListProductResponse, ListSupplierResponse,
+    ListTagResponse, Product, ProductImage, SetProductSupplierRequest, Supplier, Tag,
+    UpdateCategoryRequest, UpdateProductRequest, UpdateSupplierRequest, UpdateTagRequest,
 };
 use common::product_pb::product_services_server::ProductServices;
-use common::types::{GetByIdRequest, ListRequest};
+use common::types::{DeleteResponse, GetByIdRequest, ImportReport, ListRequest, SearchRequest};
+use common::util::cache::{
+    change_products, invalidate, product_key, product_keys, read_through, Cache,
+};
 use common::util::tools::grpc_error_handler;
 
+use crate::category::services::service::{CategoryService, CategoryServiceImpl};
+use crate::image::services::service::{ImageService, ImageServiceImpl};
 use crate::product::services::service::{ProductService, ProductServiceImpl};
+use crate::supplier::services::service::{SupplierService, SupplierServiceImpl};
+use crate::tag::services::service::{TagService, TagServiceImpl};
 
 #[derive(Debug)]
 pub struct ProductServicesImpl {
     pool: Pool<Postgres>,
+    cache: Arc<dyn Cache>,
 }
 
 impl ProductServicesImpl {
-    pub fn new(pool: Pool<Postgres>) -> Self {
-        Self { pool }
+    pub fn new(pool: Pool<Postgres>, cache: Arc<dyn Cache>) -> Self {
+        Self { pool, cache }
     }
 
     async fn get_session(&self) -> Result<Arc<Mutex<PoolConnection<Postgres>>>> {
@@ -36,6 +50,8 @@ impl ProductServicesImpl {
 
 #[async_trait]
 impl ProductServices for ProductServicesImpl {
+    type exportStream = BoxStream<'static, Result<Product, Status>>;
+
     #[instrument]
     async fn create(
         &self,
@@ -65,14 +81,13 @@ impl ProductServices for ProductServicesImpl {
 
         let services = ProductServiceImpl::new(session);
 
-        services
-            .update(request)
-            .await
-            .map(|p| {
-                let p: Product = p.into();
-                Response::new(p)
-            })
-            .map_err(grpc_error_handler)
+        let id = request.id;
+        let product = services.update(request).await.map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), product_keys(id)).await;
+
+        let p: Product = product.into();
+        Ok(Response::new(p))
     }
 
     async fn get(
@@ -84,19 +99,39 @@ impl ProductServices for ProductServicesImpl {
 
         let services = ProductServiceImpl::new(session);
 
+        read_through(self.cache.as_ref(), &product_key(request.id), || {
+            services.get(request.id as i64)
+        })
+        .await
+        .map(|p| {
+            let p: Option<Product> = p.map(|e| e.into());
+            Response::new(GetProductResponse { product: p })
+        })
+        .map_err(grpc_error_handler)
+    }
+
+    async fn list(
+        &self,
+        request: Request<ListProductRequest>,
+    ) -> Result<Response<ListProductResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = ProductServiceImpl::new(session);
+
         services
-            .get(request.id as i64)
+            .list(request)
             .await
             .map(|p| {
-                let p: Option<Product> = p.map(|e| e.into());
-                Response::new(GetProductResponse { product: p })
+                let p: Vec<Product> = p.into_iter().map(|e| e.into()).collect();
+                Response::new(ListProductResponse { products: p })
             })
             .map_err(grpc_error_handler)
     }
 
-    async fn list(
+    async fn search(
         &self,
-        request: Request<ListRequest>,
+        request: Request<SearchRequest>,
     ) -> Result<Response<ListProductResponse>, Status> {
         let request = request.into_inner();
         let session = self.get_session().await.unwrap();
@@ -104,7 +139,7 @@ impl ProductServices for ProductServicesImpl {
         let services = ProductServiceImpl::new(session);
 
         services
-            .list(request)
+            .search(request)
             .await
             .map(|p| {
                 let p: Vec<Product> = p.into_iter().map(|e| e.into()).collect();
@@ -112,4 +147,337 @@ impl ProductServices for ProductServicesImpl {
             })
             .map_err(grpc_error_handler)
     }
+
+    async fn import(
+        &self,
+        request: Request<Streaming<CreateProductRequest>>,
+    ) -> Result<Response<ImportReport>, Status> {
+        let requests = request.into_inner().try_collect::<Vec<_>>().await?;
+        let session = self.get_session().await.unwrap();
+
+        let services = ProductServiceImpl::new(session);
+
+        services
+            .import(requests)
+            .await
+            .map(|e| Response::new(e.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn export(
+        &self,
+        request: Request<ListProductRequest>,
+    ) -> Result<Response<Self::exportStream>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = ProductServiceImpl::new(session);
+
+        services
+            .export(request)
+            .await
+            .map(|products| {
+                let products = products
+                    .map_ok(Product::from)
+                    .map_err(grpc_error_handler)
+                    .boxed();
+                Response::new(products)
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn create_category(
+        &self,
+        request: Request<CreateCategoryRequest>,
+    ) -> Result<Response<Category>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CategoryServiceImpl::new(session);
+
+        services
+            .create(request)
+            .await
+            .map(|c| Response::new(c.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn update_category(
+        &self,
+        request: Request<UpdateCategoryRequest>,
+    ) -> Result<Response<Category>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CategoryServiceImpl::new(session);
+
+        services
+            .update(request)
+            .await
+            .map(|c| Response::new(c.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_category(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = CategoryServiceImpl::new(session);
+
+        let product_ids = services
+            .list_product_ids(id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        change_products(self.cache.as_ref(), product_ids, services.delete(id as i64))
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_categories(
+        &self,
+        request: Request<ListCategoryRequest>,
+    ) -> Result<Response<ListCategoryResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = CategoryServiceImpl::new(session);
+
+        services
+            .list(request)
+            .await
+            .map(|c| {
+                let c: Vec<Category> = c.into_iter().map(|e| e.into()).collect();
+                Response::new(ListCategoryResponse { categories: c })
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn create_tag(
+        &self,
+        request: Request<CreateTagRequest>,
+    ) -> Result<Response<Tag>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = TagServiceImpl::new(session);
+
+        services
+            .create(request)
+            .await
+            .map(|t| Response::new(t.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn update_tag(
+        &self,
+        request: Request<UpdateTagRequest>,
+    ) -> Result<Response<Tag>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = TagServiceImpl::new(session);
+
+        let product_ids = services
+            .list_product_ids(request.id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        change_products(self.cache.as_ref(), product_ids, services.update(request))
+            .await
+            .map(|t| Response::new(t.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_tag(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = TagServiceImpl::new(session);
+
+        let product_ids = services
+            .list_product_ids(id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        change_products(self.cache.as_ref(), product_ids, services.delete(id as i64))
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_tags(
+        &self,
+        request: Request<ListRequest>,
+    ) -> Result<Response<ListTagResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = TagServiceImpl::new(session);
+
+        services
+            .list(request)
+            .await
+            .map(|t| {
+                let t: Vec<Tag> = t.into_iter().map(|e| e.into()).collect();
+                Response::new(ListTagResponse { tags: t })
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn add_image(
+        &self,
+        request: Request<AddProductImageRequest>,
+    ) -> Result<Response<ProductImage>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = ImageServiceImpl::new(session);
+
+        let image = services.add(request).await.map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), product_keys(image.product_id as u64)).await;
+
+        Ok(Response::new(image.into()))
+    }
+
+    async fn delete_image(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<ProductImage>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = ImageServiceImpl::new(session);
+
+        let image = services
+            .delete(id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), product_keys(image.product_id as u64)).await;
+
+        Ok(Response::new(image.into()))
+    }
+
+    async fn create_supplier(
+        &self,
+        request: Request<CreateSupplierRequest>,
+    ) -> Result<Response<Supplier>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = SupplierServiceImpl::new(session);
+
+        services
+            .create(request)
+            .await
+            .map(|s| Response::new(s.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn update_supplier(
+        &self,
+        request: Request<UpdateSupplierRequest>,
+    ) -> Result<Response<Supplier>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = SupplierServiceImpl::new(session);
+
+        let product_ids = services
+            .list_product_ids(request.id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        change_products(self.cache.as_ref(), product_ids, services.update(request))
+            .await
+            .map(|s| Response::new(s.into()))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn delete_supplier(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = SupplierServiceImpl::new(session);
+
+        let product_ids = services
+            .list_product_ids(id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        change_products(self.cache.as_ref(), product_ids, services.delete(id as i64))
+            .await
+            .map(|e| Response::new(DeleteResponse { result: e }))
+            .map_err(grpc_error_handler)
+    }
+
+    async fn list_suppliers(
+        &self,
+        request: Request<ListRequest>,
+    ) -> Result<Response<ListSupplierResponse>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = SupplierServiceImpl::new(session);
+
+        services
+            .list(request)
+            .await
+            .map(|s| {
+                let s: Vec<Supplier> = s.into_iter().map(|e| e.into()).collect();
+                Response::new(ListSupplierResponse { suppliers: s })
+            })
+            .map_err(grpc_error_handler)
+    }
+
+    async fn set_product_supplier(
+        &self,
+        request: Request<SetProductSupplierRequest>,
+    ) -> Result<Response<Product>, Status> {
+        let request = request.into_inner();
+        let session = self.get_session().await.unwrap();
+
+        let services = ProductServiceImpl::new(session);
+
+        let id = request.product_id;
+        let product = services
+            .set_supplier(request)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), product_keys(id)).await;
+
+        Ok(Response::new(product.into()))
+    }
+
+    async fn remove_product_supplier(
+        &self,
+        request: Request<GetByIdRequest>,
+    ) -> Result<Response<DeleteResponse>, Status> {
+        let id = request.into_inner().id;
+        let session = self.get_session().await.unwrap();
+
+        let services = ProductServiceImpl::new(session);
+
+        let result = services
+            .remove_supplier(id as i64)
+            .await
+            .map_err(grpc_error_handler)?;
+
+        invalidate(self.cache.as_ref(), product_keys(id)).await;
+
+        Ok(Response::new(DeleteResponse { result }))
+    }
 }
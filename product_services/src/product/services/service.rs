@@ -2,19 +2,40 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use futures::lock::Mutex;
+use futures::stream::BoxStream;
 use rust_decimal::Decimal;
 use sqlx::pool::PoolConnection;
 use sqlx::Postgres;
 
+use common::json::import::ImportReport;
 use common::json::product::Product;
-use common::product_pb::{CreateProductRequest, UpdateProductRequest};
-use common::types::ListRequest;
+use common::product_pb::{
+    CreateProductRequest, ListProductRequest, SetProductSupplierRequest, UpdateProductRequest,
+};
+use common::types::SearchRequest;
 use common::util::alias::AppResult;
 use common::util::errors::AppError;
-use common::util::tools::database_error_handler;
+use common::util::search::search_limit;
+use common::util::tools::{batch_stream, database_error_handler, in_transaction, Transaction};
 
+use crate::category::repos::postgres_repo::CategoryRepoImpl;
+use crate::category::repos::repo::CategoryRepo;
+use crate::category::services::service::collect_descendant_ids;
+use crate::image::repos::postgres_repo::ImageRepoImpl;
+use crate::image::repos::repo::ImageRepo;
 use crate::product::repos::postgres_repo::ProductRepoImpl;
 use crate::product::repos::repo::ProductRepo;
+use crate::supplier::repos::postgres_repo::SupplierRepoImpl;
+use crate::supplier::repos::repo::SupplierRepo;
+use crate::tag::repos::postgres_repo::TagRepoImpl;
+use crate::tag::repos::repo::TagRepo;
+use crate::tag::services::service::normalize_tags;
+
+/// the largest cost price, the converted cost still fits in a `Decimal` and in NUMERIC.
+const MAX_COST_PRICE: f64 = 1e12;
+
+/// the largest exchange rate.
+const MAX_EXCHANGE_RATE: f64 = 1e6;
 
 #[async_trait]
 pub trait ProductService {
@@ -24,66 +45,362 @@ pub trait ProductService {
 
     async fn update(&self, request: UpdateProductRequest) -> AppResult<Product>;
 
-    async fn list(&self, request: ListRequest) -> AppResult<Vec<Product>>;
+    async fn list(&self, request: ListProductRequest) -> AppResult<Vec<Product>>;
+
+    /// find the products which match the query, the most similar product is the first one.
+    async fn search(&self, request: SearchRequest) -> AppResult<Vec<Product>>;
+
+    /// create the products in a transaction, the invalid rows are reported as failed.
+    async fn import(&self, requests: Vec<CreateProductRequest>) -> AppResult<ImportReport>;
+
+    /// stream all of the products which match the filters, ordered by id.
+    async fn export(
+        self,
+        request: ListProductRequest,
+    ) -> AppResult<BoxStream<'static, AppResult<Product>>>;
+
+    async fn set_supplier(&self, request: SetProductSupplierRequest) -> AppResult<Product>;
+
+    async fn remove_supplier(&self, product_id: i64) -> AppResult<bool>;
 }
 
 pub(crate) struct ProductServiceImpl {
+    transaction: Box<dyn Transaction>,
     repo: Box<dyn ProductRepo + Send + Sync>,
+    category_repo: Box<dyn CategoryRepo + Send + Sync>,
+    tag_repo: Box<dyn TagRepo + Send + Sync>,
+    image_repo: Box<dyn ImageRepo + Send + Sync>,
+    supplier_repo: Box<dyn SupplierRepo + Send + Sync>,
 }
 
 impl ProductServiceImpl {
     pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
-        let repo = Box::new(ProductRepoImpl::new(session));
-        Self { repo }
+        let repo = Box::new(ProductRepoImpl::new(session.clone()));
+        let category_repo = Box::new(CategoryRepoImpl::new(session.clone()));
+        let tag_repo = Box::new(TagRepoImpl::new(session.clone()));
+        let image_repo = Box::new(ImageRepoImpl::new(session.clone()));
+        let supplier_repo = Box::new(SupplierRepoImpl::new(session.clone()));
+        Self {
+            transaction: Box::new(session),
+            repo,
+            category_repo,
+            tag_repo,
+            image_repo,
+            supplier_repo,
+        }
+    }
+
+    async fn check_category_is_exist(&self, category_id: Option<u64>) -> AppResult<()> {
+        if let Some(category_id) = category_id.filter(|&e| e != 0) {
+            let category = self
+                .category_repo
+                .get(category_id as i64)
+                .await
+                .map_err(database_error_handler)?;
+
+            if category.is_none() {
+                return Err(AppError::BadRequest(format!(
+                    "Can't find the category by id {}",
+                    category_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn set_tags(&self, product_id: i64, tags: Vec<String>) -> anyhow::Result<Vec<String>> {
+        let tags = self.tag_repo.find_or_create(tags).await?;
+        let tag_ids = tags.iter().map(|t| t.id).collect::<Vec<_>>();
+
+        self.tag_repo.set_product_tags(product_id, tag_ids).await?;
+
+        let mut names = tags.into_iter().map(|t| t.name).collect::<Vec<_>>();
+        names.sort();
+
+        Ok(names)
+    }
+
+    async fn import_rows(
+        &self,
+        requests: Vec<CreateProductRequest>,
+    ) -> anyhow::Result<ImportReport> {
+        let mut report = ImportReport::default();
+
+        for (i, request) in requests.into_iter().enumerate() {
+            let row = i as u32 + 1;
+
+            match self.check_category_is_exist(request.category_id).await {
+                Err(AppError::BadRequest(reason)) => {
+                    report.add_failed(row, reason);
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+                Ok(_) => {}
+            }
+
+            let tags = normalize_tags(request.tags.clone());
+
+            let product = self.repo.create(request).await?;
+            self.set_tags(product.id, tags).await?;
+
+            report.add_created(row, product.id);
+        }
+
+        Ok(report)
+    }
+
+    /// resolve the filters of a list request.
+    ///
+    /// return:
+    /// - the request with the normalized tags.
+    /// - the ids of the requested category and its descendants.
+    async fn resolve_filters(
+        &self,
+        request: ListProductRequest,
+    ) -> AppResult<(ListProductRequest, Option<Vec<i64>>)> {
+        let category_ids = match request.category_id {
+            Some(category_id) => {
+                let categories = self
+                    .category_repo
+                    .list_all()
+                    .await
+                    .map_err(database_error_handler)?;
+
+                Some(collect_descendant_ids(&categories, category_id as i64))
+            }
+            None => None,
+        };
+
+        let request = ListProductRequest {
+            tags: normalize_tags(request.tags),
+            ..request
+        };
+
+        Ok((request, category_ids))
+    }
+
+    /// attach the tags, the images and the supplier to the products.
+    async fn attach_relations(&self, mut products: Vec<Product>) -> AppResult<Vec<Product>> {
+        let ids = products.iter().map(|p| p.id).collect::<Vec<_>>();
+
+        let mut tags = self
+            .tag_repo
+            .get_product_tags(ids.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        let mut images = self
+            .image_repo
+            .get_product_images(ids.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        let mut suppliers = self
+            .supplier_repo
+            .get_product_suppliers(ids)
+            .await
+            .map_err(database_error_handler)?;
+
+        for p in products.iter_mut() {
+            p.tags = tags.remove(&p.id).unwrap_or_default();
+            p.images = images.remove(&p.id).unwrap_or_default();
+            p.supplier = suppliers.remove(&p.id).map(|s| s.with_margin(p.price));
+        }
+
+        Ok(products)
     }
 }
 
 #[async_trait]
 impl ProductService for ProductServiceImpl {
     async fn get(&self, id: i64) -> AppResult<Option<Product>> {
-        self.repo.get(id).await.map_err(database_error_handler)
+        let product = self.repo.get(id).await.map_err(database_error_handler)?;
+
+        match product {
+            Some(p) => Ok(self.attach_relations(vec![p]).await?.pop()),
+            None => Ok(None),
+        }
     }
 
     async fn create(&self, request: CreateProductRequest) -> AppResult<Product> {
-        self.repo
-            .create(request)
-            .await
-            .map_err(database_error_handler)
+        self.check_category_is_exist(request.category_id).await?;
+
+        let tags = normalize_tags(request.tags.clone());
+
+        in_transaction(self.transaction.as_ref(), async {
+            let mut product = self.repo.create(request).await?;
+            product.tags = self.set_tags(product.id, tags).await?;
+            Ok(product)
+        })
+        .await
     }
 
     async fn update(&self, request: UpdateProductRequest) -> AppResult<Product> {
-        let old_product = self.repo.get(request.id as i64).await.ok().flatten();
-
-        if let Some(p) = old_product {
-            let is_affected = self.repo.update(request.clone()).await;
-
-            if is_affected.is_ok() {
-                let currency = request.currency.map(|c| c as i16).unwrap_or(p.currency);
-                let price = request
-                    .price
-                    .map(|e| Decimal::from_f64_retain(e).unwrap())
-                    .unwrap_or(p.price);
-                let new_product = Product {
-                    name: request.name.unwrap_or(p.name),
-                    currency,
-                    price,
-                    ..p
-                };
-
-                return Ok(new_product);
+        self.check_category_is_exist(request.category_id).await?;
+
+        let p = self.get(request.id as i64).await?.ok_or_else(|| {
+            AppError::BadRequest(format!("Can't find the product by id {}", request.id))
+        })?;
+
+        let tags = in_transaction(self.transaction.as_ref(), async {
+            self.repo.update(request.clone()).await?;
+
+            match request.tags.clone() {
+                Some(tags) => self.set_tags(p.id, normalize_tags(tags.tags)).await,
+                None => Ok(p.tags.clone()),
             }
+        })
+        .await?;
 
-            return Ok(p);
+        let currency = request.currency.map(|c| c as i16).unwrap_or(p.currency);
+        let price = request
+            .price
+            .map(|e| Decimal::from_f64_retain(e).unwrap())
+            .unwrap_or(p.price);
+        let category_id = match request.category_id {
+            Some(0) => None,
+            Some(id) => Some(id as i64),
+            None => p.category_id,
+        };
+        let supplier = p.supplier.clone().map(|s| s.with_margin(price));
+
+        Ok(Product {
+            name: request.name.unwrap_or(p.name),
+            currency,
+            price,
+            category_id,
+            tags,
+            supplier,
+            ..p
+        })
+    }
+
+    async fn list(&self, request: ListProductRequest) -> AppResult<Vec<Product>> {
+        let (request, category_ids) = self.resolve_filters(request).await?;
+
+        let products = self
+            .repo
+            .list(request, category_ids)
+            .await
+            .map_err(database_error_handler)?;
+
+        self.attach_relations(products).await
+    }
+
+    async fn search(&self, request: SearchRequest) -> AppResult<Vec<Product>> {
+        if request.query.trim().is_empty() {
+            return Ok(vec![]);
         }
 
-        Err(AppError::DatabaseError(
-            "failed to update product.".to_string(),
-        ))
+        let products = self
+            .repo
+            .search(request.query, search_limit(request.limit))
+            .await
+            .map_err(database_error_handler)?;
+
+        self.attach_relations(products).await
+    }
+
+    async fn import(&self, requests: Vec<CreateProductRequest>) -> AppResult<ImportReport> {
+        in_transaction(self.transaction.as_ref(), self.import_rows(requests)).await
+    }
+
+    async fn export(
+        self,
+        request: ListProductRequest,
+    ) -> AppResult<BoxStream<'static, AppResult<Product>>> {
+        let (request, category_ids) = self.resolve_filters(request).await?;
+        let service = Arc::new(self);
+
+        let products = batch_stream(
+            move |after_id, limit| {
+                let service = service.clone();
+                let request = request.clone();
+                let category_ids = category_ids.clone();
+
+                async move {
+                    let products = service
+                        .repo
+                        .list_after(request, category_ids, after_id, limit)
+                        .await
+                        .map_err(database_error_handler)?;
+
+                    service.attach_relations(products).await
+                }
+            },
+            |p| p.id,
+        );
+
+        Ok(products)
+    }
+
+    async fn set_supplier(&self, request: SetProductSupplierRequest) -> AppResult<Product> {
+        let product = self.get(request.product_id as i64).await?.ok_or_else(|| {
+            AppError::BadRequest(format!(
+                "Can't find the product by id {}",
+                request.product_id
+            ))
+        })?;
+
+        let supplier = self
+            .supplier_repo
+            .get(request.supplier_id as i64)
+            .await
+            .map_err(database_error_handler)?;
+
+        if supplier.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the supplier by id {}",
+                request.supplier_id
+            )));
+        }
+
+        // NaN isn't in any range, so it is rejected as well.
+        if !(0.0..=MAX_COST_PRICE).contains(&request.cost_price) {
+            return Err(AppError::BadRequest(format!(
+                "cost price must be between 0 and {}.",
+                MAX_COST_PRICE
+            )));
+        }
+
+        // the cost doesn't need to be converted if it is paid in the sale currency.
+        let exchange_rate = match request.exchange_rate.filter(|&e| e != 0.0) {
+            Some(rate) if !(0.0..=MAX_EXCHANGE_RATE).contains(&rate) => {
+                return Err(AppError::BadRequest(format!(
+                    "exchange rate must be between 0 and {}.",
+                    MAX_EXCHANGE_RATE
+                )));
+            }
+            Some(rate) => rate,
+            None if request.cost_currency as i16 == product.currency => 1.0,
+            None => {
+                return Err(AppError::BadRequest(
+                    "exchange rate is required when the cost currency is different from the sale currency.".to_string(),
+                ));
+            }
+        };
+
+        let request = SetProductSupplierRequest {
+            exchange_rate: Some(exchange_rate),
+            source_url: request.source_url.filter(|e| !e.is_empty()),
+            ..request
+        };
+
+        in_transaction(
+            self.transaction.as_ref(),
+            self.supplier_repo.set_product_supplier(request),
+        )
+        .await?;
+
+        self.get(product.id).await?.ok_or_else(|| {
+            AppError::DatabaseError("failed to set the product supplier.".to_string())
+        })
     }
 
-    async fn list(&self, request: ListRequest) -> AppResult<Vec<Product>> {
-        self.repo
-            .list(request)
+    async fn remove_supplier(&self, product_id: i64) -> AppResult<bool> {
+        self.supplier_repo
+            .remove_product_supplier(product_id)
             .await
             .map_err(database_error_handler)
     }
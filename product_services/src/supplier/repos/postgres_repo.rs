@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use sea_query::{Alias, Expr, JoinType, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::{FromRow, Postgres, Row};
+
+use common::json::supplier::{ProductSupplier, ProductSuppliers, Supplier, Suppliers};
+use common::product_pb::{CreateSupplierRequest, SetProductSupplierRequest, UpdateSupplierRequest};
+use common::types::ListRequest;
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::supplier::repos::repo::SupplierRepo;
+use crate::ID_GENERATOR;
+
+pub struct SupplierRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl SupplierRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl SupplierRepo for SupplierRepoImpl {
+    async fn get(&self, id: i64) -> Result<Option<Supplier>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Suppliers::Id,
+                Suppliers::Name,
+                Suppliers::Website,
+                Suppliers::CreatedAt,
+                Suppliers::UpdatedAt,
+            ])
+            .from(Suppliers::Table)
+            .and_where(Expr::col(Suppliers::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Supplier>(&sql), &values)
+            .fetch_optional(conn.deref_mut())
+            .await?)
+    }
+
+    async fn create(&self, request: CreateSupplierRequest) -> Result<Supplier> {
+        let id = async move { ID_GENERATOR.lock().unwrap().next_id() as u64 }.await;
+
+        let mut conn = self.session.lock().await;
+
+        let cols: Vec<Suppliers> = vec![
+            Suppliers::Id,
+            Suppliers::Name,
+            Suppliers::Website,
+            Suppliers::CreatedAt,
+            Suppliers::UpdatedAt,
+        ];
+
+        let (sql, values) = Query::insert()
+            .into_table(Suppliers::Table)
+            .columns(cols.clone().into_iter().take(4).collect::<Vec<_>>())
+            .values_panic(vec![
+                id.into(),
+                request.name.into(),
+                request.website.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(cols).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Supplier>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn update(&self, request: UpdateSupplierRequest) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let mut update_values = vec![];
+        if let Some(name) = request.name {
+            update_values.push((Suppliers::Name, name.into()));
+        }
+
+        // an empty website removes the website of the supplier.
+        if let Some(website) = request.website {
+            let website = Some(website).filter(|e| !e.is_empty());
+            update_values.push((Suppliers::Website, website.into()));
+        }
+
+        if update_values.is_empty() {
+            return Ok(false);
+        }
+
+        update_values.push((Suppliers::UpdatedAt, chrono::Utc::now().into()));
+
+        let (sql, values) = Query::update()
+            .table(Suppliers::Table)
+            .values(update_values)
+            .and_where(Expr::col(Suppliers::Id).eq(request.id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn delete(&self, id: i64) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(Suppliers::Table)
+            .and_where(Expr::col(Suppliers::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, request: ListRequest) -> Result<Vec<Supplier>> {
+        let mut conn = self.session.lock().await;
+
+        let query = request.query.map(|q| format!("%{}%", q));
+        let page_size = request.page_size;
+        let offset = request.page * page_size;
+
+        let (sql, values) = Query::select()
+            .columns([
+                Suppliers::Id,
+                Suppliers::Name,
+                Suppliers::Website,
+                Suppliers::CreatedAt,
+                Suppliers::UpdatedAt,
+            ])
+            .from(Suppliers::Table)
+            .and_where_option(query.map(|e| Expr::col(Suppliers::Name).like(&e)))
+            .order_by(Suppliers::Name, Order::Asc)
+            .offset(offset)
+            .limit(page_size)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Supplier>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn set_product_supplier(&self, request: SetProductSupplierRequest) -> Result<()> {
+        let mut conn = self.session.lock().await;
+
+        let (delete_sql, delete_values) = Query::delete()
+            .from_table(ProductSuppliers::Table)
+            .and_where(Expr::col(ProductSuppliers::ProductId).eq(request.product_id))
+            .build(PostgresQueryBuilder);
+
+        let cost_price = Decimal::from_f64(request.cost_price).unwrap_or_default();
+        let exchange_rate = request
+            .exchange_rate
+            .and_then(Decimal::from_f64)
+            .unwrap_or(Decimal::ONE);
+
+        let (insert_sql, insert_values) = Query::insert()
+            .into_table(ProductSuppliers::Table)
+            .columns(vec![
+                ProductSuppliers::ProductId,
+                ProductSuppliers::SupplierId,
+                ProductSuppliers::SourceUrl,
+                ProductSuppliers::CostPrice,
+                ProductSuppliers::CostCurrency,
+                ProductSuppliers::ExchangeRate,
+                ProductSuppliers::CreatedAt,
+            ])
+            .values_panic(vec![
+                request.product_id.into(),
+                request.supplier_id.into(),
+                request.source_url.into(),
+                cost_price.into(),
+                (request.cost_currency as i16).into(),
+                exchange_rate.into(),
+                chrono::Utc::now().into(),
+            ])
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&delete_sql), &delete_values)
+            .execute(conn.deref_mut())
+            .await?;
+        bind_query(sqlx::query(&insert_sql), &insert_values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_product_supplier(&self, product_id: i64) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(ProductSuppliers::Table)
+            .and_where(Expr::col(ProductSuppliers::ProductId).eq(product_id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn get_product_suppliers(
+        &self,
+        product_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, ProductSupplier>> {
+        if product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([
+                (ProductSuppliers::Table, ProductSuppliers::ProductId),
+                (ProductSuppliers::Table, ProductSuppliers::SupplierId),
+                (ProductSuppliers::Table, ProductSuppliers::SourceUrl),
+                (ProductSuppliers::Table, ProductSuppliers::CostPrice),
+                (ProductSuppliers::Table, ProductSuppliers::CostCurrency),
+                (ProductSuppliers::Table, ProductSuppliers::ExchangeRate),
+            ])
+            .expr_as(
+                Expr::tbl(Suppliers::Table, Suppliers::Name),
+                Alias::new("supplier_name"),
+            )
+            .from(ProductSuppliers::Table)
+            .join(
+                JoinType::InnerJoin,
+                Suppliers::Table,
+                Expr::tbl(ProductSuppliers::Table, ProductSuppliers::SupplierId)
+                    .equals(Suppliers::Table, Suppliers::Id),
+            )
+            .and_where(
+                Expr::tbl(ProductSuppliers::Table, ProductSuppliers::ProductId).is_in(product_ids),
+            )
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        let mut suppliers = HashMap::new();
+        for row in rows {
+            let product_id: i64 = row.try_get("product_id")?;
+            suppliers.insert(product_id, ProductSupplier::from_row(&row)?);
+        }
+
+        Ok(suppliers)
+    }
+
+    async fn list_product_ids(&self, supplier_id: i64) -> Result<Vec<i64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column(ProductSuppliers::ProductId)
+            .from(ProductSuppliers::Table)
+            .and_where(Expr::col(ProductSuppliers::SupplierId).eq(supplier_id))
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect::<Result<Vec<i64>, _>>()?)
+    }
+}
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::supplier::{ProductSupplier, Supplier};
+use common::product_pb::{CreateSupplierRequest, SetProductSupplierRequest, UpdateSupplierRequest};
+use common::types::ListRequest;
+
+#[async_trait]
+pub trait SupplierRepo {
+    async fn get(&self, id: i64) -> Result<Option<Supplier>>;
+
+    async fn create(&self, request: CreateSupplierRequest) -> Result<Supplier>;
+
+    async fn update(&self, request: UpdateSupplierRequest) -> Result<bool>;
+
+    async fn delete(&self, id: i64) -> Result<bool>;
+
+    async fn list(&self, request: ListRequest) -> Result<Vec<Supplier>>;
+
+    /// insert or replace the supplier of the product.
+    async fn set_product_supplier(&self, request: SetProductSupplierRequest) -> Result<()>;
+
+    async fn remove_product_supplier(&self, product_id: i64) -> Result<bool>;
+
+    /// get the suppliers of the products, keyed by product id.
+    async fn get_product_suppliers(
+        &self,
+        product_ids: Vec<i64>,
+    ) -> Result<HashMap<i64, ProductSupplier>>;
+
+    /// the ids of the products which are bought from the supplier.
+    async fn list_product_ids(&self, supplier_id: i64) -> Result<Vec<i64>>;
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::supplier::Supplier;
+use common::product_pb::{CreateSupplierRequest, UpdateSupplierRequest};
+use common::types::ListRequest;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::database_error_handler;
+
+use crate::supplier::repos::postgres_repo::SupplierRepoImpl;
+use crate::supplier::repos::repo::SupplierRepo;
+
+#[async_trait]
+pub trait SupplierService {
+    async fn create(&self, request: CreateSupplierRequest) -> AppResult<Supplier>;
+
+    async fn update(&self, request: UpdateSupplierRequest) -> AppResult<Supplier>;
+
+    async fn delete(&self, id: i64) -> AppResult<bool>;
+
+    async fn list(&self, request: ListRequest) -> AppResult<Vec<Supplier>>;
+
+    /// the ids of the products of the supplier, their cached details keep the supplier.
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>>;
+}
+
+pub(crate) struct SupplierServiceImpl {
+    repo: Box<dyn SupplierRepo + Send + Sync>,
+}
+
+impl SupplierServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(SupplierRepoImpl::new(session));
+        Self { repo }
+    }
+}
+
+#[async_trait]
+impl SupplierService for SupplierServiceImpl {
+    async fn create(&self, request: CreateSupplierRequest) -> AppResult<Supplier> {
+        let name = request.name.trim().to_string();
+
+        if name.is_empty() {
+            return Err(AppError::BadRequest(
+                "supplier name can't be empty.".to_string(),
+            ));
+        }
+
+        let website = request.website.filter(|e| !e.is_empty());
+
+        self.repo
+            .create(CreateSupplierRequest { name, website })
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn update(&self, request: UpdateSupplierRequest) -> AppResult<Supplier> {
+        if matches!(&request.name, Some(name) if name.trim().is_empty()) {
+            return Err(AppError::BadRequest(
+                "supplier name can't be empty.".to_string(),
+            ));
+        }
+
+        let old_supplier = self
+            .repo
+            .get(request.id as i64)
+            .await
+            .map_err(database_error_handler)?
+            .ok_or_else(|| {
+                AppError::BadRequest(format!("Can't find the supplier by id {}", request.id))
+            })?;
+
+        let request = UpdateSupplierRequest {
+            name: request.name.map(|e| e.trim().to_string()),
+            ..request
+        };
+
+        self.repo
+            .update(request.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        let website = match request.website {
+            Some(website) if website.is_empty() => None,
+            Some(website) => Some(website),
+            None => old_supplier.website,
+        };
+
+        Ok(Supplier {
+            name: request.name.unwrap_or(old_supplier.name),
+            website,
+            ..old_supplier
+        })
+    }
+
+    async fn delete(&self, id: i64) -> AppResult<bool> {
+        self.repo.delete(id).await.map_err(database_error_handler)
+    }
+
+    async fn list(&self, request: ListRequest) -> AppResult<Vec<Supplier>> {
+        self.repo
+            .list(request)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>> {
+        self.repo
+            .list_product_ids(id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+
+use common::json::tag::Tag;
+use common::product_pb::{CreateTagRequest, UpdateTagRequest};
+use common::types::ListRequest;
+
+use crate::tag::repos::repo::TagRepo;
+use crate::ID_GENERATOR;
+
+/// keep the tags and the tags of the products in memory.
+#[derive(Clone, Default)]
+pub(crate) struct FakeTagRepo {
+    tags: Arc<Mutex<HashMap<i64, Tag>>>,
+    product_tags: Arc<Mutex<Vec<(i64, i64)>>>,
+}
+
+impl FakeTagRepo {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl TagRepo for FakeTagRepo {
+    async fn get(&self, id: i64) -> anyhow::Result<Option<Tag>> {
+        Ok(self.tags.lock().await.get(&id).cloned())
+    }
+
+    async fn get_by_name(&self, name: String) -> anyhow::Result<Option<Tag>> {
+        let tags = self.tags.lock().await;
+        Ok(tags.values().find(|t| t.name == name).cloned())
+    }
+
+    async fn create(&self, req: CreateTagRequest) -> anyhow::Result<Tag> {
+        let tag = Tag {
+            id: ID_GENERATOR.lock().unwrap().next_id() as i64,
+            name: req.name,
+            created_at: chrono::Utc::now(),
+        };
+
+        self.tags.lock().await.insert(tag.id, tag.clone());
+        Ok(tag)
+    }
+
+    async fn update(&self, req: UpdateTagRequest) -> anyhow::Result<bool> {
+        let mut tags = self.tags.lock().await;
+
+        match tags.get_mut(&(req.id as i64)) {
+            Some(tag) => {
+                tag.name = req.name;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn delete(&self, id: i64) -> anyhow::Result<bool> {
+        self.product_tags.lock().await.retain(|(_, t)| *t != id);
+        Ok(self.tags.lock().await.remove(&id).is_some())
+    }
+
+    async fn list(&self, req: ListRequest) -> anyhow::Result<Vec<Tag>> {
+        let tags = self.tags.lock().await;
+
+        let mut tags = tags
+            .values()
+            .filter(|t| req.query.as_ref().map_or(true, |q| t.name.contains(q)))
+            .cloned()
+            .collect::<Vec<_>>();
+        tags.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(tags
+            .into_iter()
+            .skip((req.page * req.page_size) as usize)
+            .take(req.page_size as usize)
+            .collect())
+    }
+
+    async fn find_or_create(&self, names: Vec<String>) -> anyhow::Result<Vec<Tag>> {
+        let mut tags = vec![];
+
+        for name in names {
+            let tag = match self.get_by_name(name.clone()).await? {
+                Some(tag) => tag,
+                None => self.create(CreateTagRequest { name }).await?,
+            };
+            tags.push(tag);
+        }
+
+        Ok(tags)
+    }
+
+    async fn set_product_tags(&self, product_id: i64, tag_ids: Vec<i64>) -> anyhow::Result<()> {
+        let mut product_tags = self.product_tags.lock().await;
+
+        product_tags.retain(|(p, _)| *p != product_id);
+        product_tags.extend(tag_ids.into_iter().map(|t| (product_id, t)));
+
+        Ok(())
+    }
+
+    async fn get_product_tags(
+        &self,
+        product_ids: Vec<i64>,
+    ) -> anyhow::Result<HashMap<i64, Vec<String>>> {
+        let tags = self.tags.lock().await;
+        let product_tags = self.product_tags.lock().await;
+
+        let mut names: HashMap<i64, Vec<String>> = HashMap::new();
+        for (product_id, tag_id) in product_tags.iter() {
+            if let (true, Some(tag)) = (product_ids.contains(product_id), tags.get(tag_id)) {
+                names.entry(*product_id).or_default().push(tag.name.clone());
+            }
+        }
+
+        names.values_mut().for_each(|e| e.sort());
+        Ok(names)
+    }
+
+    async fn list_product_ids(&self, tag_id: i64) -> anyhow::Result<Vec<i64>> {
+        let product_tags = self.product_tags.lock().await;
+
+        Ok(product_tags
+            .iter()
+            .filter(|(_, t)| *t == tag_id)
+            .map(|(p, _)| *p)
+            .collect())
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+pub mod fake_repo;
+pub mod postgres_repo;
+pub mod repo;
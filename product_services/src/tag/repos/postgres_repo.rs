@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sea_query::{Expr, JoinType, Order, PostgresQueryBuilder, Query};
+use sqlx::pool::PoolConnection;
+use sqlx::{Postgres, Row};
+
+use common::json::tag::{ProductTags, Tag, Tags};
+use common::product_pb::{CreateTagRequest, UpdateTagRequest};
+use common::types::ListRequest;
+use common::util::sql::{bind_query, bind_query_as};
+
+use crate::tag::repos::repo::TagRepo;
+use crate::ID_GENERATOR;
+
+pub struct TagRepoImpl {
+    session: Arc<Mutex<PoolConnection<Postgres>>>,
+}
+
+impl TagRepoImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        Self { session }
+    }
+}
+
+#[async_trait]
+impl TagRepo for TagRepoImpl {
+    async fn get(&self, id: i64) -> Result<Option<Tag>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([Tags::Id, Tags::Name, Tags::CreatedAt])
+            .from(Tags::Table)
+            .and_where(Expr::col(Tags::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_optional(conn.deref_mut())
+            .await?)
+    }
+
+    async fn get_by_name(&self, name: String) -> Result<Option<Tag>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .columns([Tags::Id, Tags::Name, Tags::CreatedAt])
+            .from(Tags::Table)
+            .and_where(Expr::col(Tags::Name).eq(name))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_optional(conn.deref_mut())
+            .await?)
+    }
+
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag> {
+        let id = async move { ID_GENERATOR.lock().unwrap().next_id() as u64 }.await;
+
+        let mut conn = self.session.lock().await;
+
+        let cols = vec![Tags::Id, Tags::Name, Tags::CreatedAt];
+
+        let (sql, values) = Query::insert()
+            .into_table(Tags::Table)
+            .columns(cols.clone())
+            .values_panic(vec![
+                id.into(),
+                request.name.into(),
+                chrono::Utc::now().into(),
+            ])
+            .returning(Query::select().columns(cols).take())
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_one(conn.deref_mut())
+            .await?)
+    }
+
+    async fn update(&self, request: UpdateTagRequest) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::update()
+            .table(Tags::Table)
+            .values(vec![(Tags::Name, request.name.into())])
+            .and_where(Expr::col(Tags::Id).eq(request.id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn delete(&self, id: i64) -> Result<bool> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(Tags::Table)
+            .and_where(Expr::col(Tags::Id).eq(id))
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await
+            .map(|e| e.rows_affected() > 0)?)
+    }
+
+    async fn list(&self, request: ListRequest) -> Result<Vec<Tag>> {
+        let mut conn = self.session.lock().await;
+
+        let query = request.query.map(|q| format!("%{}%", q));
+        let page_size = request.page_size;
+        let offset = request.page * page_size;
+
+        let (sql, values) = Query::select()
+            .columns([Tags::Id, Tags::Name, Tags::CreatedAt])
+            .from(Tags::Table)
+            .and_where_option(query.map(|e| Expr::col(Tags::Name).like(&e)))
+            .order_by(Tags::Name, Order::Asc)
+            .offset(offset)
+            .limit(page_size)
+            .build(PostgresQueryBuilder);
+
+        Ok(bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?)
+    }
+
+    async fn find_or_create(&self, names: Vec<String>) -> Result<Vec<Tag>> {
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut conn = self.session.lock().await;
+
+        let cols = vec![Tags::Id, Tags::Name, Tags::CreatedAt];
+
+        let (sql, values) = Query::select()
+            .columns(cols.clone())
+            .from(Tags::Table)
+            .and_where(Expr::col(Tags::Name).is_in(names.clone()))
+            .build(PostgresQueryBuilder);
+
+        let mut tags = bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        let missing = names
+            .into_iter()
+            .filter(|name| !tags.iter().any(|t| t.name.eq(name)))
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            return Ok(tags);
+        }
+
+        let mut insert = Query::insert();
+        insert.into_table(Tags::Table).columns(cols.clone());
+
+        let now = chrono::Utc::now();
+        for name in missing {
+            let id = ID_GENERATOR.lock().unwrap().next_id() as u64;
+            insert.values_panic(vec![id.into(), name.into(), now.into()]);
+        }
+
+        let (sql, values) = insert
+            .returning(Query::select().columns(cols).take())
+            .build(PostgresQueryBuilder);
+
+        let created = bind_query_as(sqlx::query_as::<_, Tag>(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        tags.extend(created);
+
+        Ok(tags)
+    }
+
+    async fn set_product_tags(&self, product_id: i64, tag_ids: Vec<i64>) -> Result<()> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::delete()
+            .from_table(ProductTags::Table)
+            .and_where(Expr::col(ProductTags::ProductId).eq(product_id))
+            .build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        if tag_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(ProductTags::Table)
+            .columns(vec![ProductTags::ProductId, ProductTags::TagId]);
+
+        for tag_id in tag_ids {
+            insert.values_panic(vec![product_id.into(), tag_id.into()]);
+        }
+
+        let (sql, values) = insert.build(PostgresQueryBuilder);
+
+        bind_query(sqlx::query(&sql), &values)
+            .execute(conn.deref_mut())
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_product_tags(&self, product_ids: Vec<i64>) -> Result<HashMap<i64, Vec<String>>> {
+        if product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column((ProductTags::Table, ProductTags::ProductId))
+            .column((Tags::Table, Tags::Name))
+            .from(ProductTags::Table)
+            .join(
+                JoinType::InnerJoin,
+                Tags::Table,
+                Expr::tbl(ProductTags::Table, ProductTags::TagId).equals(Tags::Table, Tags::Id),
+            )
+            .and_where(Expr::tbl(ProductTags::Table, ProductTags::ProductId).is_in(product_ids))
+            .order_by((Tags::Table, Tags::Name), Order::Asc)
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        for row in rows {
+            let product_id: i64 = row.try_get(0)?;
+            let name: String = row.try_get(1)?;
+            tags.entry(product_id).or_default().push(name);
+        }
+
+        Ok(tags)
+    }
+
+    async fn list_product_ids(&self, tag_id: i64) -> Result<Vec<i64>> {
+        let mut conn = self.session.lock().await;
+
+        let (sql, values) = Query::select()
+            .column(ProductTags::ProductId)
+            .from(ProductTags::Table)
+            .and_where(Expr::col(ProductTags::TagId).eq(tag_id))
+            .build(PostgresQueryBuilder);
+
+        let rows = bind_query(sqlx::query(&sql), &values)
+            .fetch_all(conn.deref_mut())
+            .await?;
+
+        Ok(rows
+            .iter()
+            .map(|row| row.try_get(0))
+            .collect::<Result<Vec<i64>, _>>()?)
+    }
+}
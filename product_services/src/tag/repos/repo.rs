@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use common::json::tag::Tag;
+use common::product_pb::{CreateTagRequest, UpdateTagRequest};
+use common::types::ListRequest;
+
+#[async_trait]
+pub trait TagRepo {
+    async fn get(&self, id: i64) -> Result<Option<Tag>>;
+
+    async fn get_by_name(&self, name: String) -> Result<Option<Tag>>;
+
+    async fn create(&self, request: CreateTagRequest) -> Result<Tag>;
+
+    async fn update(&self, request: UpdateTagRequest) -> Result<bool>;
+
+    async fn delete(&self, id: i64) -> Result<bool>;
+
+    async fn list(&self, request: ListRequest) -> Result<Vec<Tag>>;
+
+    /// find the tags by names, the missing tags will be created.
+    async fn find_or_create(&self, names: Vec<String>) -> Result<Vec<Tag>>;
+
+    /// replace all tags of the product.
+    async fn set_product_tags(&self, product_id: i64, tag_ids: Vec<i64>) -> Result<()>;
+
+    /// get the tag names of the products, grouped by product id.
+    async fn get_product_tags(&self, product_ids: Vec<i64>) -> Result<HashMap<i64, Vec<String>>>;
+
+    /// the ids of the products which have the tag.
+    async fn list_product_ids(&self, tag_id: i64) -> Result<Vec<i64>>;
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use sqlx::pool::PoolConnection;
+use sqlx::Postgres;
+
+use common::json::tag::Tag;
+use common::product_pb::{CreateTagRequest, UpdateTagRequest};
+use common::types::ListRequest;
+use common::util::alias::AppResult;
+use common::util::errors::AppError;
+use common::util::tools::database_error_handler;
+
+use crate::tag::repos::postgres_repo::TagRepoImpl;
+use crate::tag::repos::repo::TagRepo;
+
+#[async_trait]
+pub trait TagService {
+    async fn create(&self, request: CreateTagRequest) -> AppResult<Tag>;
+
+    async fn update(&self, request: UpdateTagRequest) -> AppResult<Tag>;
+
+    async fn delete(&self, id: i64) -> AppResult<bool>;
+
+    async fn list(&self, request: ListRequest) -> AppResult<Vec<Tag>>;
+
+    /// the ids of the products of the tag, their cached details keep the tag names.
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>>;
+}
+
+pub(crate) struct TagServiceImpl {
+    repo: Box<dyn TagRepo + Send + Sync>,
+}
+
+impl TagServiceImpl {
+    pub(crate) fn new(session: Arc<Mutex<PoolConnection<Postgres>>>) -> Self {
+        let repo = Box::new(TagRepoImpl::new(session));
+        Self { repo }
+    }
+}
+
+#[async_trait]
+impl TagService for TagServiceImpl {
+    async fn create(&self, request: CreateTagRequest) -> AppResult<Tag> {
+        let name = normalize_tag(&request.name)
+            .ok_or_else(|| AppError::BadRequest("tag name can't be empty.".to_string()))?;
+
+        let tag = self
+            .repo
+            .get_by_name(name.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        if tag.is_some() {
+            return Err(AppError::BadRequest(format!("tag {} already exist.", name)));
+        }
+
+        self.repo
+            .create(CreateTagRequest { name })
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn update(&self, request: UpdateTagRequest) -> AppResult<Tag> {
+        let name = normalize_tag(&request.name)
+            .ok_or_else(|| AppError::BadRequest("tag name can't be empty.".to_string()))?;
+
+        let old_tag = self.repo.get(request.id as i64).await.ok().flatten();
+
+        if old_tag.is_none() {
+            return Err(AppError::BadRequest(format!(
+                "Can't find the tag by id {}",
+                request.id
+            )));
+        }
+
+        let duplicated = self
+            .repo
+            .get_by_name(name.clone())
+            .await
+            .map_err(database_error_handler)?;
+
+        if duplicated.filter(|t| t.id != request.id as i64).is_some() {
+            return Err(AppError::BadRequest(format!("tag {} already exist.", name)));
+        }
+
+        let old_tag = old_tag.unwrap();
+
+        self.repo
+            .update(UpdateTagRequest {
+                id: request.id,
+                name: name.clone(),
+            })
+            .await
+            .map_err(database_error_handler)?;
+
+        Ok(Tag { name, ..old_tag })
+    }
+
+    async fn delete(&self, id: i64) -> AppResult<bool> {
+        self.repo.delete(id).await.map_err(database_error_handler)
+    }
+
+    async fn list(&self, request: ListRequest) -> AppResult<Vec<Tag>> {
+        self.repo
+            .list(request)
+            .await
+            .map_err(database_error_handler)
+    }
+
+    async fn list_product_ids(&self, id: i64) -> AppResult<Vec<i64>> {
+        self.repo
+            .list_product_ids(id)
+            .await
+            .map_err(database_error_handler)
+    }
+}
+
+/// normalize a tag name. return None if the tag is empty.
+pub(crate) fn normalize_tag(name: &str) -> Option<String> {
+    let name = name.trim().to_lowercase();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// normalize the tag names, the empty and duplicated tags are removed.
+pub(crate) fn normalize_tags(names: Vec<String>) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+
+    for name in names.iter().filter_map(|e| normalize_tag(e)) {
+        if !tags.contains(&name) {
+            tags.push(name);
+        }
+    }
+
+    tags
+}
+
+#[cfg(test)]
+mod test {
+    use common::util::cache::{change_products, product_key, read_through, Cache, MemoryCache};
+
+    use crate::tag::repos::fake_repo::FakeTagRepo;
+
+    use super::*;
+
+    /// read the tags of the product 1 through the cache, the way `get` reads the product.
+    async fn cached_tags(cache: &dyn Cache, repo: &FakeTagRepo) -> Option<Vec<String>> {
+        read_through(cache, &product_key(1), || async {
+            let mut tags = repo
+                .get_product_tags(vec![1])
+                .await
+                .map_err(database_error_handler)?;
+            Ok(tags.remove(&1))
+        })
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn updated_tag_is_not_read_from_the_cache() {
+        let repo = FakeTagRepo::new();
+        let service = TagServiceImpl {
+            repo: Box::new(repo.clone()),
+        };
+        let cache = MemoryCache::new();
+
+        let tag = service
+            .create(CreateTagRequest {
+                name: "sale".to_string(),
+            })
+            .await
+            .unwrap();
+        repo.set_product_tags(1, vec![tag.id]).await.unwrap();
+
+        assert_eq!(
+            cached_tags(&cache, &repo).await,
+            Some(vec!["sale".to_string()])
+        );
+
+        let product_ids = service.list_product_ids(tag.id).await.unwrap();
+        let request = UpdateTagRequest {
+            id: tag.id as u64,
+            name: "Clearance".to_string(),
+        };
+        change_products(&cache, product_ids, service.update(request))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cached_tags(&cache, &repo).await,
+            Some(vec!["clearance".to_string()])
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use warp::reply::Reply;
+
+use common::json::category::Category;
+use common::{product_pb, types};
+
+use crate::category::json::{CreateCategoryRequest, ListCategoryRequest, UpdateCategoryRequest};
+use crate::util::alias::WebResult;
+use crate::util::env::Env;
+use crate::util::recover::custom_error_handler;
+
+pub(crate) async fn create(req: CreateCategoryRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::CreateCategoryRequest = req.into();
+
+    client
+        .create_category(req)
+        .await
+        .map(|c| {
+            let c: Category = c.into_inner().into();
+            warp::reply::json(&c)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn update(req: UpdateCategoryRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::UpdateCategoryRequest = req.into();
+
+    client
+        .update_category(req)
+        .await
+        .map(|c| {
+            let c: Category = c.into_inner().into();
+            warp::reply::json(&c)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete(req: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    client
+        .delete_category(types::GetByIdRequest { id: req })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list(req: ListCategoryRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::ListCategoryRequest = req.into();
+
+    client
+        .list_categories(req)
+        .await
+        .map(|c| {
+            let c = c
+                .into_inner()
+                .categories
+                .into_iter()
+                .map(|c| c.into())
+                .collect::<Vec<Category>>();
+            warp::reply::json(&c)
+        })
+        .map_err(custom_error_handler)
+}
@@ -0,0 +1,50 @@
+use serde::Deserialize;
+
+use common::product_pb;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCategoryRequest {
+    pub name: String,
+    pub parent_id: Option<u64>,
+}
+
+impl From<CreateCategoryRequest> for product_pb::CreateCategoryRequest {
+    fn from(r: CreateCategoryRequest) -> Self {
+        Self {
+            name: r.name,
+            parent_id: r.parent_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateCategoryRequest {
+    pub id: u64,
+    pub name: Option<String>,
+    /// `0` moves the category to the root.
+    pub parent_id: Option<u64>,
+}
+
+impl From<UpdateCategoryRequest> for product_pb::UpdateCategoryRequest {
+    fn from(r: UpdateCategoryRequest) -> Self {
+        Self {
+            id: r.id,
+            name: r.name,
+            parent_id: r.parent_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListCategoryRequest {
+    /// list all categories if it's empty, `0` lists the root categories.
+    pub parent_id: Option<u64>,
+}
+
+impl From<ListCategoryRequest> for product_pb::ListCategoryRequest {
+    fn from(r: ListCategoryRequest) -> Self {
+        Self {
+            parent_id: r.parent_id,
+        }
+    }
+}
@@ -1,11 +1,21 @@
+use warp::hyper::body::Bytes;
 use warp::reply::Reply;
 
-use common::json::customer::Customer;
-use common::{customer_pb, types};
+use common::json::address::Address;
+use common::json::customer::{Customer, CustomerDuplicate};
+use common::json::customer_history::CustomerHistory;
+use common::json::note::Note;
+use common::{customer_pb, order_item_pb, types};
 
-use crate::customer::json::{CreateCustomerRequest, ListCustomerRequest, UpdateCustomerRequest};
+use crate::customer::json::{
+    CreateAddressRequest, CreateCustomerRequest, CustomerExportRow, ListCustomerRequest,
+    ListDuplicatesRequest, MergeCustomersRequest, UpdateAddressRequest, UpdateCustomerRequest,
+};
 use crate::util::alias::WebResult;
 use crate::util::env::Env;
+use crate::util::export::{export_reply, ExportRequest};
+use crate::util::import::{ImportRequest, ImportRows};
+use crate::util::note::CreateNoteRequest;
 
 use crate::util::recover::custom_error_handler;
 
@@ -77,3 +87,217 @@ pub(crate) async fn list(req: ListCustomerRequest, env: Env) -> WebResult<impl R
         })
         .map_err(custom_error_handler)
 }
+
+pub(crate) async fn import(req: ImportRequest, body: Bytes, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let mut rows = ImportRows::<customer_pb::CreateCustomerRequest>::parse::<
+        CreateCustomerRequest,
+        CreateCustomerRequest,
+    >(&body, req.format);
+
+    let requests = futures::stream::iter(rows.take_requests());
+
+    client
+        .import(requests)
+        .await
+        .map(|r| {
+            let report = rows.into_report(r.into_inner());
+            warp::reply::json(&report)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn export(
+    export_req: ExportRequest,
+    req: ListCustomerRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let req: types::ListRequest = req.into();
+
+    client
+        .export(req)
+        .await
+        .map(|rows| {
+            export_reply::<_, CustomerExportRow, Customer>(
+                rows.into_inner(),
+                export_req.format,
+                "customers",
+            )
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list_duplicates(req: ListDuplicatesRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let req: customer_pb::ListDuplicatesRequest = req.into();
+
+    client
+        .list_duplicates(req)
+        .await
+        .map(|d| {
+            let d = d
+                .into_inner()
+                .duplicates
+                .into_iter()
+                .map(|e| e.into())
+                .collect::<Vec<CustomerDuplicate>>();
+            warp::reply::json(&d)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn merge(req: MergeCustomersRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let req: customer_pb::MergeCustomersRequest = req.into();
+
+    client
+        .merge(req)
+        .await
+        .map(|c| {
+            let c: Customer = c.into_inner().into();
+            warp::reply::json(&c)
+        })
+        .map_err(custom_error_handler)
+}
+
+/// list the order items of the customer with the count by status,
+/// the total spent per currency, and the first and the last order date.
+pub(crate) async fn orders(customer_id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    client
+        .customer_history(order_item_pb::CustomerHistoryRequest { customer_id })
+        .await
+        .map(|h| {
+            let h: CustomerHistory = h.into_inner().into();
+            warp::reply::json(&h)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list_addresses(customer_id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    client
+        .list_addresses(customer_pb::ListAddressesRequest { customer_id })
+        .await
+        .map(|a| {
+            let a = a
+                .into_inner()
+                .addresses
+                .into_iter()
+                .map(|e| e.into())
+                .collect::<Vec<Address>>();
+            warp::reply::json(&a)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn create_address(
+    customer_id: u64,
+    req: CreateAddressRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let req = customer_pb::CreateAddressRequest {
+        customer_id,
+        ..req.into()
+    };
+
+    client
+        .create_address(req)
+        .await
+        .map(|a| {
+            let a: Address = a.into_inner().into();
+            warp::reply::json(&a)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn update_address(
+    customer_id: u64,
+    id: u64,
+    req: UpdateAddressRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    let req = customer_pb::UpdateAddressRequest {
+        id,
+        customer_id,
+        ..req.into()
+    };
+
+    client
+        .update_address(req)
+        .await
+        .map(|a| {
+            let a: Address = a.into_inner().into();
+            warp::reply::json(&a)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete_address(customer_id: u64, id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    client
+        .delete_address(customer_pb::DeleteAddressRequest { id, customer_id })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list_notes(id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    client
+        .list_notes(types::GetByIdRequest { id })
+        .await
+        .map(|n| {
+            let n = n
+                .into_inner()
+                .notes
+                .into_iter()
+                .map(|e| e.into())
+                .collect::<Vec<Note>>();
+            warp::reply::json(&n)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn create_note(
+    id: u64,
+    req: CreateNoteRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    client
+        .create_note(req.into_pb(id))
+        .await
+        .map(|n| {
+            let n: Note = n.into_inner().into();
+            warp::reply::json(&n)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete_note(id: u64, note_id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_customer_client;
+
+    client
+        .delete_note(types::DeleteNoteRequest {
+            owner_id: id,
+            id: note_id,
+        })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
@@ -1,5 +1,7 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
+use common::util::tools::timestamp2datetime;
 use common::{customer_pb, types};
 
 #[derive(Debug, Deserialize)]
@@ -7,6 +9,8 @@ pub struct CreateCustomerRequest {
     pub name: String,
     pub email: Option<String>,
     pub phone: Option<String>,
+    #[serde(default)]
+    pub contacts: Vec<ContactChannelRequest>,
 }
 
 impl From<CreateCustomerRequest> for customer_pb::CreateCustomerRequest {
@@ -15,6 +19,25 @@ impl From<CreateCustomerRequest> for customer_pb::CreateCustomerRequest {
             name: c.name,
             email: c.email,
             phone: c.phone,
+            contacts: c.contacts.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ContactChannelRequest {
+    pub channel: String,
+    pub handle: String,
+    #[serde(default)]
+    pub preferred: bool,
+}
+
+impl From<ContactChannelRequest> for customer_pb::ContactChannelRequest {
+    fn from(c: ContactChannelRequest) -> Self {
+        Self {
+            channel: c.channel,
+            handle: c.handle,
+            preferred: c.preferred,
         }
     }
 }
@@ -25,6 +48,8 @@ pub struct UpdateCustomerRequest {
     pub name: Option<String>,
     pub email: Option<String>,
     pub phone: Option<String>,
+    /// replace all of the contact channels if it is set.
+    pub contacts: Option<Vec<ContactChannelRequest>>,
 }
 
 impl From<UpdateCustomerRequest> for customer_pb::UpdateCustomerRequest {
@@ -34,6 +59,40 @@ impl From<UpdateCustomerRequest> for customer_pb::UpdateCustomerRequest {
             name: c.name,
             email: c.email,
             phone: c.phone,
+            contacts: c.contacts.map(|contacts| customer_pb::ContactChannelList {
+                contacts: contacts.into_iter().map(|e| e.into()).collect(),
+            }),
+        }
+    }
+}
+
+/// a customer row of an export csv file, the contact channels are joined as "channel:handle".
+#[derive(Debug, Serialize)]
+pub struct CustomerExportRow {
+    pub id: u64,
+    pub name: String,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub contacts: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<customer_pb::Customer> for CustomerExportRow {
+    fn from(c: customer_pb::Customer) -> Self {
+        Self {
+            id: c.id,
+            name: c.name,
+            email: c.email,
+            phone: c.phone,
+            contacts: c
+                .contacts
+                .iter()
+                .map(|e| format!("{}:{}", e.channel, e.handle))
+                .collect::<Vec<_>>()
+                .join(","),
+            created_at: timestamp2datetime(c.created_at),
+            updated_at: c.updated_at.map(timestamp2datetime),
         }
     }
 }
@@ -54,3 +113,94 @@ impl From<ListCustomerRequest> for types::ListRequest {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct ListDuplicatesRequest {
+    pub customer_id: Option<u64>,
+}
+
+impl From<ListDuplicatesRequest> for customer_pb::ListDuplicatesRequest {
+    fn from(e: ListDuplicatesRequest) -> Self {
+        Self {
+            customer_id: e.customer_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeCustomersRequest {
+    pub source_id: u64,
+    pub target_id: u64,
+}
+
+impl From<MergeCustomersRequest> for customer_pb::MergeCustomersRequest {
+    fn from(e: MergeCustomersRequest) -> Self {
+        Self {
+            source_id: e.source_id,
+            target_id: e.target_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAddressRequest {
+    pub recipient: String,
+    pub phone: Option<String>,
+    pub line1: String,
+    pub line2: Option<String>,
+    pub city: String,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: String,
+    #[serde(default)]
+    pub is_default: bool,
+}
+
+impl From<CreateAddressRequest> for customer_pb::CreateAddressRequest {
+    fn from(a: CreateAddressRequest) -> Self {
+        Self {
+            customer_id: 0,
+            recipient: a.recipient,
+            phone: a.phone,
+            line1: a.line1,
+            line2: a.line2,
+            city: a.city,
+            region: a.region,
+            postal_code: a.postal_code,
+            country: a.country,
+            is_default: a.is_default,
+        }
+    }
+}
+
+/// an empty string removes the optional fields, e.g. phone and line2.
+#[derive(Debug, Deserialize)]
+pub struct UpdateAddressRequest {
+    pub recipient: Option<String>,
+    pub phone: Option<String>,
+    pub line1: Option<String>,
+    pub line2: Option<String>,
+    pub city: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub is_default: Option<bool>,
+}
+
+impl From<UpdateAddressRequest> for customer_pb::UpdateAddressRequest {
+    fn from(a: UpdateAddressRequest) -> Self {
+        Self {
+            id: 0,
+            customer_id: 0,
+            recipient: a.recipient,
+            phone: a.phone,
+            line1: a.line1,
+            line2: a.line2,
+            city: a.city,
+            region: a.region,
+            postal_code: a.postal_code,
+            country: a.country,
+            is_default: a.is_default,
+        }
+    }
+}
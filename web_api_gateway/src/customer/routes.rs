@@ -1,8 +1,13 @@
 use warp::{Filter, filters::BoxedFilter, Reply};
 
-use crate::customer::handlers::v1::{create, get, list, update};
-use crate::customer::json::ListCustomerRequest;
+use crate::customer::handlers::v1::{
+    create, create_address, create_note, delete_address, delete_note, export, get, import, list,
+    list_addresses, list_duplicates, list_notes, merge, orders, update, update_address,
+};
+use crate::customer::json::{ListCustomerRequest, ListDuplicatesRequest};
 use crate::util::env::Env;
+use crate::util::export::ExportRequest;
+use crate::util::import::{ImportRequest, MAX_IMPORT_SIZE};
 use crate::util::middleware::with_env::with_env;
 
 pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
@@ -26,10 +31,95 @@ pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
     let list_route = warp::path!("api" / "v1" / "customers")
         .and(warp::get())
         .and(warp::query::<ListCustomerRequest>())
-        .and(with_env(env))
+        .and(with_env(env.clone()))
         .and_then(list);
 
-    let routes = get_route.or(create_route).or(update_route).or(list_route);
+    let import_route = warp::path!("api" / "v1" / "customers" / "import")
+        .and(warp::post())
+        .and(warp::query::<ImportRequest>())
+        .and(warp::body::content_length_limit(MAX_IMPORT_SIZE))
+        .and(warp::body::bytes())
+        .and(with_env(env.clone()))
+        .and_then(import);
+
+    let export_route = warp::path!("api" / "v1" / "customers" / "export")
+        .and(warp::get())
+        .and(warp::query::<ExportRequest>())
+        .and(warp::query::<ListCustomerRequest>())
+        .and(with_env(env.clone()))
+        .and_then(export);
+
+    let duplicates_route = warp::path!("api" / "v1" / "customers" / "duplicates")
+        .and(warp::get())
+        .and(warp::query::<ListDuplicatesRequest>())
+        .and(with_env(env.clone()))
+        .and_then(list_duplicates);
+
+    let merge_route = warp::path!("api" / "v1" / "customers" / "merge")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(merge);
+
+    let orders_route = warp::path!("api" / "v1" / "customers" / u64 / "orders")
+        .and(warp::get())
+        .and(with_env(env.clone()))
+        .and_then(orders);
+
+    let list_addresses_route = warp::path!("api" / "v1" / "customers" / u64 / "addresses")
+        .and(warp::get())
+        .and(with_env(env.clone()))
+        .and_then(list_addresses);
+
+    let create_address_route = warp::path!("api" / "v1" / "customers" / u64 / "addresses")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(create_address);
+
+    let update_address_route = warp::path!("api" / "v1" / "customers" / u64 / "addresses" / u64)
+        .and(warp::put())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(update_address);
+
+    let delete_address_route = warp::path!("api" / "v1" / "customers" / u64 / "addresses" / u64)
+        .and(warp::delete())
+        .and(with_env(env.clone()))
+        .and_then(delete_address);
+
+    let list_notes_route = warp::path!("api" / "v1" / "customers" / u64 / "notes")
+        .and(warp::get())
+        .and(with_env(env.clone()))
+        .and_then(list_notes);
+
+    let create_note_route = warp::path!("api" / "v1" / "customers" / u64 / "notes")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(create_note);
+
+    let delete_note_route = warp::path!("api" / "v1" / "customers" / u64 / "notes" / u64)
+        .and(warp::delete())
+        .and(with_env(env))
+        .and_then(delete_note);
+
+    let routes = get_route
+        .or(create_route)
+        .or(update_route)
+        .or(list_route)
+        .or(import_route)
+        .or(export_route)
+        .or(duplicates_route)
+        .or(merge_route)
+        .or(orders_route)
+        .or(list_addresses_route)
+        .or(create_address_route)
+        .or(update_address_route)
+        .or(delete_address_route)
+        .or(list_notes_route)
+        .or(create_note_route)
+        .or(delete_note_route);
 
     routes.boxed()
 }
@@ -0,0 +1,67 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use tonic::transport::Channel;
+use tonic_health::proto::health_check_response::ServingStatus;
+use tonic_health::proto::health_client::HealthClient;
+use tonic_health::proto::HealthCheckRequest;
+use warp::http::StatusCode;
+use warp::reply::Reply;
+
+use crate::health::json::ReadinessResponse;
+use crate::util::alias::WebResult;
+use crate::util::env::Env;
+
+/// how long a downstream service is given to answer the health check.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// the gateway is alive if it can answer.
+pub(crate) async fn healthz() -> WebResult<impl Reply> {
+    Ok(warp::reply::with_status("ok", StatusCode::OK))
+}
+
+/// check the health of the downstream services, the gateway is not ready if one of them
+/// is unreachable or not serving.
+pub(crate) async fn readyz(env: Env) -> WebResult<impl Reply> {
+    let checks = env
+        .grpc_health_clients
+        .into_iter()
+        .map(|(name, client)| async move { (name, check(name, client).await.to_string()) });
+
+    let services = futures::future::join_all(checks)
+        .await
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+
+    let ready = services.values().all(|e| e == "SERVING");
+
+    let code = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    let response = ReadinessResponse { ready, services };
+
+    Ok(warp::reply::with_status(warp::reply::json(&response), code))
+}
+
+/// check the health of the whole server of a downstream service.
+async fn check(name: &str, mut client: HealthClient<Channel>) -> &'static str {
+    let request = HealthCheckRequest {
+        service: String::new(),
+    };
+
+    match tokio::time::timeout(CHECK_TIMEOUT, client.check(request)).await {
+        Ok(Ok(response)) => match ServingStatus::from_i32(response.into_inner().status) {
+            Some(ServingStatus::Serving) => "SERVING",
+            Some(ServingStatus::NotServing) => "NOT_SERVING",
+            _ => "UNKNOWN",
+        },
+        Ok(Err(e)) => {
+            tracing::warn!(message = "the health check failed", name, %e);
+            "UNREACHABLE"
+        }
+        Err(_) => "TIMEOUT",
+    }
+}
@@ -0,0 +1,11 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+/// the gateway is ready if all of the downstream services are serving.
+#[derive(Debug, Serialize)]
+pub struct ReadinessResponse {
+    pub ready: bool,
+    /// the health status of each downstream service, e.g. `SERVING` or `UNREACHABLE`.
+    pub services: BTreeMap<&'static str, String>,
+}
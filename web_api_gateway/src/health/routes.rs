@@ -0,0 +1,16 @@
+use warp::{filters::BoxedFilter, Filter, Reply};
+
+use crate::health::handlers::v1::{healthz, readyz};
+use crate::util::env::Env;
+use crate::util::middleware::with_env::with_env;
+
+pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
+    let healthz_route = warp::path!("healthz").and(warp::get()).and_then(healthz);
+
+    let readyz_route = warp::path!("readyz")
+        .and(warp::get())
+        .and(with_env(env))
+        .and_then(readyz);
+
+    healthz_route.or(readyz_route).boxed()
+}
@@ -1,92 +1,147 @@
-use std::net::SocketAddr;
+use std::convert::Infallible;
+use std::sync::Arc;
 
 use anyhow::Result;
-use tonic::transport::Endpoint;
+use clap::Parser;
+use tonic_health::proto::health_client::HealthClient;
+use warp::hyper::service::{make_service_fn, service_fn};
+use warp::hyper::Server;
 use warp::Filter;
 
+use common::config::settings::Settings;
 use common::customer_pb::customer_services_client::CustomerServicesClient;
 use common::order_item_pb::order_services_client::OrderServicesClient;
 use common::product_pb::product_services_client::ProductServicesClient;
+use common::util::cli::{run_command, Cli, Command};
 use common::util::connections::create_database_connection;
-use common::util::tools::{
-    read_config_from_env, read_postgresql_config_from_env, tracing_initialize,
-};
+use common::util::metrics::{http_metrics, matched, metrics_route, named};
+use common::util::request_id::REQUEST_ID_HEADER;
+use common::util::shutdown::Shutdown;
+use common::util::storage::FileSystemStore;
+use common::util::telemetry::traced;
+use common::util::tools::tracing_initialize;
 
 use crate::util::env::Env;
+use crate::util::middleware::request_id::with_request_id;
 use crate::util::recover::rejection_handler;
 
+mod category;
 mod customer;
+mod health;
 mod order;
 mod product;
+mod search;
+mod supplier;
+mod tag;
 mod util;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let env_file = concat!(env!("CARGO_MANIFEST_DIR"), "/", "env", "/", "dev.env");
-    let _ = dotenv::from_path(env_file);
+    let cli = Cli::parse();
+    let settings = Settings::load_or_exit(&cli.source());
 
-    let config = read_config_from_env();
-    tracing_initialize(config.debug, "logs/", "gateway");
+    match cli.command() {
+        Command::Serve => serve(&cli, &settings).await,
+        command => run_command(command, &settings).await,
+    }
+}
+
+async fn serve(cli: &Cli, settings: &Settings) -> Result<()> {
+    let _tracing_guard = tracing_initialize(settings, &cli.log_dir, "gateway");
 
-    let postgres = read_postgresql_config_from_env();
-    let database_connection_pool = create_database_connection(postgres)
+    let database_connection_pool = create_database_connection(settings.postgres.clone())
         .await
         .expect("Can create a database connection pool.");
 
     let cors = warp::cors()
         .allow_any_origin()
-        .allow_headers(vec!["Content-Type"])
+        .allow_headers(vec!["Content-Type", REQUEST_ID_HEADER])
         .allow_credentials(true)
-        .expose_headers(vec!["set-cookie"])
+        .expose_headers(vec!["set-cookie", REQUEST_ID_HEADER])
         .allow_methods(vec!["GET", "POST", "DELETE", "PUT", "PATCH"]);
 
-    let addr = dotenv::var("CUSTOMER_CLIENT_ADDRESS")
-        .unwrap_or_else(|_| "http://127.0.0.1:10001".to_string())
-        .parse::<Endpoint>()
-        .expect("Can't parse hosting address.");
+    let addresses = &settings.addresses;
+
+    let customer_channel = addresses.customer_client.connect().await.unwrap();
+    let grpc_customer_client = CustomerServicesClient::new(traced(customer_channel.clone()));
 
-    let grpc_customer_client = CustomerServicesClient::connect(addr).await.unwrap();
+    let product_channel = addresses.product_client.connect().await.unwrap();
+    let grpc_product_client = ProductServicesClient::new(traced(product_channel.clone()));
 
-    let addr = dotenv::var("PRODUCT_CLIENT_ADDRESS")
-        .unwrap_or_else(|_| "http://127.0.0.1:10002".to_string())
-        .parse::<Endpoint>()
-        .expect("Can't parse hosting address.");
+    let order_channel = addresses.order_client.connect().await.unwrap();
+    let grpc_order_client = OrderServicesClient::new(traced(order_channel.clone()));
 
-    let grpc_product_client = ProductServicesClient::connect(addr).await.unwrap();
+    let grpc_health_clients = vec![
+        ("customer", HealthClient::new(customer_channel)),
+        ("product", HealthClient::new(product_channel)),
+        ("order", HealthClient::new(order_channel)),
+    ];
 
-    let addr = dotenv::var("ORDER_CLIENT_ADDRESS")
-        .unwrap_or_else(|_| "http://127.0.0.1:10003".to_string())
-        .parse::<Endpoint>()
-        .expect("Can't parse hosting address.");
+    let image_dir = settings.image_storage_dir.clone();
 
-    let grpc_order_client = OrderServicesClient::connect(addr).await.unwrap();
+    let image_store = Arc::new(FileSystemStore::new(&image_dir, "/images"));
 
     let env = Env::new(
         true,
         grpc_customer_client,
         grpc_product_client,
         grpc_order_client,
+        image_store,
+        grpc_health_clients,
     );
 
     let customer_routes = customer::routes::routes(env.clone());
     let product_routes = product::routes::routes(env.clone());
     let order_routes = order::routes::routes(env.clone());
+    let category_routes = category::routes::routes(env.clone());
+    let tag_routes = tag::routes::routes(env.clone());
+    let supplier_routes = supplier::routes::routes(env.clone());
+    let search_routes = search::routes::routes(env.clone());
+    let health_routes = health::routes::routes(env.clone());
+    let metrics_routes = metrics_route(database_connection_pool.clone());
+    let image_routes = product::routes::image_routes(image_dir);
 
     // CORS added at the end. Otherwise, it will cause missing cors problem.
-    let routes = customer_routes
-        .or(product_routes)
-        .or(order_routes)
-        .with(warp::trace::request())
-        .recover(rejection_handler)
-        .with(cors);
-
-    let addr = dotenv::var("WEB_API_GATEWAY_HOST_ADDRESS")
-        .unwrap_or_else(|_| "127.0.0.1:10002".to_string())
-        .parse::<SocketAddr>()?;
-
-    warp::serve(routes).run(addr).await;
-
-    database_connection_pool.close().await;
+    let routes = matched(
+        customer_routes
+            .or(product_routes)
+            .or(order_routes)
+            .or(category_routes)
+            .or(tag_routes)
+            .or(supplier_routes)
+            .or(search_routes)
+            .or(health_routes)
+            .or(metrics_routes),
+    )
+    .or(named("/images/:file", image_routes))
+    .with(warp::trace::request())
+    .recover(rejection_handler)
+    .with(cors);
+
+    let service = warp::service(routes);
+    let make_service = make_service_fn(move |_| {
+        let service = service.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let method = request.method().clone();
+                http_metrics(method, with_request_id(service.clone(), request))
+            }))
+        }
+    });
+
+    let shutdown = Shutdown::listen();
+    let server =
+        Server::try_bind(&cli.listen_or(addresses.web_api_gateway_host))?.serve(make_service);
+    let addr = server.local_addr();
+    let server = server.with_graceful_shutdown(shutdown.triggered());
+
+    tracing::info!(message = "starting web api gateway", %addr);
+
+    let drained = shutdown.drain(server, settings.shutdown_timeout).await;
+    if let Some(result) = drained {
+        database_connection_pool.close().await;
+        result?;
+    }
 
     Ok(())
 }
@@ -1,14 +1,23 @@
+use warp::http::StatusCode;
+use warp::hyper::body::Bytes;
 use warp::Reply;
 
-use common::json::order_item::OrderItem;
+use common::json::note::Note;
+use common::json::order_item::{BatchCreateOrderItemResult, OrderItem};
+use common::json::shopping_list::ShoppingListItem;
 use common::types::ListRequest;
 use common::{order_item_pb, types};
 
 use crate::order::json::{
-    CreateOrderItemRequest, ListOrderItemsRequest, UpdateOrderItemRequest,
-    UpdateOrderItemStatusRequest,
+    BatchCreateOrderItemRequest, CreateOrderItemRequest, ListOrderItemsRequest, OrderItemExportRow,
+    ShoppingListFormat, ShoppingListRequest, UpdateOrderItemRequest, UpdateOrderItemStatusRequest,
 };
+use crate::order::shopping_list::{to_csv, to_text};
 use crate::util::alias::WebResult;
+use crate::util::error::ServerError;
+use crate::util::export::{export_reply, ExportRequest};
+use crate::util::import::{ImportRequest, ImportRows};
+use crate::util::note::CreateNoteRequest;
 use crate::util::recover::custom_error_handler;
 use crate::Env;
 
@@ -46,6 +55,32 @@ pub(crate) async fn create(req: CreateOrderItemRequest, env: Env) -> WebResult<i
         .map_err(custom_error_handler)
 }
 
+pub(crate) async fn batch_create(
+    req: BatchCreateOrderItemRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    let req: order_item_pb::BatchCreateOrderItemRequest = req.into();
+
+    client
+        .batch_create(req)
+        .await
+        .map(|r| {
+            let r: BatchCreateOrderItemResult = r.into_inner().into();
+
+            // nothing is created if any item is invalid.
+            let status = if r.errors.is_empty() {
+                StatusCode::OK
+            } else {
+                StatusCode::BAD_REQUEST
+            };
+
+            warp::reply::with_status(warp::reply::json(&r), status)
+        })
+        .map_err(custom_error_handler)
+}
+
 pub(crate) async fn list(req: ListOrderItemsRequest, env: Env) -> WebResult<impl Reply> {
     let mut client = env.grpc_order_client;
 
@@ -95,3 +130,124 @@ pub(crate) async fn update_items_status(
         .map(|_| warp::reply::reply())
         .map_err(custom_error_handler)
 }
+
+pub(crate) async fn shopping_list(req: ShoppingListRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    let items = client
+        .shopping_list(order_item_pb::ShoppingListRequest::from(&req))
+        .await
+        .map_err(custom_error_handler)?
+        .into_inner()
+        .items
+        .into_iter()
+        .map(|e| e.into())
+        .collect::<Vec<ShoppingListItem>>();
+
+    let response = match req.format {
+        ShoppingListFormat::Json => warp::reply::json(&items).into_response(),
+        ShoppingListFormat::Csv => {
+            let csv = to_csv(&items).map_err(|e| warp::reject::custom(ServerError::Other(e)))?;
+            let csv = warp::reply::with_header(csv, "content-type", "text/csv");
+            warp::reply::with_header(
+                csv,
+                "content-disposition",
+                "attachment; filename=\"shopping-list.csv\"",
+            )
+            .into_response()
+        }
+        ShoppingListFormat::Text => to_text(&items).into_response(),
+    };
+
+    Ok(response)
+}
+
+pub(crate) async fn import(req: ImportRequest, body: Bytes, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    let mut rows = ImportRows::<order_item_pb::CreateOrderItemRequest>::parse::<
+        CreateOrderItemRequest,
+        CreateOrderItemRequest,
+    >(&body, req.format);
+
+    let requests = futures::stream::iter(rows.take_requests());
+
+    client
+        .import(requests)
+        .await
+        .map(|r| {
+            let report = rows.into_report(r.into_inner());
+            warp::reply::json(&report)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn export(
+    export_req: ExportRequest,
+    req: ListOrderItemsRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    let req: ListRequest = req.into();
+
+    client
+        .export(req)
+        .await
+        .map(|rows| {
+            export_reply::<_, OrderItemExportRow, OrderItem>(
+                rows.into_inner(),
+                export_req.format,
+                "order-items",
+            )
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list_notes(id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    client
+        .list_notes(types::GetByIdRequest { id })
+        .await
+        .map(|n| {
+            let n = n
+                .into_inner()
+                .notes
+                .into_iter()
+                .map(|e| e.into())
+                .collect::<Vec<Note>>();
+            warp::reply::json(&n)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn create_note(
+    id: u64,
+    req: CreateNoteRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    client
+        .create_note(req.into_pb(id))
+        .await
+        .map(|n| {
+            let n: Note = n.into_inner().into();
+            warp::reply::json(&n)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete_note(id: u64, note_id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_order_client;
+
+    client
+        .delete_note(types::DeleteNoteRequest {
+            owner_id: id,
+            id: note_id,
+        })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
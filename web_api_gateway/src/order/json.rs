@@ -1,6 +1,9 @@
+use chrono::{DateTime, Utc};
+use common::json::order_item::OrderItemStatus;
+use common::order_item_pb;
 use common::types::ListRequest;
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use common::util::tools::timestamp2datetime;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Debug)]
 pub struct CreateOrderItemRequest {
@@ -8,6 +11,9 @@ pub struct CreateOrderItemRequest {
     pub product_id: u64,
     pub quantity: u16,
     pub status: OrderItemStatus,
+    /// the shipping address of the customer.
+    #[serde(default)]
+    pub address_id: Option<u64>,
 }
 
 impl From<CreateOrderItemRequest> for common::order_item_pb::CreateOrderItemRequest {
@@ -17,17 +23,22 @@ impl From<CreateOrderItemRequest> for common::order_item_pb::CreateOrderItemRequ
             product_id: r.product_id,
             quantity: r.quantity as u32,
             status: r.status as u32,
+            address_id: r.address_id,
         }
     }
 }
 
-#[derive(Debug, Deserialize_repr, PartialEq, Eq)]
-#[repr(u8)]
-pub enum OrderItemStatus {
-    Picked = 0,
-    Available = 1,
-    Ordering = 2,
-    OutOfStock = 3,
+#[derive(Deserialize, Debug)]
+pub struct BatchCreateOrderItemRequest {
+    pub items: Vec<CreateOrderItemRequest>,
+}
+
+impl From<BatchCreateOrderItemRequest> for order_item_pb::BatchCreateOrderItemRequest {
+    fn from(r: BatchCreateOrderItemRequest) -> Self {
+        Self {
+            items: r.items.into_iter().map(|e| e.into()).collect(),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -37,6 +48,7 @@ pub struct UpdateOrderItemRequest {
     pub product_id: Option<u64>,
     pub quantity: Option<u16>,
     pub status: Option<OrderItemStatus>,
+    pub address_id: Option<u64>,
 }
 
 impl From<UpdateOrderItemRequest> for common::order_item_pb::UpdateOrderItemRequest {
@@ -47,6 +59,7 @@ impl From<UpdateOrderItemRequest> for common::order_item_pb::UpdateOrderItemRequ
             product_id: r.product_id,
             quantity: r.quantity.map(|e| e as u32),
             status: r.status.map(|e| e as u32),
+            address_id: r.address_id,
         }
     }
 }
@@ -82,3 +95,73 @@ impl From<ListOrderItemsRequest> for ListRequest {
         }
     }
 }
+
+/// an order item row of an export csv file.
+#[derive(Debug, Serialize)]
+pub struct OrderItemExportRow {
+    pub id: u64,
+    pub customer_id: Option<u64>,
+    pub customer_name: Option<String>,
+    pub product_id: Option<u64>,
+    pub product_name: Option<String>,
+    pub currency: Option<u32>,
+    pub price: Option<f64>,
+    pub quantity: u32,
+    pub status: u32,
+    pub address_id: Option<u64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl From<order_item_pb::OrderItem> for OrderItemExportRow {
+    fn from(o: order_item_pb::OrderItem) -> Self {
+        let customer = o.customer;
+        let product = o.product;
+
+        Self {
+            id: o.id,
+            customer_id: customer.as_ref().map(|c| c.id),
+            customer_name: customer.map(|c| c.name),
+            product_id: product.as_ref().map(|p| p.id),
+            product_name: product.as_ref().map(|p| p.name.clone()),
+            currency: product.as_ref().map(|p| p.currency),
+            price: product.map(|p| p.price),
+            quantity: o.quantity,
+            status: o.status,
+            address_id: o.address_id,
+            created_at: timestamp2datetime(o.created_at),
+            updated_at: o.updated_at.map(timestamp2datetime),
+            deleted_at: o.deleted_at.map(timestamp2datetime),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ShoppingListRequest {
+    pub supplier_id: Option<u64>,
+    #[serde(default)]
+    pub format: ShoppingListFormat,
+}
+
+impl From<&ShoppingListRequest> for common::order_item_pb::ShoppingListRequest {
+    fn from(r: &ShoppingListRequest) -> Self {
+        Self {
+            supplier_id: r.supplier_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ShoppingListFormat {
+    Json,
+    Csv,
+    Text,
+}
+
+impl Default for ShoppingListFormat {
+    fn default() -> Self {
+        Self::Json
+    }
+}
@@ -1,3 +1,4 @@
 pub mod handlers;
 pub mod json;
 pub mod routes;
+pub mod shopping_list;
@@ -1,8 +1,13 @@
 use warp::{filters::BoxedFilter, Filter, Reply};
 
-use crate::order::handlers::v1::{create, get, list, update, update_items_status};
-use crate::order::json::ListOrderItemsRequest;
+use crate::order::handlers::v1::{
+    batch_create, create, create_note, delete_note, export, get, import, list, list_notes,
+    shopping_list, update, update_items_status,
+};
+use crate::order::json::{ListOrderItemsRequest, ShoppingListRequest};
 use crate::util::env::Env;
+use crate::util::export::ExportRequest;
+use crate::util::import::{ImportRequest, MAX_IMPORT_SIZE};
 use crate::util::middleware::with_env::with_env;
 
 pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
@@ -32,14 +37,64 @@ pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
     let update_item_status_route = warp::path!("api" / "v1" / "orders" / "status")
         .and(warp::put())
         .and(warp::body::json())
-        .and(with_env(env))
+        .and(with_env(env.clone()))
         .and_then(update_items_status);
 
+    let shopping_list_route = warp::path!("api" / "v1" / "orders" / "shopping-list")
+        .and(warp::get())
+        .and(warp::query::<ShoppingListRequest>())
+        .and(with_env(env.clone()))
+        .and_then(shopping_list);
+
+    let batch_create_route = warp::path!("api" / "v1" / "orders" / "batch")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(batch_create);
+
+    let import_route = warp::path!("api" / "v1" / "orders" / "import")
+        .and(warp::post())
+        .and(warp::query::<ImportRequest>())
+        .and(warp::body::content_length_limit(MAX_IMPORT_SIZE))
+        .and(warp::body::bytes())
+        .and(with_env(env.clone()))
+        .and_then(import);
+
+    let export_route = warp::path!("api" / "v1" / "orders" / "export")
+        .and(warp::get())
+        .and(warp::query::<ExportRequest>())
+        .and(warp::query::<ListOrderItemsRequest>())
+        .and(with_env(env.clone()))
+        .and_then(export);
+
+    let list_notes_route = warp::path!("api" / "v1" / "orders" / u64 / "notes")
+        .and(warp::get())
+        .and(with_env(env.clone()))
+        .and_then(list_notes);
+
+    let create_note_route = warp::path!("api" / "v1" / "orders" / u64 / "notes")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(create_note);
+
+    let delete_note_route = warp::path!("api" / "v1" / "orders" / u64 / "notes" / u64)
+        .and(warp::delete())
+        .and(with_env(env))
+        .and_then(delete_note);
+
     let routes = get_route
         .or(create_route)
         .or(list_route)
         .or(update_route)
-        .or(update_item_status_route);
+        .or(update_item_status_route)
+        .or(shopping_list_route)
+        .or(batch_create_route)
+        .or(import_route)
+        .or(export_route)
+        .or(list_notes_route)
+        .or(create_note_route)
+        .or(delete_note_route);
 
     routes.boxed()
 }
@@ -0,0 +1,105 @@
+use common::json::shopping_list::{ShoppingListItem, WaitingCustomer};
+
+const UNKNOWN_SUPPLIER: &str = "Unknown supplier";
+
+/// render the shopping list as csv, one product per line.
+pub(crate) fn to_csv(items: &[ShoppingListItem]) -> anyhow::Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+
+    writer.write_record(&[
+        "supplier",
+        "product_id",
+        "product",
+        "quantity",
+        "customers",
+        "source_url",
+    ])?;
+
+    for item in items {
+        writer.write_record(&[
+            item.supplier_name.clone().unwrap_or_default(),
+            item.product_id.to_string(),
+            item.product_name.clone(),
+            item.quantity.to_string(),
+            format_customers(&item.customers, "; "),
+            item.source_url.clone().unwrap_or_default(),
+        ])?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+/// render the shopping list as printable text, grouped by supplier.
+pub(crate) fn to_text(items: &[ShoppingListItem]) -> String {
+    let mut text = String::new();
+    let mut current_supplier = None;
+
+    for item in items {
+        let supplier = item.supplier_name.as_deref().unwrap_or(UNKNOWN_SUPPLIER);
+
+        if current_supplier != Some(supplier) {
+            if current_supplier.is_some() {
+                text.push('\n');
+            }
+            text.push_str(&format!("== {} ==\n", supplier));
+            current_supplier = Some(supplier);
+        }
+
+        text.push_str(&format!(
+            "[ ] {} x{} ({})\n",
+            item.product_name,
+            item.quantity,
+            format_customers(&item.customers, ", ")
+        ));
+
+        if let Some(url) = &item.source_url {
+            text.push_str(&format!("    {}\n", url));
+        }
+    }
+
+    text
+}
+
+fn format_customers(customers: &[WaitingCustomer], separator: &str) -> String {
+    customers
+        .iter()
+        .map(|c| format!("{} x{}", c.customer_name, c.quantity))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_csv_fields() {
+        let items = vec![ShoppingListItem {
+            product_id: 1,
+            product_name: "Lotion, 200ml \"moist\"".to_string(),
+            supplier_id: None,
+            supplier_name: None,
+            source_url: None,
+            quantity: 3,
+            customers: vec![
+                WaitingCustomer {
+                    order_item_id: 1,
+                    customer_id: 1,
+                    customer_name: "alice".to_string(),
+                    quantity: 1,
+                },
+                WaitingCustomer {
+                    order_item_id: 2,
+                    customer_id: 2,
+                    customer_name: "bob".to_string(),
+                    quantity: 2,
+                },
+            ],
+        }];
+
+        assert_eq!(
+            to_csv(&items).unwrap().lines().nth(1).unwrap(),
+            ",1,\"Lotion, 200ml \"\"moist\"\"\",3,alice x1; bob x2,"
+        );
+    }
+}
@@ -1,11 +1,23 @@
+use futures::TryStreamExt;
+use uuid::Uuid;
+use warp::hyper::body::{Buf, Bytes};
+use warp::multipart::{FormData, Part};
 use warp::reply::Reply;
 
 use common::json::product::Product;
+use common::json::product_image::ProductImage;
 use common::{product_pb, types};
 
-use crate::product::json::{CreateProductRequest, ListProductRequest, UpdateProductRequest};
+use crate::product::image::{create_thumbnail, image_extension};
+use crate::product::json::{
+    CreateProductRequest, ListProductRequest, ProductCsvRow, ProductExportRow,
+    SetProductSupplierRequest, UpdateProductRequest,
+};
 use crate::util::alias::WebResult;
 use crate::util::env::Env;
+use crate::util::error::ServerError;
+use crate::util::export::{export_reply, ExportRequest};
+use crate::util::import::{ImportRequest, ImportRows};
 use crate::util::recover::custom_error_handler;
 
 pub(crate) async fn get(req: u64, env: Env) -> WebResult<impl Reply> {
@@ -60,7 +72,7 @@ pub(crate) async fn update(req: UpdateProductRequest, env: Env) -> WebResult<imp
 pub(crate) async fn list(req: ListProductRequest, env: Env) -> WebResult<impl Reply> {
     let mut client = env.grpc_product_client;
 
-    let req: types::ListRequest = req.into();
+    let req: product_pb::ListProductRequest = req.into();
 
     client
         .list(req)
@@ -76,3 +88,171 @@ pub(crate) async fn list(req: ListProductRequest, env: Env) -> WebResult<impl Re
         })
         .map_err(custom_error_handler)
 }
+
+pub(crate) async fn upload_image(id: u64, form: FormData, env: Env) -> WebResult<impl Reply> {
+    let parts: Vec<Part> = form
+        .try_collect()
+        .await
+        .map_err(|e| warp::reject::custom(ServerError::Other(e.into())))?;
+
+    let part = parts
+        .into_iter()
+        .find(|p| p.name() == "image")
+        .ok_or_else(|| {
+            warp::reject::custom(ServerError::Reason("missing the image field.".to_string()))
+        })?;
+
+    let content_type = part.content_type().unwrap_or_default().to_string();
+    let extension = image_extension(&content_type).ok_or_else(|| {
+        warp::reject::custom(ServerError::Reason(format!(
+            "unsupported image type: {}",
+            content_type
+        )))
+    })?;
+
+    let bytes = part
+        .stream()
+        .try_fold(vec![], |mut acc, buf| async move {
+            acc.extend_from_slice(buf.chunk());
+            Ok(acc)
+        })
+        .await
+        .map_err(|e| warp::reject::custom(ServerError::Other(e.into())))?;
+
+    let source = bytes.clone();
+    let thumbnail = tokio::task::spawn_blocking(move || create_thumbnail(&source))
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|e| e)
+        .map_err(|e| warp::reject::custom(ServerError::Other(e)))?;
+
+    let name = Uuid::new_v4();
+    let key = format!("products/{}/{}.{}", id, name, extension);
+    let thumbnail_key = format!("products/{}/{}_thumbnail.jpg", id, name);
+
+    let store = env.image_store;
+    let url = store
+        .put(&key, bytes)
+        .await
+        .map_err(|e| warp::reject::custom(ServerError::Other(e)))?;
+    let thumbnail_url = store
+        .put(&thumbnail_key, thumbnail)
+        .await
+        .map_err(|e| warp::reject::custom(ServerError::Other(e)))?;
+
+    let mut client = env.grpc_product_client;
+
+    let req = product_pb::AddProductImageRequest {
+        product_id: id,
+        key: key.clone(),
+        url,
+        thumbnail_key: thumbnail_key.clone(),
+        thumbnail_url,
+        content_type,
+    };
+
+    match client.add_image(req).await {
+        Ok(i) => {
+            let i: ProductImage = i.into_inner().into();
+            Ok(warp::reply::json(&i))
+        }
+        Err(e) => {
+            // the image isn't saved, so the stored files are orphans.
+            let _ = store.delete(&key).await;
+            let _ = store.delete(&thumbnail_key).await;
+            Err(custom_error_handler(e))
+        }
+    }
+}
+
+pub(crate) async fn delete_image(id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let image = client
+        .delete_image(types::GetByIdRequest { id })
+        .await
+        .map_err(custom_error_handler)?
+        .into_inner();
+
+    for key in [image.key, image.thumbnail_key] {
+        if let Err(e) = env.image_store.delete(&key).await {
+            tracing::warn!(message = "failed to delete the image", key = %key, error = %e);
+        }
+    }
+
+    Ok(warp::reply::reply())
+}
+
+pub(crate) async fn set_supplier(
+    id: u64,
+    req: SetProductSupplierRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req = product_pb::SetProductSupplierRequest {
+        product_id: id,
+        ..req.into()
+    };
+
+    client
+        .set_product_supplier(req)
+        .await
+        .map(|p| {
+            let p: Product = p.into_inner().into();
+            warp::reply::json(&p)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn remove_supplier(id: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    client
+        .remove_product_supplier(types::GetByIdRequest { id })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn import(req: ImportRequest, body: Bytes, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let mut rows = ImportRows::<product_pb::CreateProductRequest>::parse::<
+        ProductCsvRow,
+        CreateProductRequest,
+    >(&body, req.format);
+
+    let requests = futures::stream::iter(rows.take_requests());
+
+    client
+        .import(requests)
+        .await
+        .map(|r| {
+            let report = rows.into_report(r.into_inner());
+            warp::reply::json(&report)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn export(
+    export_req: ExportRequest,
+    req: ListProductRequest,
+    env: Env,
+) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::ListProductRequest = req.into();
+
+    client
+        .export(req)
+        .await
+        .map(|rows| {
+            export_reply::<_, ProductExportRow, Product>(
+                rows.into_inner(),
+                export_req.format,
+                "products",
+            )
+        })
+        .map_err(custom_error_handler)
+}
@@ -0,0 +1,59 @@
+use std::io::Cursor;
+
+use anyhow::Result;
+use image::ImageOutputFormat;
+
+/// the max width and height of a thumbnail.
+const THUMBNAIL_SIZE: u32 = 320;
+
+/// get the file extension of an image by its content type.
+///
+/// return:
+/// - None if the content type is not a supported image.
+pub(crate) fn image_extension(content_type: &str) -> Option<&'static str> {
+    match content_type {
+        "image/jpeg" | "image/jpg" => Some("jpg"),
+        "image/png" => Some("png"),
+        "image/webp" => Some("webp"),
+        "image/gif" => Some("gif"),
+        _ => None,
+    }
+}
+
+/// create a jpeg thumbnail which keeps the aspect ratio of the image.
+pub(crate) fn create_thumbnail(bytes: &[u8]) -> Result<Vec<u8>> {
+    let thumbnail = image::load_from_memory(bytes)?
+        .thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        .to_rgb8();
+
+    let mut buffer = Cursor::new(vec![]);
+    thumbnail.write_to(&mut buffer, ImageOutputFormat::Jpeg(80))?;
+
+    Ok(buffer.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{ImageBuffer, Rgb};
+
+    use super::*;
+
+    #[test]
+    fn thumbnail_keeps_aspect_ratio() {
+        let image = ImageBuffer::from_pixel(1280, 640, Rgb([255u8, 0, 0]));
+        let mut bytes = Cursor::new(vec![]);
+        image.write_to(&mut bytes, ImageOutputFormat::Png).unwrap();
+
+        let thumbnail = create_thumbnail(bytes.get_ref()).unwrap();
+        let thumbnail = image::load_from_memory(&thumbnail).unwrap();
+
+        assert_eq!(thumbnail.width(), 320);
+        assert_eq!(thumbnail.height(), 160);
+    }
+
+    #[test]
+    fn reject_unsupported_content_type() {
+        assert_eq!(image_extension("image/png"), Some("png"));
+        assert_eq!(image_extension("application/pdf"), None);
+    }
+}
@@ -1,12 +1,17 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-use common::{product_pb, types};
+use common::product_pb;
+use common::util::tools::timestamp2datetime;
 
 #[derive(Deserialize)]
 pub struct CreateProductRequest {
     pub name: String,
     pub currency: i32,
     pub price: f64,
+    pub category_id: Option<u64>,
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl From<CreateProductRequest> for product_pb::CreateProductRequest {
@@ -15,6 +20,73 @@ impl From<CreateProductRequest> for product_pb::CreateProductRequest {
             name: r.name,
             currency: r.currency,
             price: r.price,
+            category_id: r.category_id,
+            tags: r.tags,
+        }
+    }
+}
+
+/// a product row of an import csv file.
+#[derive(Debug, Deserialize)]
+pub struct ProductCsvRow {
+    pub name: String,
+    pub currency: i32,
+    pub price: f64,
+    pub category_id: Option<u64>,
+    /// comma separated tags, e.g. `"skincare,sale"`
+    pub tags: Option<String>,
+}
+
+impl From<ProductCsvRow> for product_pb::CreateProductRequest {
+    fn from(r: ProductCsvRow) -> Self {
+        Self {
+            name: r.name,
+            currency: r.currency,
+            price: r.price,
+            category_id: r.category_id,
+            tags: r.tags.map(|t| split_tags(&t)).unwrap_or_default(),
+        }
+    }
+}
+
+/// a product row of an export csv file, the supplier columns are empty if the product has no supplier.
+#[derive(Debug, Serialize)]
+pub struct ProductExportRow {
+    pub id: u64,
+    pub name: String,
+    pub currency: i32,
+    pub price: f64,
+    pub category_id: Option<u64>,
+    pub tags: String,
+    pub supplier: Option<String>,
+    pub cost_price: Option<f64>,
+    pub cost_currency: Option<i32>,
+    pub exchange_rate: Option<f64>,
+    pub margin: Option<f64>,
+    pub margin_rate: Option<f64>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<product_pb::Product> for ProductExportRow {
+    fn from(p: product_pb::Product) -> Self {
+        let supplier = p.supplier;
+
+        Self {
+            id: p.id,
+            name: p.name,
+            currency: p.currency,
+            price: p.price,
+            category_id: p.category_id,
+            tags: p.tags.join(","),
+            supplier: supplier.as_ref().map(|s| s.supplier_name.clone()),
+            cost_price: supplier.as_ref().map(|s| s.cost_price),
+            cost_currency: supplier.as_ref().map(|s| s.cost_currency),
+            exchange_rate: supplier.as_ref().map(|s| s.exchange_rate),
+            margin: supplier.as_ref().and_then(|s| s.margin),
+            margin_rate: supplier.as_ref().and_then(|s| s.margin_rate),
+            created_at: timestamp2datetime(p.created_at),
+            updated_at: p.updated_at.map(timestamp2datetime),
         }
     }
 }
@@ -25,6 +97,8 @@ pub struct UpdateProductRequest {
     pub name: Option<String>,
     pub currency: Option<i32>,
     pub price: Option<f64>,
+    pub category_id: Option<u64>,
+    pub tags: Option<Vec<String>>,
 }
 
 impl From<UpdateProductRequest> for product_pb::UpdateProductRequest {
@@ -34,6 +108,8 @@ impl From<UpdateProductRequest> for product_pb::UpdateProductRequest {
             name: r.name,
             currency: r.currency,
             price: r.price,
+            category_id: r.category_id,
+            tags: r.tags.map(|tags| product_pb::TagList { tags }),
         }
     }
 }
@@ -43,14 +119,51 @@ pub struct ListProductRequest {
     pub query: Option<String>,
     pub page: Option<u64>,
     pub page_size: Option<u64>,
+    pub category_id: Option<u64>,
+    /// comma separated tags, e.g. `tags=skincare,sale`
+    pub tags: Option<String>,
 }
 
-impl From<ListProductRequest> for types::ListRequest {
+impl From<ListProductRequest> for product_pb::ListProductRequest {
     fn from(e: ListProductRequest) -> Self {
+        let tags = e.tags.map(|t| split_tags(&t)).unwrap_or_default();
+
         Self {
             query: e.query,
             page: e.page.unwrap_or(0),
             page_size: e.page_size.unwrap_or(20),
+            category_id: e.category_id,
+            tags,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetProductSupplierRequest {
+    pub supplier_id: u64,
+    pub source_url: Option<String>,
+    pub cost_price: f64,
+    pub cost_currency: i32,
+    /// the rate converts the cost currency to the sale currency of the product.
+    pub exchange_rate: Option<f64>,
+}
+
+impl From<SetProductSupplierRequest> for product_pb::SetProductSupplierRequest {
+    fn from(r: SetProductSupplierRequest) -> Self {
+        Self {
+            product_id: 0,
+            supplier_id: r.supplier_id,
+            source_url: r.source_url,
+            cost_price: r.cost_price,
+            cost_currency: r.cost_currency,
+            exchange_rate: r.exchange_rate,
         }
     }
 }
+
+fn split_tags(tags: &str) -> Vec<String> {
+    tags.split(',')
+        .map(|e| e.trim().to_string())
+        .filter(|e| !e.is_empty())
+        .collect()
+}
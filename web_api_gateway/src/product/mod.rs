@@ -1,3 +1,4 @@
 pub mod handlers;
+pub mod image;
 pub mod json;
 pub mod routes;
@@ -1,10 +1,20 @@
+use std::path::PathBuf;
+
 use warp::{Filter, filters::BoxedFilter, Reply};
 
-use crate::product::handlers::v1::{create, get, list, update};
+use crate::product::handlers::v1::{
+    create, delete_image, export, get, import, list, remove_supplier, set_supplier, update,
+    upload_image,
+};
 use crate::product::json::ListProductRequest;
 use crate::util::env::Env;
+use crate::util::export::ExportRequest;
+use crate::util::import::{ImportRequest, MAX_IMPORT_SIZE};
 use crate::util::middleware::with_env::with_env;
 
+/// the max size of an uploaded image (10 MB).
+const MAX_IMAGE_SIZE: u64 = 10 * 1024 * 1024;
+
 pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
     let get_route = warp::path!("api" / "v1" / "products" / u64)
         .and(warp::get())
@@ -26,10 +36,67 @@ pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
     let list_route = warp::path!("api" / "v1" / "products")
         .and(warp::get())
         .and(warp::query::<ListProductRequest>())
-        .and(with_env(env))
+        .and(with_env(env.clone()))
         .and_then(list);
 
-    let routes = get_route.or(create_route).or(update_route).or(list_route);
+    let upload_image_route = warp::path!("api" / "v1" / "products" / u64 / "images")
+        .and(warp::post())
+        .and(warp::multipart::form().max_length(MAX_IMAGE_SIZE))
+        .and(with_env(env.clone()))
+        .and_then(upload_image);
+
+    let delete_image_route = warp::path!("api" / "v1" / "products" / "images" / u64)
+        .and(warp::delete())
+        .and(with_env(env.clone()))
+        .and_then(delete_image);
+
+    let set_supplier_route = warp::path!("api" / "v1" / "products" / u64 / "supplier")
+        .and(warp::put())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(set_supplier);
+
+    let remove_supplier_route = warp::path!("api" / "v1" / "products" / u64 / "supplier")
+        .and(warp::delete())
+        .and(with_env(env.clone()))
+        .and_then(remove_supplier);
+
+    let import_route = warp::path!("api" / "v1" / "products" / "import")
+        .and(warp::post())
+        .and(warp::query::<ImportRequest>())
+        .and(warp::body::content_length_limit(MAX_IMPORT_SIZE))
+        .and(warp::body::bytes())
+        .and(with_env(env.clone()))
+        .and_then(import);
+
+    let export_route = warp::path!("api" / "v1" / "products" / "export")
+        .and(warp::get())
+        .and(warp::query::<ExportRequest>())
+        .and(warp::query::<ListProductRequest>())
+        .and(with_env(env))
+        .and_then(export);
+
+    let routes = get_route
+        .or(create_route)
+        .or(update_route)
+        .or(list_route)
+        .or(upload_image_route)
+        .or(delete_image_route)
+        .or(set_supplier_route)
+        .or(remove_supplier_route)
+        .or(import_route)
+        .or(export_route);
 
     routes.boxed()
 }
+
+/// serve the stored images, the image keys are unique so they can be cached forever.
+pub fn image_routes(dir: PathBuf) -> BoxedFilter<(impl Reply,)> {
+    warp::path("images")
+        .and(warp::fs::dir(dir))
+        .with(warp::reply::with::header(
+            "cache-control",
+            "public, max-age=31536000, immutable",
+        ))
+        .boxed()
+}
@@ -0,0 +1,47 @@
+use warp::reply::Reply;
+
+use common::types;
+
+use crate::search::json::{SearchRequest, SearchResponse};
+use crate::util::alias::WebResult;
+use crate::util::env::Env;
+use crate::util::recover::custom_error_handler;
+
+/// search the customers, the products and the order items at the same time.
+pub(crate) async fn search(req: SearchRequest, env: Env) -> WebResult<impl Reply> {
+    let mut customer_client = env.grpc_customer_client;
+    let mut product_client = env.grpc_product_client;
+    let mut order_client = env.grpc_order_client;
+
+    let req: types::SearchRequest = req.into();
+
+    let (customers, products, orders) = futures::try_join!(
+        customer_client.search(req.clone()),
+        product_client.search(req.clone()),
+        order_client.search(req),
+    )
+    .map_err(custom_error_handler)?;
+
+    let response = SearchResponse {
+        customers: customers
+            .into_inner()
+            .customers
+            .into_iter()
+            .map(|e| e.into())
+            .collect(),
+        products: products
+            .into_inner()
+            .products
+            .into_iter()
+            .map(|e| e.into())
+            .collect(),
+        orders: orders
+            .into_inner()
+            .items
+            .into_iter()
+            .map(|e| e.into())
+            .collect(),
+    };
+
+    Ok(warp::reply::json(&response))
+}
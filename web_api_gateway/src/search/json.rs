@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use common::json::customer::Customer;
+use common::json::order_item::OrderItem;
+use common::json::product::Product;
+use common::types;
+
+#[derive(Debug, Deserialize)]
+pub struct SearchRequest {
+    pub q: String,
+    /// the number of the results of each kind, it is 10 by default and 50 at most.
+    pub limit: Option<u64>,
+}
+
+impl From<SearchRequest> for types::SearchRequest {
+    fn from(r: SearchRequest) -> Self {
+        Self {
+            query: r.q,
+            limit: r.limit.unwrap_or(0),
+        }
+    }
+}
+
+/// the search results grouped by kind, the most similar result of a kind is the first one.
+#[derive(Debug, Serialize)]
+pub struct SearchResponse {
+    pub customers: Vec<Customer>,
+    pub products: Vec<Product>,
+    pub orders: Vec<OrderItem>,
+}
@@ -0,0 +1,16 @@
+use warp::{filters::BoxedFilter, Filter, Reply};
+
+use crate::search::handlers::v1::search;
+use crate::search::json::SearchRequest;
+use crate::util::env::Env;
+use crate::util::middleware::with_env::with_env;
+
+pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
+    let search_route = warp::path!("api" / "v1" / "search")
+        .and(warp::get())
+        .and(warp::query::<SearchRequest>())
+        .and(with_env(env))
+        .and_then(search);
+
+    search_route.boxed()
+}
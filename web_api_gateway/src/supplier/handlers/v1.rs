@@ -0,0 +1,69 @@
+use warp::reply::Reply;
+
+use common::json::supplier::Supplier;
+use common::{product_pb, types};
+
+use crate::supplier::json::{CreateSupplierRequest, ListSupplierRequest, UpdateSupplierRequest};
+use crate::util::alias::WebResult;
+use crate::util::env::Env;
+use crate::util::recover::custom_error_handler;
+
+pub(crate) async fn create(req: CreateSupplierRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::CreateSupplierRequest = req.into();
+
+    client
+        .create_supplier(req)
+        .await
+        .map(|s| {
+            let s: Supplier = s.into_inner().into();
+            warp::reply::json(&s)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn update(req: UpdateSupplierRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::UpdateSupplierRequest = req.into();
+
+    client
+        .update_supplier(req)
+        .await
+        .map(|s| {
+            let s: Supplier = s.into_inner().into();
+            warp::reply::json(&s)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete(req: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    client
+        .delete_supplier(types::GetByIdRequest { id: req })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list(req: ListSupplierRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: types::ListRequest = req.into();
+
+    client
+        .list_suppliers(req)
+        .await
+        .map(|s| {
+            let s = s
+                .into_inner()
+                .suppliers
+                .into_iter()
+                .map(|s| s.into())
+                .collect::<Vec<Supplier>>();
+            warp::reply::json(&s)
+        })
+        .map_err(custom_error_handler)
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+use common::{product_pb, types};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSupplierRequest {
+    pub name: String,
+    pub website: Option<String>,
+}
+
+impl From<CreateSupplierRequest> for product_pb::CreateSupplierRequest {
+    fn from(r: CreateSupplierRequest) -> Self {
+        Self {
+            name: r.name,
+            website: r.website,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSupplierRequest {
+    pub id: u64,
+    pub name: Option<String>,
+    pub website: Option<String>,
+}
+
+impl From<UpdateSupplierRequest> for product_pb::UpdateSupplierRequest {
+    fn from(r: UpdateSupplierRequest) -> Self {
+        Self {
+            id: r.id,
+            name: r.name,
+            website: r.website,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListSupplierRequest {
+    pub query: Option<String>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl From<ListSupplierRequest> for types::ListRequest {
+    fn from(e: ListSupplierRequest) -> Self {
+        Self {
+            query: e.query,
+            page: e.page.unwrap_or(0),
+            page_size: e.page_size.unwrap_or(20),
+        }
+    }
+}
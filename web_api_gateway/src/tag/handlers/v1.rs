@@ -0,0 +1,69 @@
+use warp::reply::Reply;
+
+use common::json::tag::Tag;
+use common::{product_pb, types};
+
+use crate::tag::json::{CreateTagRequest, ListTagRequest, UpdateTagRequest};
+use crate::util::alias::WebResult;
+use crate::util::env::Env;
+use crate::util::recover::custom_error_handler;
+
+pub(crate) async fn create(req: CreateTagRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::CreateTagRequest = req.into();
+
+    client
+        .create_tag(req)
+        .await
+        .map(|t| {
+            let t: Tag = t.into_inner().into();
+            warp::reply::json(&t)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn update(req: UpdateTagRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: product_pb::UpdateTagRequest = req.into();
+
+    client
+        .update_tag(req)
+        .await
+        .map(|t| {
+            let t: Tag = t.into_inner().into();
+            warp::reply::json(&t)
+        })
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn delete(req: u64, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    client
+        .delete_tag(types::GetByIdRequest { id: req })
+        .await
+        .map(|_| warp::reply::reply())
+        .map_err(custom_error_handler)
+}
+
+pub(crate) async fn list(req: ListTagRequest, env: Env) -> WebResult<impl Reply> {
+    let mut client = env.grpc_product_client;
+
+    let req: types::ListRequest = req.into();
+
+    client
+        .list_tags(req)
+        .await
+        .map(|t| {
+            let t = t
+                .into_inner()
+                .tags
+                .into_iter()
+                .map(|t| t.into())
+                .collect::<Vec<Tag>>();
+            warp::reply::json(&t)
+        })
+        .map_err(custom_error_handler)
+}
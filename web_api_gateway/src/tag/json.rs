@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+use common::{product_pb, types};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateTagRequest {
+    pub name: String,
+}
+
+impl From<CreateTagRequest> for product_pb::CreateTagRequest {
+    fn from(r: CreateTagRequest) -> Self {
+        Self { name: r.name }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateTagRequest {
+    pub id: u64,
+    pub name: String,
+}
+
+impl From<UpdateTagRequest> for product_pb::UpdateTagRequest {
+    fn from(r: UpdateTagRequest) -> Self {
+        Self {
+            id: r.id,
+            name: r.name,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListTagRequest {
+    pub query: Option<String>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl From<ListTagRequest> for types::ListRequest {
+    fn from(e: ListTagRequest) -> Self {
+        Self {
+            query: e.query,
+            page: e.page.unwrap_or(0),
+            page_size: e.page_size.unwrap_or(20),
+        }
+    }
+}
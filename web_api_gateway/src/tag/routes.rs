@@ -0,0 +1,38 @@
+use warp::{filters::BoxedFilter, Filter, Reply};
+
+use crate::tag::handlers::v1::{create, delete, list, update};
+use crate::tag::json::ListTagRequest;
+use crate::util::env::Env;
+use crate::util::middleware::with_env::with_env;
+
+pub fn routes(env: Env) -> BoxedFilter<(impl Reply,)> {
+    let create_route = warp::path!("api" / "v1" / "tags")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(create);
+
+    let update_route = warp::path!("api" / "v1" / "tags")
+        .and(warp::put())
+        .and(warp::body::json())
+        .and(with_env(env.clone()))
+        .and_then(update);
+
+    let delete_route = warp::path!("api" / "v1" / "tags" / u64)
+        .and(warp::delete())
+        .and(with_env(env.clone()))
+        .and_then(delete);
+
+    let list_route = warp::path!("api" / "v1" / "tags")
+        .and(warp::get())
+        .and(warp::query::<ListTagRequest>())
+        .and(with_env(env))
+        .and_then(list);
+
+    let routes = create_route
+        .or(update_route)
+        .or(delete_route)
+        .or(list_route);
+
+    routes.boxed()
+}
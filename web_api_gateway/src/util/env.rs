@@ -1,29 +1,41 @@
+use std::sync::Arc;
+
 use tonic::transport::Channel;
+use tonic_health::proto::health_client::HealthClient;
 
 use common::customer_pb::customer_services_client::CustomerServicesClient;
 use common::order_item_pb::order_services_client::OrderServicesClient;
 use common::product_pb::product_services_client::ProductServicesClient;
+use common::util::storage::ObjectStore;
+use common::util::telemetry::TracedChannel;
 
 #[derive(Debug, Clone)]
 pub struct Env {
     pub debug: bool,
-    pub grpc_customer_client: CustomerServicesClient<Channel>,
-    pub grpc_product_client: ProductServicesClient<Channel>,
-    pub grpc_order_client: OrderServicesClient<Channel>,
+    pub grpc_customer_client: CustomerServicesClient<TracedChannel>,
+    pub grpc_product_client: ProductServicesClient<TracedChannel>,
+    pub grpc_order_client: OrderServicesClient<TracedChannel>,
+    pub image_store: Arc<dyn ObjectStore>,
+    /// the health clients of the downstream services by name, they are checked by `/readyz`.
+    pub grpc_health_clients: Vec<(&'static str, HealthClient<Channel>)>,
 }
 
 impl Env {
     pub fn new(
         debug: bool,
-        grpc_customer_client: CustomerServicesClient<Channel>,
-        grpc_product_client: ProductServicesClient<Channel>,
-        grpc_order_client: OrderServicesClient<Channel>,
+        grpc_customer_client: CustomerServicesClient<TracedChannel>,
+        grpc_product_client: ProductServicesClient<TracedChannel>,
+        grpc_order_client: OrderServicesClient<TracedChannel>,
+        image_store: Arc<dyn ObjectStore>,
+        grpc_health_clients: Vec<(&'static str, HealthClient<Channel>)>,
     ) -> Self {
         Self {
             debug,
             grpc_customer_client,
             grpc_product_client,
             grpc_order_client,
+            image_store,
+            grpc_health_clients,
         }
     }
 }
@@ -1,9 +1,13 @@
+use common::util::validation::FieldError;
+
 #[derive(thiserror::Error, Debug)]
 pub enum ServerError {
     #[error(transparent)]
     Other(#[from] anyhow::Error),
     #[error("failed to process: {0}")]
     Reason(String),
+    #[error("invalid fields")]
+    Invalid(Vec<FieldError>),
 }
 
 impl warp::reject::Reject for ServerError {}
@@ -0,0 +1,136 @@
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tonic::Streaming;
+use warp::hyper::Body;
+use warp::reply::Response;
+use warp::Reply;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Csv,
+    Jsonl,
+}
+
+impl Default for ExportFormat {
+    fn default() -> Self {
+        Self::Csv
+    }
+}
+
+impl ExportFormat {
+    fn content_type(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Jsonl => "application/x-ndjson",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Jsonl => "jsonl",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportRequest {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+/// stream the rows of a server-streaming rpc as a csv or a json lines file,
+/// every row is written as soon as it is received.
+///
+/// params:
+/// - C: the type of a csv record.
+/// - J: the type of a json line.
+/// - name: the name of the downloaded file without extension.
+pub(crate) fn export_reply<T, C, J>(
+    rows: Streaming<T>,
+    format: ExportFormat,
+    name: &str,
+) -> Response
+where
+    T: Send + 'static,
+    C: Serialize + From<T> + 'static,
+    J: Serialize + From<T> + 'static,
+{
+    let body = rows.enumerate().map(move |(i, row)| {
+        let row = row?;
+
+        match format {
+            ExportFormat::Csv => encode_csv(&C::from(row), i == 0),
+            ExportFormat::Jsonl => encode_json_line(&J::from(row)),
+        }
+    });
+
+    let response = Response::new(Body::wrap_stream(body));
+    let response = warp::reply::with_header(response, "content-type", format.content_type());
+
+    warp::reply::with_header(
+        response,
+        "content-disposition",
+        format!("attachment; filename=\"{}.{}\"", name, format.extension()),
+    )
+    .into_response()
+}
+
+/// encode a csv record, the header line is written before the first record.
+fn encode_csv<C: Serialize>(row: &C, with_header: bool) -> anyhow::Result<Vec<u8>> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(with_header)
+        .from_writer(vec![]);
+
+    writer.serialize(row)?;
+
+    Ok(writer.into_inner()?)
+}
+
+fn encode_json_line<J: Serialize>(row: &J) -> anyhow::Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(row)?;
+    line.push(b'\n');
+
+    Ok(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Row {
+        id: u64,
+        name: String,
+        email: Option<String>,
+    }
+
+    #[test]
+    fn write_csv_header_once() {
+        let row = |id: u64, name: &str| Row {
+            id,
+            name: name.to_string(),
+            email: None,
+        };
+
+        let first = encode_csv(&row(1, "boris, lok"), true).unwrap();
+        let second = encode_csv(&row(2, "alice"), false).unwrap();
+
+        assert_eq!(first, b"id,name,email\n1,\"boris, lok\",\n");
+        assert_eq!(second, b"2,alice,\n");
+    }
+
+    #[test]
+    fn write_json_lines() {
+        let row = Row {
+            id: 1,
+            name: "boris".to_string(),
+            email: None,
+        };
+
+        let line = encode_json_line(&row).unwrap();
+
+        assert_eq!(line, b"{\"id\":1,\"name\":\"boris\",\"email\":null}\n");
+    }
+}
@@ -0,0 +1,161 @@
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use common::json::import::ImportReport;
+use common::types;
+
+/// the max size of an import file (10 MB).
+pub(crate) const MAX_IMPORT_SIZE: u64 = 10 * 1024 * 1024;
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    Csv,
+    Jsonl,
+}
+
+impl Default for ImportFormat {
+    fn default() -> Self {
+        Self::Jsonl
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportRequest {
+    #[serde(default)]
+    pub format: ImportFormat,
+}
+
+/// the rows of an import file. the valid rows are sent to the service,
+/// the rows which can't be parsed are reported as failed by the gateway.
+pub(crate) struct ImportRows<T> {
+    requests: Vec<T>,
+    row_numbers: Vec<u32>,
+    errors: Vec<(u32, String)>,
+}
+
+impl<T> ImportRows<T> {
+    /// parse a csv file with a header line or a json lines file.
+    ///
+    /// params:
+    /// - C: the type of a csv record.
+    /// - J: the type of a json line.
+    ///
+    /// the row numbers are 1-based, for csv files the header line isn't counted.
+    pub(crate) fn parse<C, J>(body: &[u8], format: ImportFormat) -> Self
+    where
+        C: DeserializeOwned + Into<T>,
+        J: DeserializeOwned + Into<T>,
+    {
+        let mut rows = Self {
+            requests: vec![],
+            row_numbers: vec![],
+            errors: vec![],
+        };
+
+        match format {
+            ImportFormat::Csv => {
+                let mut reader = csv::ReaderBuilder::new()
+                    .trim(csv::Trim::All)
+                    .from_reader(body);
+
+                for (i, record) in reader.deserialize::<C>().enumerate() {
+                    rows.push(i as u32 + 1, record.map_err(|e| e.to_string()));
+                }
+            }
+            ImportFormat::Jsonl => {
+                let body = String::from_utf8_lossy(body);
+
+                for (i, line) in body.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+
+                    let record = serde_json::from_str::<J>(line).map_err(|e| e.to_string());
+                    rows.push(i as u32 + 1, record);
+                }
+            }
+        }
+
+        rows
+    }
+
+    fn push<R: Into<T>>(&mut self, row: u32, record: Result<R, String>) {
+        match record {
+            Ok(r) => {
+                self.requests.push(r.into());
+                self.row_numbers.push(row);
+            }
+            Err(e) => self.errors.push((row, e)),
+        }
+    }
+
+    /// take the valid rows which are sent to the service.
+    pub(crate) fn take_requests(&mut self) -> Vec<T> {
+        std::mem::take(&mut self.requests)
+    }
+
+    /// map the rows of the service report back to the rows of the file
+    /// and add the rows which can't be parsed.
+    pub(crate) fn into_report(self, report: types::ImportReport) -> ImportReport {
+        let mut report: ImportReport = report.into();
+
+        for r in report.rows.iter_mut() {
+            let index = r.row.checked_sub(1).map(|i| i as usize);
+
+            if let Some(&row) = index.and_then(|i| self.row_numbers.get(i)) {
+                r.row = row;
+            }
+        }
+
+        for (row, reason) in self.errors {
+            report.add_failed(row, reason);
+        }
+
+        report.rows.sort_by_key(|r| r.row);
+
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::customer_pb;
+    use common::json::import::ImportRowStatus;
+
+    use crate::customer::json::CreateCustomerRequest;
+
+    use super::*;
+
+    #[test]
+    fn report_rows_of_the_file() {
+        let body = b"name,email,phone\nboris,,123\nalice\n";
+
+        let mut rows = ImportRows::<customer_pb::CreateCustomerRequest>::parse::<
+            CreateCustomerRequest,
+            CreateCustomerRequest,
+        >(body, ImportFormat::Csv);
+
+        let requests = rows.take_requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].phone, Some("123".to_string()));
+
+        let report = rows.into_report(types::ImportReport {
+            rows: vec![types::ImportRowResult {
+                row: 1,
+                status: types::ImportRowStatus::Created as i32,
+                id: Some(1),
+                reason: None,
+            }],
+            created: 1,
+            skipped: 0,
+            failed: 0,
+        });
+
+        assert_eq!(report.created, 1);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.rows[0].row, 1);
+        assert_eq!(report.rows[1].row, 2);
+        assert_eq!(report.rows[1].status, ImportRowStatus::Failed);
+    }
+}
@@ -0,0 +1,38 @@
+use std::convert::Infallible;
+
+use tracing::Instrument;
+use warp::http::{HeaderValue, Request, Response};
+use warp::hyper::service::Service;
+use warp::hyper::Body;
+
+use common::util::request_id::{request_id_or_generate, scope, REQUEST_ID_HEADER};
+
+/// Handle a request with its id, the id of the client is kept if it is valid.
+///
+/// the id is added to the headers of the request and the response, it is a field of the span
+/// of the request, and the grpc clients forward it to the services.
+pub async fn with_request_id<S>(
+    mut service: S,
+    mut request: Request<Body>,
+) -> Result<Response<Body>, Infallible>
+where
+    S: Service<Request<Body>, Response = Response<Body>, Error = Infallible>,
+{
+    let id = request_id_or_generate(request.headers());
+    let value = HeaderValue::from_str(&id).expect("A request id is a valid header value.");
+    request
+        .headers_mut()
+        .insert(REQUEST_ID_HEADER, value.clone());
+
+    let span = tracing::info_span!(
+        "http request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        request_id = %id
+    );
+
+    let mut response = scope(id, service.call(request)).instrument(span).await?;
+    response.headers_mut().insert(REQUEST_ID_HEADER, value);
+
+    Ok(response)
+}
@@ -1,5 +1,8 @@
 pub mod alias;
 pub mod env;
 pub mod error;
+pub mod export;
+pub mod import;
 pub mod middleware;
+pub mod note;
 pub mod recover;
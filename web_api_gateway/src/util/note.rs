@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+use common::types;
+
+/// a note of a customer or an order item, the owner id is in the path.
+#[derive(Debug, Deserialize)]
+pub struct CreateNoteRequest {
+    pub author: String,
+    pub body: String,
+}
+
+impl CreateNoteRequest {
+    pub fn into_pb(self, owner_id: u64) -> types::CreateNoteRequest {
+        types::CreateNoteRequest {
+            owner_id,
+            author: self.author,
+            body: self.body,
+        }
+    }
+}
@@ -1,13 +1,16 @@
+use std::collections::BTreeMap;
 use std::convert::Infallible;
 use std::error::Error;
 
 use serde::Serialize;
-use tonic::Status;
+use tonic::{Code, Status};
 use tracing::log::error;
 use warp::http::StatusCode;
 use warp::Reply;
 
 use common::util::errors::AppError;
+use common::util::request_id::current_request_id;
+use common::util::validation::FieldError;
 
 use crate::util::error::ServerError;
 
@@ -15,6 +18,12 @@ use crate::util::error::ServerError;
 struct ErrorResponse {
     code: u16,
     message: String,
+    /// the error messages of each invalid field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    errors: Option<BTreeMap<String, Vec<String>>>,
+    /// the id of the request, it is in the logs of the gateway and of the services.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
 }
 
 impl From<(u16, String)> for ErrorResponse {
@@ -22,6 +31,8 @@ impl From<(u16, String)> for ErrorResponse {
         Self {
             code: t.0,
             message: t.1,
+            errors: None,
+            request_id: current_request_id(),
         }
     }
 }
@@ -29,12 +40,34 @@ impl From<(u16, String)> for ErrorResponse {
 pub fn custom_error_handler(status: Status) -> warp::reject::Rejection {
     let msg = status.message();
     tracing::error!(message = msg);
+
+    if status.code() == Code::InvalidArgument {
+        if let Ok(errors) = serde_json::from_slice::<Vec<FieldError>>(status.details()) {
+            return warp::reject::custom(ServerError::Invalid(errors));
+        }
+    }
+
     warp::reject::custom(ServerError::Reason(msg.to_string()))
 }
 
+/// group the messages of the invalid fields by field, a field can have more than one error.
+fn field_messages(errors: &[FieldError]) -> BTreeMap<String, Vec<String>> {
+    let mut messages: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for e in errors {
+        messages
+            .entry(e.field.clone())
+            .or_default()
+            .push(e.message.clone());
+    }
+
+    messages
+}
+
 pub async fn rejection_handler(err: warp::Rejection) -> Result<impl Reply, Infallible> {
     let code;
     let message;
+    let mut errors = None;
 
     error!("unhandled rejection: {:?}", err);
 
@@ -60,6 +93,10 @@ pub async fn rejection_handler(err: warp::Rejection) -> Result<impl Reply, Infal
     } else if let Some(ServerError::Reason(s)) = err.find() {
         code = StatusCode::BAD_REQUEST;
         message = s.to_string();
+    } else if let Some(ServerError::Invalid(e)) = err.find() {
+        code = StatusCode::BAD_REQUEST;
+        message = "invalid fields.".to_string();
+        errors = Some(field_messages(e));
     } else if let Some(ServerError::Other(e)) = err.find() {
         code = StatusCode::BAD_REQUEST;
         message = e.to_string();
@@ -68,9 +105,32 @@ pub async fn rejection_handler(err: warp::Rejection) -> Result<impl Reply, Infal
         message = "unhandled rejection.".to_string();
     }
 
-    let response: ErrorResponse = (code.as_u16(), message).into();
+    let mut response: ErrorResponse = (code.as_u16(), message).into();
+    response.errors = errors;
 
     let json = warp::reply::json(&response);
 
     Ok(warp::reply::with_status(json, code))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_every_message_of_a_field() {
+        let error = |field: &str, message: &str| FieldError {
+            field: field.to_string(),
+            message: message.to_string(),
+        };
+
+        let messages = field_messages(&[
+            error("email", "can't be empty."),
+            error("email", "is invalid."),
+            error("name", "can't be empty."),
+        ]);
+
+        assert_eq!(messages["email"], vec!["can't be empty.", "is invalid."]);
+        assert_eq!(messages["name"], vec!["can't be empty."]);
+    }
+}